@@ -1,20 +1,50 @@
 use convert_case::{Case, Casing};
-use quote::{format_ident, quote, quote_spanned};
+use quote::{format_ident, quote};
 
-#[proc_macro_derive(AsVariant)]
+/// Derive `is_*`, `as_*`, `as_*_mut` and `try_into_*` methods for each variant
+/// of an enum
+/// Also derives a `variant_name` method and a `VARIANT_COUNT` constant, which
+/// cover all variants of the enum
+///
+/// Variants marked with `#[as_variant(ignore)]` are skipped, e.g. to avoid
+/// clashing with methods defined elsewhere:
+///
+/// ```
+/// use as_variant::AsVariant;
+///
+/// #[derive(AsVariant)]
+/// enum Example {
+///     A(usize),
+///     #[as_variant(ignore)]
+///     B(bool),
+/// }
+///
+/// assert_eq!(Example::A(1).as_a(), Some(&1));
+/// ```
+///
+/// ```compile_fail
+/// use as_variant::AsVariant;
+///
+/// #[derive(AsVariant)]
+/// enum Example {
+///     A(usize),
+///     #[as_variant(ignore)]
+///     B(bool),
+/// }
+///
+/// let _ = Example::B(true).as_b();
+/// ```
+#[proc_macro_derive(AsVariant, attributes(as_variant))]
+#[allow(clippy::too_many_lines)]
 pub fn as_variant_derive(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     // 1. assert that it is an enum
     // 2. For each variant field with data, generate an `as_` method
     let input = syn::parse_macro_input!(input as syn::DeriveInput);
 
     let syn::Data::Enum(data) = input.data else {
-        // compile_error!("Only enums are supported");
-        let span = input.ident.span();
-        return quote_spanned! {
-            span => compile_error!("Only enums are supported");
-        }
-        .into();
-        // panic!("Only enums are supported");
+        return syn::Error::new_spanned(&input.ident, "Only enums are supported")
+            .to_compile_error()
+            .into();
     };
 
     let variants = data.variants;
@@ -24,29 +54,60 @@ pub fn as_variant_derive(input: proc_macro::TokenStream) -> proc_macro::TokenStr
     let mut output = proc_macro2::TokenStream::new();
 
     let mut as_variant_methods = proc_macro2::TokenStream::new();
+    // snake case name of each variant methods are generated for, used to detect
+    // variants that would generate methods with the same name
+    let mut generated_for: Vec<(String, &syn::Ident)> = Vec::with_capacity(variants.len());
+
+    for variant in &variants {
+        match is_ignored(variant) {
+            Ok(true) => continue,
+            Ok(false) => {}
+            Err(err) => return err.to_compile_error().into(),
+        }
 
-    for variant in variants {
-        let syn::Fields::Unnamed(_) = variant.fields else {
-            continue;
-        };
         let variant_ident = &variant.ident;
 
+        let snake_name = variant_ident.to_string().to_case(Case::Snake);
+        if let Some((_, clashing)) = generated_for.iter().find(|(name, _)| *name == snake_name) {
+            return syn::Error::new_spanned(
+                variant_ident,
+                format!(
+                    "variant `{variant_ident}` would generate the same methods as `{clashing}`, \
+                     mark one of them with `#[as_variant(ignore)]`"
+                ),
+            )
+            .to_compile_error()
+            .into();
+        }
+        generated_for.push((snake_name.clone(), variant_ident));
+
+        {
+            let is_fn_name = format_ident!("is_{}", snake_name, span = variant.ident.span());
+            let doc_comment = format!(
+                " Return `true` if this value is a `{enum_name}::{variant_ident}` variant."
+            );
+            let is_ = quote! {
+                #[inline]
+                #[must_use]
+                #[doc = #doc_comment]
+                pub const fn #is_fn_name(&self) -> bool {
+                    matches!(self, Self::#variant_ident { .. })
+                }
+            };
+
+            as_variant_methods.extend(is_);
+        }
+
+        if matches!(variant.fields, syn::Fields::Unit) {
+            continue;
+        }
+
         let (data_pattern, ret_value, data_types) = get_field_info(&variant.fields);
         let pattern = quote! { #enum_name :: #variant_ident #data_pattern };
 
-        let variant_name = &variant.ident;
-
-        let fn_name = format_ident!(
-            "as_{}",
-            variant.ident.to_string().to_case(Case::Snake),
-            span = variant.ident.span()
-        );
+        let fn_name = format_ident!("as_{}", snake_name, span = variant.ident.span());
 
-        let mut_fn_name = format_ident!(
-            "as_{}_mut",
-            variant.ident.to_string().to_case(Case::Snake),
-            span = variant.ident.span()
-        );
+        let mut_fn_name = format_ident!("as_{}_mut", snake_name, span = variant.ident.span());
 
         // TODO:
         let doc_comment = format!(" Return a reference to `{enum_name}::{variant_ident}` variant.");
@@ -71,8 +132,8 @@ pub fn as_variant_derive(input: proc_macro::TokenStream) -> proc_macro::TokenStr
                 //     #pattern => Some(#ret_value),
                 //     _ => None,
                 // }
-                if let Self::#variant_name(ref inner) = self {
-                    Some(inner)
+                if let #pattern = self {
+                    Some(#ret_value)
                 } else {
                     None
                 }
@@ -99,8 +160,8 @@ pub fn as_variant_derive(input: proc_macro::TokenStream) -> proc_macro::TokenStr
                // fn #fn_name(&self) -> Option<&#data_pattern> {
                // fn #fn_name(&self) -> Option<#(&#data_types),*> {
                pub fn #mut_fn_name(&mut self) -> Option<#some_value> {
-                   if let Self::#variant_name(ref mut inner) = self {
-                       Some(inner)
+                   if let #pattern = self {
+                       Some(#ret_value)
                    } else {
                        None
                    }
@@ -109,11 +170,55 @@ pub fn as_variant_derive(input: proc_macro::TokenStream) -> proc_macro::TokenStr
 
             as_variant_methods.extend(as_mut_);
         }
+
+        {
+            let try_into_fn_name =
+                format_ident!("try_into_{}", snake_name, span = variant.ident.span());
+            let ok_value = if data_types.len() == 1 {
+                quote! { #(#data_types),* }
+            } else {
+                quote! { (#(#data_types),*) }
+            };
+
+            let doc_comment =
+                format!(" Convert into the data of the `{enum_name}::{variant_ident}` variant.");
+            let doc_if_err = " Return `Err(self)` if this value is of any other type";
+            let try_into_ = quote! {
+                #[inline]
+                #[doc = #doc_comment]
+                #[doc = #doc_if_err]
+                pub fn #try_into_fn_name(self) -> ::std::result::Result<#ok_value, Self> {
+                    match self {
+                        #pattern => Ok(#ret_value),
+                        other => Err(other),
+                    }
+                }
+            };
+
+            as_variant_methods.extend(try_into_);
+        }
     }
 
+    let variant_count = variants.len();
+    let variant_count_doc = format!(" Number of variants of `{enum_name}`.");
+    let variant_idents = variants.iter().map(|variant| &variant.ident);
+    let variant_names = variants.iter().map(|variant| variant.ident.to_string());
+
     output.extend(quote! {
         #[automatically_derived]
         impl #impl_generics #enum_name #type_generics #where_clause {
+            #[doc = #variant_count_doc]
+            pub const VARIANT_COUNT: usize = #variant_count;
+
+            /// Return the name of the variant of this value.
+            #[inline]
+            #[must_use]
+            pub const fn variant_name(&self) -> &'static str {
+                match *self {
+                    #(Self::#variant_idents { .. } => #variant_names,)*
+                }
+            }
+
             #as_variant_methods
         }
     });
@@ -121,17 +226,25 @@ pub fn as_variant_derive(input: proc_macro::TokenStream) -> proc_macro::TokenStr
     output.into()
 }
 
-// #[derive(AsVariant)]
-// enum Example {
-//     #[as_variant(ignore)]
-//     A(usize)
-//     B(bool, [usize; 4]),
-//     C,
-//     D { a: u32, b: u32 }
-// }
-
-// Example::as_b();
-// Example::as_b_mut();
+/// Check if `variant` is marked with `#[as_variant(ignore)]`
+fn is_ignored(variant: &syn::Variant) -> syn::Result<bool> {
+    let mut ignored = false;
+    for attr in variant
+        .attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("as_variant"))
+    {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("ignore") {
+                ignored = true;
+                Ok(())
+            } else {
+                Err(meta.error("unsupported as_variant attribute, expected `ignore`"))
+            }
+        })?;
+    }
+    Ok(ignored)
+}
 
 // https://github.com/JelteF/derive_more/blob/e0d169887d1d5f026be077a9458467e970a168f9/impl/src/unwrap.rs#L130-L144
 fn get_field_info(
@@ -142,7 +255,19 @@ fn get_field_info(
     Vec<&syn::Type>,
 ) {
     match fields {
-        syn::Fields::Named(_) => panic!("cannot unwrap anonymous records"),
+        syn::Fields::Named(ref fields) => {
+            let (idents, types) = fields
+                .named
+                .iter()
+                .filter_map(|it| Some((it.ident.as_ref()?, &it.ty)))
+                .unzip::<_, _, Vec<_>, Vec<_>>();
+            let ret_value = if idents.len() == 1 {
+                quote! { #(#idents),* }
+            } else {
+                quote! { (#(#idents),*) }
+            };
+            (quote! { { #(#idents),* } }, ret_value, types)
+        }
         syn::Fields::Unnamed(ref fields) => {
             let (idents, types) = fields
                 .unnamed
@@ -150,7 +275,12 @@ fn get_field_info(
                 .enumerate()
                 .map(|(n, it)| (format_ident!("field_{n}"), &it.ty))
                 .unzip::<_, _, Vec<_>, Vec<_>>();
-            (quote! { (#(#idents),*) }, quote! { (#(#idents),*) }, types)
+            let ret_value = if idents.len() == 1 {
+                quote! { #(#idents),* }
+            } else {
+                quote! { (#(#idents),*) }
+            };
+            (quote! { (#(#idents),*) }, ret_value, types)
         }
         syn::Fields::Unit => (quote! {}, quote! { () }, vec![]),
     }
@@ -0,0 +1,25 @@
+use as_variant::AsVariant;
+
+#[derive(Debug, PartialEq, AsVariant)]
+enum Example {
+    A(usize),
+    #[as_variant(ignore)]
+    B(bool),
+}
+
+impl Example {
+    // would clash with the derived method if `B` was not ignored
+    const fn as_b(&self) -> Option<bool> {
+        if let Self::B(b) = self {
+            Some(*b)
+        } else {
+            None
+        }
+    }
+}
+
+fn main() {
+    assert_eq!(Example::A(1).as_a(), Some(&1));
+    assert_eq!(Example::B(true).as_b(), Some(true));
+    assert_eq!(Example::B(true).as_a(), None);
+}
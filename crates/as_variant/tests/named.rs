@@ -0,0 +1,32 @@
+use as_variant::AsVariant;
+
+#[derive(Debug, PartialEq, AsVariant)]
+enum Example {
+    C(u8),
+    D { a: u32 },
+    E { b: bool, c: String },
+}
+
+fn main() {
+    let mut d = Example::D { a: 1 };
+    assert_eq!(d.as_d(), Some(&1));
+    assert_eq!(d.as_c(), None);
+    assert_eq!(Example::C(3).as_c(), Some(&3));
+    if let Some(a) = d.as_d_mut() {
+        *a = 2;
+    }
+    assert_eq!(d, Example::D { a: 2 });
+    assert_eq!(d.try_into_d(), Ok(2));
+
+    let mut e = Example::E {
+        b: true,
+        c: "c".to_string(),
+    };
+    assert_eq!(e.as_e(), Some((&true, &"c".to_string())));
+    assert_eq!(e.as_d(), None);
+    if let Some((b, _)) = e.as_e_mut() {
+        *b = false;
+    }
+    assert!(e.is_e());
+    assert_eq!(e.try_into_e(), Ok((false, "c".to_string())));
+}
@@ -2,4 +2,9 @@
 fn tests() {
     let t = trybuild::TestCases::new();
     t.pass("tests/usecase.rs");
+    t.pass("tests/try_into.rs");
+    t.pass("tests/named.rs");
+    t.pass("tests/ignore.rs");
+    t.pass("tests/variant_name.rs");
+    t.compile_fail("tests/ui/*.rs");
 }
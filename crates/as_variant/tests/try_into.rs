@@ -0,0 +1,30 @@
+use as_variant::AsVariant;
+
+#[derive(Debug, PartialEq)]
+struct Foo {
+    a: i32,
+}
+
+#[derive(Debug, PartialEq, AsVariant)]
+enum Example {
+    A(Foo),
+    B(String),
+    Pair(u8, bool),
+    NoData,
+}
+
+fn main() {
+    let a = Example::A(Foo { a: 1 });
+    assert!(a.is_a());
+    assert!(!a.is_b());
+    assert_eq!(a.try_into_a(), Ok(Foo { a: 1 }));
+
+    let b = Example::B("b".to_string());
+    assert_eq!(b.try_into_a(), Err(Example::B("b".to_string())));
+
+    let pair = Example::Pair(2, true);
+    assert_eq!(pair.try_into_pair(), Ok((2, true)));
+
+    assert!(Example::NoData.is_no_data());
+    assert!(!Example::NoData.is_pair());
+}
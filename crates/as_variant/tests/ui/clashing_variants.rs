@@ -0,0 +1,10 @@
+use as_variant::AsVariant;
+
+#[allow(non_camel_case_types)]
+#[derive(AsVariant)]
+enum Example {
+    FooBar(u8),
+    Foo_Bar { a: u32 },
+}
+
+fn main() {}
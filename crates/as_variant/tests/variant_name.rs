@@ -0,0 +1,25 @@
+use as_variant::AsVariant;
+
+#[allow(dead_code)]
+#[derive(AsVariant)]
+enum Example {
+    Unit,
+    Tuple(u8),
+    Named {
+        a: u32,
+    },
+    #[as_variant(ignore)]
+    Ignored(bool),
+}
+
+#[derive(AsVariant)]
+enum Empty {}
+
+fn main() {
+    assert_eq!(Example::Unit.variant_name(), "Unit");
+    assert_eq!(Example::Tuple(1).variant_name(), "Tuple");
+    assert_eq!(Example::Named { a: 2 }.variant_name(), "Named");
+    assert_eq!(Example::Ignored(true).variant_name(), "Ignored");
+    assert_eq!(Example::VARIANT_COUNT, 4);
+    assert_eq!(Empty::VARIANT_COUNT, 0);
+}
@@ -1,10 +1,13 @@
 #![forbid(missing_docs)]
 //! Simple **Bevy** plugin that toggles fullscreen of the primary window.
 //!
-//! Users can toggle fullscreen by emitting the `ToggleFullscreenEvent` event.
+//! Users can toggle fullscreen by emitting the `ToggleFullscreenEvent` event,
+//! or set the window mode directly by emitting the `SetWindowMode` event.
 //!
 //! By default `F11` will be bound to toggle fullscreen. Users can change this
 //! by setting `ToggleFullscreenPlugin`'s `bind_f11` field to `false`.
+//! At runtime the keybind can be changed by emitting the
+//! `SetFullscreenKeybind` event.
 //!
 //! # Examples
 //! ```rust
@@ -17,17 +20,42 @@
 //!     .run();
 //! ```
 
-use bevy::{input::common_conditions::input_just_released, prelude::*, window::WindowMode};
+use bevy::{
+    prelude::*,
+    window::{PrimaryWindow, WindowMode},
+};
 
 /// prelude module bringing entire public API into score
 pub mod prelude {
-    pub use super::{ToggleFullscreen, ToggleFullscreenPlugin};
+    pub use super::{
+        CurrentWindowMode, FullscreenKeybind, SetFullscreenKeybind, SetWindowMode,
+        ToggleFullscreen, ToggleFullscreenPlugin,
+    };
 }
 
 /// Event that toggles fullscreen of the primary window.
 #[derive(Debug, Event)]
 pub struct ToggleFullscreen;
 
+/// Event that sets the window mode of the primary window, regardless of the
+/// current mode.
+#[derive(Debug, Event)]
+pub struct SetWindowMode(pub WindowMode);
+
+/// Resource mirroring the window mode of the primary window, whether it was
+/// changed by `ToggleFullscreen`, `SetWindowMode` or directly on the `Window`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Resource)]
+pub struct CurrentWindowMode(pub WindowMode);
+
+/// Event that changes the keybind used to toggle fullscreen. `None` removes
+/// the keybind.
+#[derive(Debug, Event)]
+pub struct SetFullscreenKeybind(pub Option<KeyCode>);
+
+/// Resource storing the keybind currently used to toggle fullscreen.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Resource)]
+pub struct FullscreenKeybind(pub Option<KeyCode>);
+
 /// Plugin that toggles fullscreen of the primary window.
 #[derive(Debug)]
 pub struct ToggleFullscreenPlugin {
@@ -46,6 +74,15 @@ impl Default for ToggleFullscreenPlugin {
 
 impl Plugin for ToggleFullscreenPlugin {
     fn build(&self, app: &mut App) {
+        // Register the events and resource on every target, so emitting them is
+        // always valid, even if nothing happens
+        app.add_event::<ToggleFullscreen>()
+            .add_event::<SetWindowMode>()
+            .add_event::<SetFullscreenKeybind>()
+            .insert_resource(FullscreenKeybind(self.keybind))
+            .init_resource::<CurrentWindowMode>()
+            .add_systems(Last, mirror_primary_window_mode);
+
         if cfg!(target_arch = "wasm32") {
             warn!(
                 "ToggleFullscreenPlugin: on target 'wasm32' the window cannot be fullscreened. no \
@@ -54,15 +91,11 @@ impl Plugin for ToggleFullscreenPlugin {
             return;
         }
 
-        app.add_event::<ToggleFullscreen>()
-            .add_systems(PostUpdate, toggle_fullscreen);
-
-        if let Some(keycode) = self.keybind {
-            app.add_systems(
-                Update,
-                emit_toggle_fullscreen.run_if(input_just_released(keycode)),
-            );
-        }
+        app.add_systems(
+            Update,
+            (set_fullscreen_keybind, emit_toggle_fullscreen).chain(),
+        )
+        .add_systems(PostUpdate, (toggle_fullscreen, set_window_mode).chain());
     }
 }
 
@@ -79,9 +112,33 @@ impl Plugin for ToggleFullscreenPlugin {
 //     }
 // }
 
-/// Emit `ToggleFullscreenEvent` event
-fn emit_toggle_fullscreen(mut event_writer: EventWriter<ToggleFullscreen>) {
-    event_writer.send(ToggleFullscreen);
+/// Updates the `FullscreenKeybind` resource, when `SetFullscreenKeybind` is
+/// emitted.
+fn set_fullscreen_keybind(
+    mut keybind: ResMut<FullscreenKeybind>,
+    mut event_reader: EventReader<SetFullscreenKeybind>,
+) {
+    if let Some(SetFullscreenKeybind(new_keybind)) = event_reader.read().last() {
+        info!(
+            "changed fullscreen keybind from: {:?} to: {:?}",
+            keybind.0, new_keybind
+        );
+        keybind.0 = *new_keybind;
+    }
+}
+
+/// Emit `ToggleFullscreenEvent` event, when the keybind is released
+fn emit_toggle_fullscreen(
+    keybind: Res<FullscreenKeybind>,
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    mut event_writer: EventWriter<ToggleFullscreen>,
+) {
+    if keybind
+        .0
+        .is_some_and(|keycode| keyboard_input.just_released(keycode))
+    {
+        event_writer.send(ToggleFullscreen);
+    }
 }
 
 /// Toggles fullscreen of the primary window, when `ToggleFullscreenEvent` is
@@ -106,3 +163,139 @@ fn toggle_fullscreen(
         }
     }
 }
+
+/// Sets the window mode of the primary window, when `SetWindowMode` is emitted.
+fn set_window_mode(mut query: Query<&mut Window>, mut event_reader: EventReader<SetWindowMode>) {
+    for SetWindowMode(new_window_mode) in event_reader.read() {
+        for mut window in &mut query {
+            if window.mode == *new_window_mode {
+                continue;
+            }
+
+            info!(
+                "changed window mode from: {:?} to: {:?}",
+                window.mode, new_window_mode
+            );
+            window.mode = *new_window_mode;
+        }
+    }
+}
+
+/// Updates the `CurrentWindowMode` resource, when the primary window changes.
+fn mirror_primary_window_mode(
+    query: Query<&Window, (With<PrimaryWindow>, Changed<Window>)>,
+    mut current_window_mode: ResMut<CurrentWindowMode>,
+) {
+    if let Ok(window) = query.get_single() {
+        current_window_mode.set_if_neq(CurrentWindowMode(window.mode));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn app() -> App {
+        let mut app = App::new();
+        app.init_resource::<ButtonInput<KeyCode>>()
+            .add_plugins(ToggleFullscreenPlugin::default());
+        app
+    }
+
+    /// Press and release `keycode`, and return the number of
+    /// `ToggleFullscreen` events emitted
+    fn toggles_after_pressing(app: &mut App, keycode: KeyCode) -> usize {
+        app.world.resource_mut::<Events<ToggleFullscreen>>().clear();
+        let mut keyboard_input = app.world.resource_mut::<ButtonInput<KeyCode>>();
+        keyboard_input.reset_all();
+        keyboard_input.press(keycode);
+        keyboard_input.release(keycode);
+        app.update();
+        app.world.resource::<Events<ToggleFullscreen>>().len()
+    }
+
+    #[test]
+    fn keybind_can_be_changed_at_runtime() {
+        let mut app = app();
+        assert_eq!(toggles_after_pressing(&mut app, KeyCode::F11), 1);
+
+        app.world
+            .send_event(SetFullscreenKeybind(Some(KeyCode::KeyF)));
+        app.update();
+        assert_eq!(
+            *app.world.resource::<FullscreenKeybind>(),
+            FullscreenKeybind(Some(KeyCode::KeyF))
+        );
+
+        assert_eq!(toggles_after_pressing(&mut app, KeyCode::F11), 0);
+        assert_eq!(toggles_after_pressing(&mut app, KeyCode::KeyF), 1);
+
+        app.world.send_event(SetFullscreenKeybind(None));
+        app.update();
+        assert_eq!(toggles_after_pressing(&mut app, KeyCode::KeyF), 0);
+    }
+
+    fn window_mode(app: &mut App) -> WindowMode {
+        app.world.query::<&Window>().single(&app.world).mode
+    }
+
+    #[test]
+    fn set_window_mode_is_idempotent() {
+        let mut app = app();
+        app.world.spawn((Window::default(), PrimaryWindow));
+        assert_eq!(window_mode(&mut app), WindowMode::Windowed);
+
+        for _ in 0..2 {
+            app.world.send_event(SetWindowMode(WindowMode::Windowed));
+            app.update();
+            assert_eq!(window_mode(&mut app), WindowMode::Windowed);
+        }
+
+        for expected in [WindowMode::BorderlessFullscreen, WindowMode::Windowed] {
+            app.world.send_event(ToggleFullscreen);
+            app.update();
+            assert_eq!(window_mode(&mut app), expected);
+        }
+    }
+
+    #[test]
+    fn current_window_mode_follows_toggles() {
+        let mut app = app();
+        app.world.spawn((Window::default(), PrimaryWindow));
+        assert_eq!(
+            *app.world.resource::<CurrentWindowMode>(),
+            CurrentWindowMode(WindowMode::Windowed)
+        );
+
+        app.world.send_event(ToggleFullscreen);
+        app.update();
+        assert_eq!(
+            *app.world.resource::<CurrentWindowMode>(),
+            CurrentWindowMode(WindowMode::BorderlessFullscreen)
+        );
+
+        app.world.send_event(SetWindowMode(WindowMode::Windowed));
+        app.update();
+        assert_eq!(
+            *app.world.resource::<CurrentWindowMode>(),
+            CurrentWindowMode(WindowMode::Windowed)
+        );
+    }
+
+    #[test]
+    fn current_window_mode_follows_the_primary_window() {
+        let mut app = app();
+        let window = app.world.spawn((Window::default(), PrimaryWindow)).id();
+        app.update();
+
+        app.world
+            .get_mut::<Window>(window)
+            .expect("window was spawned")
+            .mode = WindowMode::Fullscreen;
+        app.update();
+        assert_eq!(
+            *app.world.resource::<CurrentWindowMode>(),
+            CurrentWindowMode(WindowMode::Fullscreen)
+        );
+    }
+}
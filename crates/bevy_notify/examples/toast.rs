@@ -19,6 +19,7 @@ fn main() {
 
 fn create_toast(mut toast_event: EventWriter<ToastEvent>) {
     toast_event.send(ToastEvent {
+        id:      ToastId::new(),
         caption: "hello".into(),
         options: ToastOptions {
             level: ToastLevel::Success,
@@ -1,6 +1,12 @@
 //! `bevy_notify`
 //!
-//! `bevy_notify` is bevy plugin wrapping [egui-notify](https://crates.io/crates/egui-notify)
+//! `bevy_notify` is bevy plugin showing toasts with egui, using the anchors and
+//! levels of [egui-notify](https://crates.io/crates/egui-notify)
+//!
+//! The toasts are stored and rendered by `bevy_notify` itself, instead of by
+//! `egui_notify::Toasts`, as it does not give access to the toasts once they
+//! are added. Owning them is what allows a duplicate toast to refresh the one
+//! already shown, see [`ToastOptions::dedup_key`]
 //!
 //! # Examples
 //! ```rust
@@ -18,6 +24,7 @@
 //!
 //! fn notify_example_system(mut toast_event: EventWriter<ToastEvent>) {
 //!      toast_event.send(ToastEvent {
+//!          id:      ToastId::new(),
 //!          caption: "hello".into(),
 //!          options: ToastOptions {
 //!              level: ToastLevel::Success,
@@ -29,7 +36,14 @@
 //! }
 //! ```
 
-use std::{num::NonZeroU8, time::Duration};
+use std::{
+    num::NonZeroU8,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc,
+    },
+    time::Duration,
+};
 
 use bevy::prelude::*;
 use bevy_egui::{egui, EguiContexts, EguiPlugin};
@@ -39,18 +53,23 @@ pub use egui_notify::ToastLevel;
 
 /// Bring all symbols into scope that you need to use this crate
 pub mod prelude {
-    pub use super::{Anchor, NotifyPlugin, ToastEvent, ToastLevel, ToastOptions};
+    pub use super::{
+        Anchor, DismissAllToasts, DismissToast, NotifyPlugin, ProgressToast, ToastEvent,
+        ToastEventBuilder, ToastId, ToastLevel, ToastOptions, Toasts,
+    };
 }
 
-/// Adds event `ToastEvent` to be used in systems.
+/// Adds events `ToastEvent`, `DismissToast` and `DismissAllToasts` to be used
+/// in systems.
 /// Uses a `Update` system to render the toasts on the screen at the specified
 /// anchor
 #[derive(Debug)]
 pub struct NotifyPlugin {
     /// Anchor for where to render the toasts in the main window
     /// Defaults to `Anchor::TopCenter`
+    /// Can be overridden for individual toasts with [`ToastOptions::anchor`]
     pub anchor: egui_notify::Anchor,
-    /// Maximum number of toasts to show at once
+    /// Maximum number of toasts to show at once at each anchor
     /// Defaults to 5
     /// When the max is reached, the oldest toast is removed
     pub max:    NonZeroU8,
@@ -72,45 +91,290 @@ impl Plugin for NotifyPlugin {
             app.add_plugins(EguiPlugin);
         }
 
-        let toasts = egui_notify::Toasts::new().with_anchor(self.anchor);
-
-        app.insert_resource(Toasts::new(toasts, self.max))
+        app.insert_resource(Toasts::new(self.anchor, self.max))
             .add_event::<ToastEvent>()
-            .add_systems(Update, update_toasts);
+            .add_event::<DismissToast>()
+            .add_event::<DismissAllToasts>()
+            .add_systems(
+                Update,
+                (
+                    add_toasts,
+                    dismiss_toasts,
+                    dismiss_all_toasts,
+                    expire_toasts,
+                    remove_finished_progress_toasts,
+                    show_toasts,
+                )
+                    .chain(),
+            );
+    }
+}
+
+/// A toast currently shown
+struct Toast {
+    /// Id of the latest [`ToastEvent`] adding or refreshing the toast
+    id: ToastId,
+    caption: String,
+    options: ToastOptions,
+    anchor: Anchor,
+    /// Time left until the toast expires
+    /// `None` if it is shown until closed
+    remaining: Option<Duration>,
+}
+
+impl Toast {
+    /// Icon and colour of the toast's level
+    fn icon(&self) -> Option<(&str, egui::Color32)> {
+        match &self.options.level {
+            ToastLevel::Info => Some(("ℹ", egui::Color32::from_rgb(0, 155, 255))),
+            ToastLevel::Warning => Some(("⚠", egui::Color32::from_rgb(255, 212, 0))),
+            ToastLevel::Error => Some(("！", egui::Color32::from_rgb(255, 32, 0))),
+            ToastLevel::Success => Some(("✔", egui::Color32::from_rgb(140, 230, 140))),
+            ToastLevel::Custom(icon, colour) => Some((icon.as_str(), *colour)),
+            ToastLevel::None => None,
+        }
+    }
+
+    /// Render the toast, and return `true` if it was closed
+    #[allow(clippy::cast_possible_truncation)]
+    fn show(&self, ui: &mut egui::Ui) -> bool {
+        let mut closed = false;
+        egui::Frame::popup(ui.style()).show(ui, |ui| {
+            ui.horizontal(|ui| {
+                if let Some((icon, colour)) = self.icon() {
+                    ui.label(egui::RichText::new(icon).color(colour));
+                }
+                ui.label(&self.caption);
+                if self.options.closable {
+                    closed = ui.small_button("🗙").clicked();
+                }
+            });
+
+            if let Some(ref progress) = self.options.progress {
+                ui.add(egui::ProgressBar::new(progress.fraction() as f32).show_percentage());
+            } else if let (true, Some(duration), Some(remaining)) = (
+                self.options.show_progress_bar,
+                self.options.duration,
+                self.remaining,
+            ) {
+                let fraction = remaining.as_secs_f64() / duration.as_secs_f64();
+                ui.add(egui::ProgressBar::new(fraction as f32).desired_width(ui.available_width()));
+            }
+        });
+        closed
     }
 }
 
+/// **Bevy** [`Resource`] holding the toasts currently shown
 #[derive(Resource)]
-struct Toasts {
-    toasts: egui_notify::Toasts,
-    max:    NonZeroU8,
+pub struct Toasts {
+    /// Anchor used for toasts that do not set [`ToastOptions::anchor`]
+    default_anchor: Anchor,
+    /// Toasts currently shown at every anchor, oldest first
+    toasts: Vec<Toast>,
+    max: NonZeroU8,
 }
 
 impl Toasts {
-    const fn new(toasts: egui_notify::Toasts, max: NonZeroU8) -> Self {
-        Self { toasts, max }
+    /// Space between the toasts and the edge of the window
+    const MARGIN: f32 = 8.0;
+
+    const fn new(default_anchor: Anchor, max: NonZeroU8) -> Self {
+        Self {
+            default_anchor,
+            toasts: Vec::new(),
+            max,
+        }
     }
 
-    fn add(&mut self, toast: egui_notify::Toast) {
-        if self.toasts.len() == self.max.get() as usize {
-            self.toasts.remove_oldest_toast();
+    fn add(&mut self, id: ToastId, caption: &str, options: &ToastOptions) {
+        if let Some(ref dedup_key) = options.dedup_key {
+            if let Some(toast) = self
+                .toasts
+                .iter_mut()
+                .find(|toast| toast.options.dedup_key.as_ref() == Some(dedup_key))
+            {
+                trace!("refreshing toast with duplicate dedup key: {}", dedup_key);
+                toast.id = id;
+                toast.remaining = options.duration;
+                return;
+            }
+        }
+
+        let anchor = options.anchor.unwrap_or(self.default_anchor);
+        let max = self.max.get() as usize;
+        if self.len_at(anchor) == max {
+            if let Some(oldest) = self.toasts.iter().position(|toast| toast.anchor == anchor) {
+                self.toasts.remove(oldest);
+            }
             debug!(
                 "removed the oldest toast to satisfy the set max constraint: {}",
-                self.max.get()
+                max
             );
         }
-        self.toasts.add(toast);
+        self.toasts.push(Toast {
+            id,
+            caption: caption.to_string(),
+            options: options.clone(),
+            anchor,
+            // progress toasts are shown until their operation finishes
+            remaining: options.duration.filter(|_| options.progress.is_none()),
+        });
     }
 
+    /// Number of toasts currently shown
     #[inline]
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.toasts.len()
+    }
+
+    /// Number of toasts currently shown at `anchor`
+    #[must_use]
+    pub fn len_at(&self, anchor: Anchor) -> usize {
+        self.toasts
+            .iter()
+            .filter(|toast| toast.anchor == anchor)
+            .count()
+    }
+
+    /// Returns `true` if no toasts are shown
+    #[inline]
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Remove the toast added by the [`ToastEvent`] with `id` immediately
+    /// Returns `false` if no such toast is shown
+    pub fn dismiss(&mut self, id: ToastId) -> bool {
+        let len = self.toasts.len();
+        self.toasts.retain(|toast| toast.id != id);
+        self.toasts.len() != len
+    }
+
+    /// Remove all toasts immediately
+    pub fn dismiss_all(&mut self) {
+        self.toasts.clear();
+    }
+
+    /// Advance the time of every toast by `delta`, and remove the ones that
+    /// have expired
+    fn tick(&mut self, delta: Duration) {
+        for toast in &mut self.toasts {
+            if let Some(ref mut remaining) = toast.remaining {
+                *remaining = remaining.saturating_sub(delta);
+            }
+        }
+        self.toasts.retain(|toast| {
+            toast
+                .remaining
+                .map_or(true, |remaining| !remaining.is_zero())
+        });
+    }
+
+    /// Remove the progress toasts whose operation has finished
+    fn remove_finished_progress(&mut self) {
+        self.toasts.retain(|toast| {
+            toast
+                .options
+                .progress
+                .as_ref()
+                .map_or(true, |progress| !progress.is_finished())
+        });
+    }
+
+    /// Where on the screen toasts at `anchor` are rendered
+    const fn align(anchor: Anchor) -> egui::Align2 {
+        match anchor {
+            Anchor::TopLeft => egui::Align2::LEFT_TOP,
+            Anchor::TopCenter => egui::Align2::CENTER_TOP,
+            Anchor::TopRight => egui::Align2::RIGHT_TOP,
+            Anchor::BottomLeft => egui::Align2::LEFT_BOTTOM,
+            Anchor::BottomCenter => egui::Align2::CENTER_BOTTOM,
+            Anchor::BottomRight => egui::Align2::RIGHT_BOTTOM,
+        }
+    }
+
     fn show(&mut self, ctx: &egui::Context) {
-        self.toasts.show(ctx);
+        let mut anchors: Vec<Anchor> = Vec::new();
+        for toast in &self.toasts {
+            if !anchors.contains(&toast.anchor) {
+                anchors.push(toast.anchor);
+            }
+        }
+
+        let mut closed = Vec::new();
+        for anchor in anchors {
+            let align = Self::align(anchor);
+            let offset = -egui::vec2(align.x().to_sign(), align.y().to_sign()) * Self::MARGIN;
+            egui::Area::new(egui::Id::new("bevy_notify_toasts").with(align))
+                .anchor(align, offset)
+                .show(ctx, |ui| {
+                    for (index, toast) in self.toasts.iter().enumerate() {
+                        if toast.anchor == anchor && toast.show(ui) {
+                            closed.push(index);
+                        }
+                    }
+                });
+        }
+        let mut index = 0;
+        self.toasts.retain(|_| {
+            index += 1;
+            !closed.contains(&(index - 1))
+        });
+    }
+}
+
+/// Handle for updating the progress shown by a progress toast
+/// Created together with the toast by [`ToastEvent::progress`]
+/// The toast is removed once the progress reaches 1.0
+#[derive(Debug, Clone, Default)]
+pub struct ProgressToast(Arc<AtomicU64>);
+
+impl ProgressToast {
+    /// Set the progress of the operation, as a fraction in [0, 1]
+    /// Values outside the range are clamped
+    pub fn update(&self, fraction: f64) {
+        self.0
+            .store(fraction.clamp(0.0, 1.0).to_bits(), Ordering::Relaxed);
+    }
+
+    /// The progress of the operation, as a fraction in [0, 1]
+    #[must_use]
+    pub fn fraction(&self) -> f64 {
+        f64::from_bits(self.0.load(Ordering::Relaxed))
+    }
+
+    /// Returns `true` if the operation has finished, i.e. the progress is 1.0
+    #[must_use]
+    pub fn is_finished(&self) -> bool {
+        self.fraction() >= 1.0
+    }
+}
+
+/// Identifies the toast added by a [`ToastEvent`], e.g. to dismiss it with
+/// [`DismissToast`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ToastId(u64);
+
+impl ToastId {
+    /// Create a new id, different from every id created before it
+    #[must_use]
+    #[allow(clippy::new_without_default)]
+    pub fn new() -> Self {
+        static NEXT: AtomicU64 = AtomicU64::new(0);
+        Self(NEXT.fetch_add(1, Ordering::Relaxed))
     }
 }
 
 /// Event for creating a toast
 #[derive(Debug, Event)]
 pub struct ToastEvent {
+    /// Id of the toast, used to dismiss it with [`DismissToast`]
+    /// If the toast refreshes a toast already shown, see
+    /// [`ToastOptions::dedup_key`], the shown toast takes over this id
+    pub id:      ToastId,
     /// The caption of the toast
     pub caption: String,
     /// Options for the toast, to configure properties like duration, closable,
@@ -124,6 +388,7 @@ impl ToastEvent {
     #[must_use]
     pub fn info(caption: impl Into<String>) -> Self {
         Self {
+            id:      ToastId::new(),
             caption: caption.into(),
             options: ToastOptions {
                 level: ToastLevel::Info,
@@ -137,6 +402,7 @@ impl ToastEvent {
     #[must_use]
     pub fn success(caption: impl Into<String>) -> Self {
         Self {
+            id:      ToastId::new(),
             caption: caption.into(),
             options: ToastOptions {
                 level: ToastLevel::Success,
@@ -150,6 +416,7 @@ impl ToastEvent {
     #[must_use]
     pub fn error(caption: impl Into<String>) -> Self {
         Self {
+            id:      ToastId::new(),
             caption: caption.into(),
             options: ToastOptions {
                 level: ToastLevel::Error,
@@ -163,6 +430,7 @@ impl ToastEvent {
     #[must_use]
     pub fn warning(caption: impl Into<String>) -> Self {
         Self {
+            id:      ToastId::new(),
             caption: caption.into(),
             options: ToastOptions {
                 level: ToastLevel::Warning,
@@ -176,6 +444,7 @@ impl ToastEvent {
     #[must_use]
     pub fn custom(caption: impl Into<String>, level: ToastLevel) -> Self {
         Self {
+            id:      ToastId::new(),
             caption: caption.into(),
             options: ToastOptions {
                 level,
@@ -183,6 +452,110 @@ impl ToastEvent {
             },
         }
     }
+
+    /// Create a progress toast, and the handle used to update its progress
+    /// The toast is shown until the progress reaches 1.0
+    #[must_use]
+    pub fn progress(caption: impl Into<String>) -> (Self, ProgressToast) {
+        let progress = ProgressToast::default();
+        let event = Self {
+            id:      ToastId::new(),
+            caption: caption.into(),
+            options: ToastOptions {
+                level: ToastLevel::Info,
+                duration: None,
+                progress: Some(progress.clone()),
+                ..Default::default()
+            },
+        };
+        (event, progress)
+    }
+
+    /// Create a builder for a toast, to configure options without
+    /// constructing `ToastOptions` manually
+    /// All options start out as in `ToastOptions::default()`
+    ///
+    /// # Examples
+    /// ```rust
+    /// use bevy_notify::prelude::*;
+    ///
+    /// let toast = ToastEvent::builder("saved")
+    ///     .level(ToastLevel::Success)
+    ///     .closable(false)
+    ///     .show_progress_bar(false)
+    ///     .build();
+    /// ```
+    #[must_use]
+    pub fn builder(caption: impl Into<String>) -> ToastEventBuilder {
+        ToastEventBuilder {
+            caption: caption.into(),
+            options: ToastOptions::default(),
+        }
+    }
+}
+
+/// Builder for a [`ToastEvent`]
+/// Created with [`ToastEvent::builder`]
+#[derive(Debug, Clone)]
+pub struct ToastEventBuilder {
+    caption: String,
+    options: ToastOptions,
+}
+
+impl ToastEventBuilder {
+    /// Set the level of the toast
+    #[must_use]
+    pub fn level(mut self, level: ToastLevel) -> Self {
+        self.options.level = level;
+        self
+    }
+
+    /// Set the duration of the toast
+    /// If `None`, the toast exists until closed
+    #[must_use]
+    pub const fn duration(mut self, duration: Option<Duration>) -> Self {
+        self.options.duration = duration;
+        self
+    }
+
+    /// Set whether the toast can be closed by using the mouse
+    #[must_use]
+    pub const fn closable(mut self, closable: bool) -> Self {
+        self.options.closable = closable;
+        self
+    }
+
+    /// Set whether to show a progress bar
+    #[must_use]
+    pub const fn show_progress_bar(mut self, show_progress_bar: bool) -> Self {
+        self.options.show_progress_bar = show_progress_bar;
+        self
+    }
+
+    /// Set the key used to deduplicate the toast
+    /// See [`ToastOptions::dedup_key`]
+    #[must_use]
+    pub fn dedup_key(mut self, dedup_key: impl Into<String>) -> Self {
+        self.options.dedup_key = Some(dedup_key.into());
+        self
+    }
+
+    /// Set where to render the toast
+    #[must_use]
+    pub const fn anchor(mut self, anchor: Anchor) -> Self {
+        self.options.anchor = Some(anchor);
+        self
+    }
+
+    /// Create the [`ToastEvent`]
+    #[must_use]
+    pub fn build(self) -> ToastEvent {
+        ToastEvent {
+            id:      ToastId::new(),
+            caption: self.caption,
+            options: self.options,
+        }
+    }
 }
 
 /// Options for the toast
@@ -201,6 +574,23 @@ pub struct ToastOptions {
     /// Whether the toast can be closed by using the mouse
     /// Defaults to true
     pub closable: bool,
+    /// Key used to deduplicate toasts
+    /// Defaults to `None`
+    /// If set, a toast is not added while another toast with the same key is
+    /// still shown, e.g. to avoid filling the screen with an error sent every
+    /// frame. Instead the duration of the shown toast starts over
+    pub dedup_key: Option<String>,
+    /// Where to render the toast
+    /// Defaults to `None`
+    /// If `None`, the toast is rendered at the
+    /// [anchor](struct.NotifyPlugin.html#structfield.anchor) of the plugin
+    pub anchor: Option<Anchor>,
+    /// Handle to the progress shown by the toast
+    /// Defaults to `None`
+    /// If set, the toast shows the progress reported through the handle
+    /// instead of its remaining duration, and is removed once the progress
+    /// reaches 1.0. See [`ToastEvent::progress`]
+    pub progress: Option<ProgressToast>,
 }
 
 impl Default for ToastOptions {
@@ -211,29 +601,330 @@ impl Default for ToastOptions {
             level: ToastLevel::default(),
             show_progress_bar: true,
             closable: true,
+            dedup_key: None,
+            anchor: None,
+            progress: None,
         }
     }
 }
 
-fn update_toasts(
-    mut egui_ctx: EguiContexts,
-    mut toasts: ResMut<Toasts>,
-    mut toast_event: EventReader<ToastEvent>,
-) {
+/// Event for removing the toast with the given id, if it is still shown
+#[derive(Debug, Clone, Copy, Event)]
+pub struct DismissToast {
+    /// Id of the [`ToastEvent`] that added the toast
+    pub id: ToastId,
+}
+
+/// Event for removing all toasts currently shown
+/// e.g. when a simulation is reset
+#[derive(Debug, Default, Clone, Copy, Event)]
+pub struct DismissAllToasts;
+
+fn add_toasts(mut toasts: ResMut<Toasts>, mut toast_event: EventReader<ToastEvent>) {
     for ToastEvent {
+        id,
         caption,
         ref options,
     } in toast_event.read()
     {
         debug!("received toast event");
         trace!("toast, caption: {}, options: {:?}", caption, options);
-        let mut toast = egui_notify::Toast::custom(caption, options.level.clone());
-        toast
-            .set_closable(options.closable)
-            .set_show_progress_bar(options.show_progress_bar)
-            .set_duration(options.duration);
-        toasts.add(toast);
+        toasts.add(*id, caption, options);
     }
+}
 
+fn dismiss_toasts(mut toasts: ResMut<Toasts>, mut dismiss_event: EventReader<DismissToast>) {
+    for DismissToast { id } in dismiss_event.read() {
+        if !toasts.dismiss(*id) {
+            debug!("toast to dismiss is no longer shown: {:?}", id);
+        }
+    }
+}
+
+fn dismiss_all_toasts(
+    mut toasts: ResMut<Toasts>,
+    mut dismiss_all_event: EventReader<DismissAllToasts>,
+) {
+    if dismiss_all_event.read().count() > 0 {
+        debug!("dismissing all {} toasts", toasts.len());
+        toasts.dismiss_all();
+    }
+}
+
+fn expire_toasts(mut toasts: ResMut<Toasts>, time: Res<Time<Real>>) {
+    toasts.tick(time.delta());
+}
+
+fn remove_finished_progress_toasts(mut toasts: ResMut<Toasts>) {
+    toasts.remove_finished_progress();
+}
+
+fn show_toasts(mut egui_ctx: EguiContexts, mut toasts: ResMut<Toasts>) {
     toasts.show(egui_ctx.ctx_mut());
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Headless app with the toast systems that do not depend on an egui
+    /// context
+    fn headless_app() -> App {
+        let mut app = App::new();
+        app.insert_resource(Toasts::new(
+            Anchor::TopCenter,
+            NonZeroU8::new(5).expect("5 > 0"),
+        ))
+        .init_resource::<Time<Real>>()
+        .add_event::<ToastEvent>()
+        .add_event::<DismissToast>()
+        .add_event::<DismissAllToasts>()
+        .add_systems(
+            Update,
+            (
+                add_toasts,
+                dismiss_toasts,
+                dismiss_all_toasts,
+                expire_toasts,
+                remove_finished_progress_toasts,
+            )
+                .chain(),
+        );
+        app
+    }
+
+    #[test]
+    fn dismiss_all_removes_every_toast() {
+        let mut app = headless_app();
+
+        app.world.send_event(ToastEvent::info("first"));
+        app.world.send_event(ToastEvent::warning("second"));
+        app.world.send_event(ToastEvent::error("third"));
+        app.update();
+        assert_eq!(app.world.resource::<Toasts>().len(), 3);
+
+        app.world.send_event(DismissAllToasts);
+        app.update();
+        assert!(app.world.resource::<Toasts>().is_empty());
+    }
+
+    #[test]
+    fn dismiss_removes_only_that_toast() {
+        let mut app = headless_app();
+
+        let ids = ["first", "second", "third"].map(|caption| {
+            let toast = ToastEvent::info(caption);
+            let id = toast.id;
+            app.world.send_event(toast);
+            id
+        });
+        app.update();
+        assert_eq!(app.world.resource::<Toasts>().len(), 3);
+
+        app.world.send_event(DismissToast { id: ids[1] });
+        app.update();
+
+        let shown = app
+            .world
+            .resource::<Toasts>()
+            .toasts
+            .iter()
+            .map(|toast| toast.id)
+            .collect::<Vec<_>>();
+        assert_eq!(shown, [ids[0], ids[2]]);
+    }
+
+    #[test]
+    fn toasts_with_same_dedup_key_are_shown_once() {
+        let mut app = headless_app();
+
+        for _ in 0..5 {
+            app.world.send_event(ToastEvent {
+                id:      ToastId::new(),
+                caption: "config failed to load".into(),
+                options: ToastOptions {
+                    level: ToastLevel::Error,
+                    dedup_key: Some("config".into()),
+                    ..Default::default()
+                },
+            });
+        }
+        app.update();
+        assert_eq!(app.world.resource::<Toasts>().len(), 1);
+
+        app.world
+            .send_event(ToastEvent::error("config failed to load"));
+        app.update();
+        assert_eq!(
+            app.world.resource::<Toasts>().len(),
+            2,
+            "toasts without a dedup key are never deduplicated"
+        );
+    }
+
+    #[test]
+    fn repeated_dedup_key_refreshes_the_shown_toast() {
+        let mut app = headless_app();
+        let toast = || {
+            ToastEvent::builder("config failed to load")
+                .duration(Some(Duration::from_secs(1)))
+                .dedup_key("config")
+                .build()
+        };
+
+        app.world.send_event(toast());
+        app.update();
+        app.world
+            .resource_mut::<Toasts>()
+            .tick(Duration::from_millis(600));
+
+        app.world.send_event(toast());
+        app.update();
+        app.world
+            .resource_mut::<Toasts>()
+            .tick(Duration::from_millis(600));
+        assert_eq!(
+            app.world.resource::<Toasts>().len(),
+            1,
+            "the toast expired even though it was refreshed"
+        );
+
+        app.world
+            .resource_mut::<Toasts>()
+            .tick(Duration::from_millis(600));
+        assert!(app.world.resource::<Toasts>().is_empty());
+    }
+
+    #[test]
+    fn evicted_toast_no_longer_deduplicates() {
+        let mut app = headless_app();
+        let toast = || {
+            ToastEvent::builder("config failed to load")
+                .dedup_key("config")
+                .build()
+        };
+
+        app.world.send_event(toast());
+        for i in 0..5 {
+            app.world.send_event(ToastEvent::info(format!("toast {i}")));
+        }
+        app.update();
+        let is_shown = |app: &App| {
+            app.world
+                .resource::<Toasts>()
+                .toasts
+                .iter()
+                .any(|toast| toast.options.dedup_key.as_deref() == Some("config"))
+        };
+        assert!(!is_shown(&app), "the oldest toast is evicted");
+
+        app.world.send_event(toast());
+        app.update();
+        assert!(is_shown(&app));
+    }
+
+    #[test]
+    fn toasts_are_rendered_at_their_own_anchor() {
+        let mut app = headless_app();
+
+        app.world.send_event(ToastEvent {
+            id:      ToastId::new(),
+            caption: "saved".into(),
+            options: ToastOptions {
+                level: ToastLevel::Success,
+                anchor: Some(Anchor::BottomCenter),
+                ..Default::default()
+            },
+        });
+        app.world.send_event(ToastEvent::error("failed to load"));
+        app.update();
+
+        let mut toasts = app.world.resource_mut::<Toasts>();
+        let ctx = egui::Context::default();
+        let _ = ctx.run(egui::RawInput::default(), |ctx| toasts.show(ctx));
+
+        assert_eq!(toasts.len(), 2);
+        assert_eq!(toasts.len_at(Anchor::BottomCenter), 1);
+        assert_eq!(toasts.len_at(Anchor::TopCenter), 1);
+    }
+
+    #[test]
+    fn finished_progress_toast_is_removed() {
+        let mut app = headless_app();
+
+        let (event, progress) = ToastEvent::progress("planning path");
+        app.world.send_event(event);
+        app.update();
+        assert_eq!(app.world.resource::<Toasts>().len(), 1);
+
+        progress.update(0.5);
+        app.update();
+        assert_eq!(app.world.resource::<Toasts>().len(), 1);
+
+        progress.update(1.0);
+        app.update();
+        assert!(app.world.resource::<Toasts>().is_empty());
+    }
+
+    #[test]
+    fn progress_toasts_are_stacked_with_other_toasts() {
+        let mut app = headless_app();
+
+        let (mut event, _progress) = ToastEvent::progress("planning path");
+        event.options.anchor = Some(Anchor::BottomCenter);
+        app.world.send_event(event);
+        app.update();
+        assert_eq!(
+            app.world.resource::<Toasts>().len_at(Anchor::BottomCenter),
+            1
+        );
+
+        for i in 0..5 {
+            app.world.send_event(ToastEvent {
+                id:      ToastId::new(),
+                caption: format!("toast {i}"),
+                options: ToastOptions {
+                    anchor: Some(Anchor::BottomCenter),
+                    ..Default::default()
+                },
+            });
+        }
+        app.update();
+
+        let toasts = app.world.resource::<Toasts>();
+        assert_eq!(toasts.len_at(Anchor::BottomCenter), 5);
+        assert!(
+            toasts
+                .toasts
+                .iter()
+                .all(|toast| toast.options.progress.is_none()),
+            "the progress toast is the oldest, and is evicted first"
+        );
+    }
+
+    #[test]
+    fn builder_matches_hand_written_event() {
+        let built = ToastEvent::builder("saved settings to config.toml")
+            .level(ToastLevel::Success)
+            .duration(Some(Duration::from_millis(500)))
+            .closable(false)
+            .show_progress_bar(false)
+            .build();
+
+        let expected = ToastEvent {
+            id:      built.id,
+            caption: "saved settings to config.toml".into(),
+            options: ToastOptions {
+                duration: Some(Duration::from_millis(500)),
+                level: ToastLevel::Success,
+                show_progress_bar: false,
+                closable: false,
+                ..Default::default()
+            },
+        };
+
+        // `ToastLevel` is defined in `egui_notify`, so compare the debug
+        // representations
+        assert_eq!(format!("{built:?}"), format!("{expected:?}"));
+    }
+}
@@ -1,5 +1,10 @@
 //! Bevy touchpad plugin
-use bevy::{input::mouse::MouseWheel, prelude::*};
+use std::{cmp::Ordering, time::Duration};
+
+use bevy::{
+    input::mouse::{MouseScrollUnit, MouseWheel},
+    prelude::*,
+};
 
 /// prelude module to bring entire public API into scope
 pub mod prelude {
@@ -7,21 +12,87 @@ pub mod prelude {
 }
 
 /// **Bevy** plugin that listens for two finger swipes
-#[derive(Debug, Default)]
-pub struct BevyTouchpadPlugin;
+#[derive(Debug, Clone, Copy)]
+pub struct BevyTouchpadPlugin {
+    /// Swipes with a magnitude below this are ignored, to filter out tiny
+    /// accidental scroll deltas. Measured in pixels
+    pub min_magnitude: f32,
+    /// Scroll deltas received within this window, counted from the first one,
+    /// are combined into a single swipe. With a zero window the deltas
+    /// received in the same frame are combined
+    /// Measured in real time, so it is unaffected by pausing or scaling
+    /// [`Time<Virtual>`]
+    pub accumulation_window: Duration,
+    /// Flip the sign of the horizontal scroll delta, for platforms or
+    /// settings where it is reversed
+    pub invert_x: bool,
+    /// Flip the sign of the vertical scroll delta, e.g. with natural
+    /// scrolling, where "up" swipes would otherwise register as "down"
+    pub invert_y: bool,
+    /// Approximate number of pixels scrolled per line, used to convert
+    /// [`MouseScrollUnit::Line`] deltas to pixels, so swipe magnitudes are
+    /// comparable across devices
+    pub line_to_pixels: f32,
+}
+
+impl Default for BevyTouchpadPlugin {
+    fn default() -> Self {
+        Self {
+            min_magnitude: 0.0,
+            accumulation_window: Duration::ZERO,
+            invert_x: false,
+            invert_y: false,
+            line_to_pixels: Self::DEFAULT_LINE_TO_PIXELS,
+        }
+    }
+}
+
+impl BevyTouchpadPlugin {
+    /// Default number of pixels per scrolled line
+    pub const DEFAULT_LINE_TO_PIXELS: f32 = 20.0;
+
+    /// Scroll delta of `event` in pixels, with the configured axis inversions
+    /// applied
+    fn interpret(&self, event: &MouseWheel) -> Vec2 {
+        let scale = match event.unit {
+            MouseScrollUnit::Line => self.line_to_pixels,
+            MouseScrollUnit::Pixel => 1.0,
+        };
+        let sign = |invert: bool| if invert { -1.0 } else { 1.0 };
+        Vec2::new(
+            event.x * scale * sign(self.invert_x),
+            event.y * scale * sign(self.invert_y),
+        )
+    }
+}
 
 impl Plugin for BevyTouchpadPlugin {
     fn build(&self, app: &mut App) {
         app.add_event::<TwoFingerSwipe>()
+            .insert_resource(SwipeAccumulator {
+                settings:   *self,
+                delta:      Vec2::ZERO,
+                started_at: None,
+            })
             .add_systems(Update, detect_two_finger_swipe);
     }
 }
 
+/// **Bevy** [`Resource`] with the scroll deltas accumulated in the current
+/// accumulation window
+#[derive(Debug, Resource)]
+struct SwipeAccumulator {
+    settings:   BevyTouchpadPlugin,
+    delta:      Vec2,
+    /// When the first delta of the current window was received
+    started_at: Option<Duration>,
+}
+
 /// **Bevy** event that contains the magnitude and direction of a two finger
 /// swipe
 #[derive(Debug, Clone, Copy, Event)]
 pub struct TwoFingerSwipe {
-    /// The magnitude of the swipe, guaranteeed to > 0.0
+    /// The magnitude of the swipe in pixels, guaranteeed to > 0.0
     pub magnitude: f32,
     /// The direction of the swipe
     pub direction: TwoFingerSwipeDirection,
@@ -38,76 +109,269 @@ pub enum TwoFingerSwipeDirection {
     Left,
     /// Right
     Right,
-    // UpLeft,
-    // UpRight,
-    // DownLeft,
-    // DownRight,
+    /// Up and to the left
+    UpLeft,
+    /// Up and to the right
+    UpRight,
+    /// Down and to the left
+    DownLeft,
+    /// Down and to the right
+    DownRight,
+}
+
+/// Direction of a swipe with delta `(x, y)`
+/// Returns `None` if the delta is zero or NaN
+fn swipe_direction(x: f32, y: f32) -> Option<TwoFingerSwipeDirection> {
+    let direction = match (x.partial_cmp(&0.0)?, y.partial_cmp(&0.0)?) {
+        (Ordering::Equal, Ordering::Equal) => return None,
+        (Ordering::Equal, Ordering::Less) => TwoFingerSwipeDirection::Up,
+        (Ordering::Equal, Ordering::Greater) => TwoFingerSwipeDirection::Down,
+        (Ordering::Less, Ordering::Equal) => TwoFingerSwipeDirection::Left,
+        (Ordering::Greater, Ordering::Equal) => TwoFingerSwipeDirection::Right,
+        (Ordering::Less, Ordering::Less) => TwoFingerSwipeDirection::UpLeft,
+        (Ordering::Greater, Ordering::Less) => TwoFingerSwipeDirection::UpRight,
+        (Ordering::Less, Ordering::Greater) => TwoFingerSwipeDirection::DownLeft,
+        (Ordering::Greater, Ordering::Greater) => TwoFingerSwipeDirection::DownRight,
+    };
+    Some(direction)
 }
 
 /// **Bevy** system that detects two finger swipes
 fn detect_two_finger_swipe(
     mut evr_scroll: EventReader<MouseWheel>,
     mut evw_two_finger_swipe: EventWriter<TwoFingerSwipe>,
+    mut accumulator: ResMut<SwipeAccumulator>,
+    time: Res<Time<Real>>,
 ) {
     for event in evr_scroll.read() {
-        // let MouseScrollUnit::Pixel = event.unit else {
-        //     return;
-        // };
-
-        let (x, y) = (event.x, event.y);
-        // println!("x: {x}, y: {y}");
-
-        if x == 0.0 && y == 0.0 {
+        let delta = accumulator.settings.interpret(event);
+        if delta == Vec2::ZERO || delta.is_nan() {
             continue;
         }
 
-        // let direction = if x > 0.0 {
-        //     if y > 0.0 {
-        //         TwoFingerSwipeDirection::UpRight
-        //     } else if y < 0.0 {
-        //         TwoFingerSwipeDirection::DownRight
-        //     } else {
-        //         TwoFingerSwipeDirection::Right
-        //     }
-        // } else if x < 0.0 {
-        //     if y > 0.0 {
-        //         TwoFingerSwipeDirection::UpLeft
-        //     } else if y < 0.0 {
-        //         TwoFingerSwipeDirection::DownLeft
-        //     } else {
-        //         TwoFingerSwipeDirection::Left
-        //     }
-        // } else {
-        //     if y > 0.0 {
-        //         TwoFingerSwipeDirection::Up
-        //     } else if y < 0.0 {
-        //         TwoFingerSwipeDirection::Down
-        //     } else {
-        //         continue;
-        //     }
-        // };
-        //
-
-        let (direction, magnitude) = if x == 0.0 {
-            if y < 0.0 {
-                (TwoFingerSwipeDirection::Up, y.abs())
-            } else {
-                (TwoFingerSwipeDirection::Down, y)
-            }
-        } else if y == 0.0 {
-            if x < 0.0 {
-                (TwoFingerSwipeDirection::Left, x.abs())
-            } else {
-                (TwoFingerSwipeDirection::Right, x)
-            }
-        } else {
-            continue;
+        accumulator.delta += delta;
+        accumulator.started_at.get_or_insert(time.elapsed());
+    }
+
+    let Some(started_at) = accumulator.started_at else {
+        return;
+    };
+    if time.elapsed() - started_at < accumulator.settings.accumulation_window {
+        return;
+    }
+
+    let delta = std::mem::take(&mut accumulator.delta);
+    accumulator.started_at = None;
+
+    let magnitude = delta.length();
+    if magnitude < accumulator.settings.min_magnitude {
+        return;
+    }
+    let Some(direction) = swipe_direction(delta.x, delta.y) else {
+        return;
+    };
+
+    evw_two_finger_swipe.send(TwoFingerSwipe {
+        magnitude,
+        direction,
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn app_with(plugin: BevyTouchpadPlugin) -> App {
+        let mut app = App::new();
+        app.init_resource::<Time<Real>>()
+            .add_event::<MouseWheel>()
+            .add_plugins(plugin);
+        app
+    }
+
+    fn scroll(app: &mut App, x: f32, y: f32) {
+        scroll_in(app, MouseScrollUnit::Pixel, x, y);
+    }
+
+    fn scroll_in(app: &mut App, unit: MouseScrollUnit, x: f32, y: f32) {
+        app.world.send_event(MouseWheel {
+            unit,
+            x,
+            y,
+            window: Entity::PLACEHOLDER,
+        });
+    }
+
+    fn swipes(app: &App) -> Vec<TwoFingerSwipe> {
+        let events = app.world.resource::<Events<TwoFingerSwipe>>();
+        events.get_reader().read(events).copied().collect()
+    }
+
+    /// Send a single `MouseWheel` event with delta `(x, y)` through the plugin,
+    /// and return the swipes detected
+    fn swipes_for_scroll(x: f32, y: f32) -> Vec<TwoFingerSwipe> {
+        let mut app = app_with(BevyTouchpadPlugin::default());
+        scroll(&mut app, x, y);
+        app.update();
+        swipes(&app)
+    }
+
+    #[test]
+    fn diagonal_swipe_is_not_swallowed() {
+        let swipes = swipes_for_scroll(1.0, 1.0);
+        assert_eq!(swipes.len(), 1);
+        assert_eq!(swipes[0].direction, TwoFingerSwipeDirection::DownRight);
+        assert!((swipes[0].magnitude - 2.0_f32.sqrt()).abs() < f32::EPSILON);
+
+        let swipes = swipes_for_scroll(1.0, -1.0);
+        assert_eq!(swipes.len(), 1);
+        assert_eq!(swipes[0].direction, TwoFingerSwipeDirection::UpRight);
+    }
+
+    #[test]
+    fn straight_swipes_keep_their_direction() {
+        let directions = [(0.0, -2.0), (0.0, 2.0), (-2.0, 0.0), (2.0, 0.0)]
+            .into_iter()
+            .flat_map(|(x, y)| swipes_for_scroll(x, y))
+            .map(|swipe| swipe.direction)
+            .collect::<Vec<_>>();
+        assert_eq!(directions, [
+            TwoFingerSwipeDirection::Up,
+            TwoFingerSwipeDirection::Down,
+            TwoFingerSwipeDirection::Left,
+            TwoFingerSwipeDirection::Right,
+        ]);
+        assert!(swipes_for_scroll(0.0, 0.0).is_empty());
+    }
+
+    #[test]
+    fn swipes_below_min_magnitude_are_ignored() {
+        let plugin = BevyTouchpadPlugin {
+            min_magnitude: 5.0,
+            ..Default::default()
         };
 
-        evw_two_finger_swipe.send(TwoFingerSwipe {
-            // magnitude: (x * x + y * y).sqrt(),
-            magnitude,
-            direction,
+        let mut app = app_with(plugin);
+        scroll(&mut app, 0.0, 1.0);
+        app.update();
+        assert!(swipes(&app).is_empty());
+
+        let mut app = app_with(plugin);
+        scroll(&mut app, 0.0, 10.0);
+        app.update();
+        assert_eq!(swipes(&app).len(), 1);
+    }
+
+    #[test]
+    fn small_deltas_within_window_are_combined() {
+        let mut app = app_with(BevyTouchpadPlugin {
+            min_magnitude: 5.0,
+            accumulation_window: Duration::from_millis(100),
+            ..Default::default()
+        });
+
+        for _ in 0..3 {
+            scroll(&mut app, 2.0, 0.0);
+            app.update();
+            app.world
+                .resource_mut::<Time<Real>>()
+                .advance_by(Duration::from_millis(20));
+        }
+        assert!(swipes(&app).is_empty(), "the window has not ended yet");
+
+        app.world
+            .resource_mut::<Time<Real>>()
+            .advance_by(Duration::from_millis(100));
+        app.update();
+
+        let swipes = swipes(&app);
+        assert_eq!(swipes.len(), 1);
+        assert_eq!(swipes[0].direction, TwoFingerSwipeDirection::Right);
+        assert!((swipes[0].magnitude - 6.0).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn window_ends_while_virtual_time_is_paused() {
+        let mut app = app_with(BevyTouchpadPlugin {
+            accumulation_window: Duration::from_millis(100),
+            ..Default::default()
         });
+        let mut virtual_time = Time::<Virtual>::default();
+        virtual_time.pause();
+        app.insert_resource(virtual_time);
+
+        scroll(&mut app, 2.0, 0.0);
+        app.update();
+        assert!(swipes(&app).is_empty(), "the window has not ended yet");
+
+        app.world
+            .resource_mut::<Time<Real>>()
+            .advance_by(Duration::from_millis(100));
+        app.update();
+        assert_eq!(swipes(&app).len(), 1);
+    }
+
+    #[test]
+    fn inverted_axes_flip_the_direction() {
+        let direction_of = |plugin: BevyTouchpadPlugin, x: f32, y: f32| {
+            let mut app = app_with(plugin);
+            scroll(&mut app, x, y);
+            app.update();
+            swipes(&app).first().map(|swipe| swipe.direction)
+        };
+
+        let inverted = BevyTouchpadPlugin {
+            invert_x: true,
+            invert_y: true,
+            ..Default::default()
+        };
+
+        assert_eq!(
+            direction_of(BevyTouchpadPlugin::default(), 0.0, -3.0),
+            Some(TwoFingerSwipeDirection::Up)
+        );
+        assert_eq!(
+            direction_of(inverted, 0.0, -3.0),
+            Some(TwoFingerSwipeDirection::Down)
+        );
+        assert_eq!(
+            direction_of(BevyTouchpadPlugin::default(), -3.0, 0.0),
+            Some(TwoFingerSwipeDirection::Left)
+        );
+        assert_eq!(
+            direction_of(inverted, -3.0, 0.0),
+            Some(TwoFingerSwipeDirection::Right)
+        );
+    }
+
+    #[test]
+    fn line_deltas_are_converted_to_pixels() {
+        let magnitude_of = |unit: MouseScrollUnit, y: f32| {
+            let mut app = app_with(BevyTouchpadPlugin::default());
+            scroll_in(&mut app, unit, 0.0, y);
+            app.update();
+            swipes(&app).first().map(|swipe| swipe.magnitude)
+        };
+
+        let line = magnitude_of(MouseScrollUnit::Line, 1.0).expect("a swipe is emitted");
+        let pixel = magnitude_of(
+            MouseScrollUnit::Pixel,
+            BevyTouchpadPlugin::DEFAULT_LINE_TO_PIXELS,
+        )
+        .expect("a swipe is emitted");
+        assert!((line - pixel).abs() < f32::EPSILON);
+
+        let plugin = BevyTouchpadPlugin {
+            min_magnitude: 5.0,
+            ..Default::default()
+        };
+        let mut app = app_with(plugin);
+        scroll_in(&mut app, MouseScrollUnit::Line, 0.0, 1.0);
+        app.update();
+        assert_eq!(
+            swipes(&app).len(),
+            1,
+            "a single line is above a threshold given in pixels"
+        );
     }
 }
@@ -20,6 +20,7 @@ impl Plugin for TrackingPlugin {
 pub mod components {
 
     use measurements::PositionMeasurement;
+    use units::SampleRate;
 
     use super::*;
     /// A component that tracks position data of an entity using a ring buffer.
@@ -49,10 +50,23 @@ pub mod components {
                 ringbuf: HeapRb::new(capacity),
                 timer: Timer::new(duration, TimerMode::Repeating),
                 measurements_performed: 0,
+                first_measurement_recorded_at: None,
                 _marker: std::marker::PhantomData,
             }
         }
 
+        /// Creates a new `PositionTracker` with specified buffer capacity,
+        /// sampling positions at `rate`.
+        ///
+        /// # Arguments
+        /// * `capacity` - The number of position vectors the ring buffer can
+        ///   hold.
+        /// * `rate` - How often to sample positions, e.g. in Hz with
+        ///   [`SampleRate::from_hz`].
+        pub fn with_sample_rate(capacity: usize, rate: SampleRate) -> Self {
+            Self::new(capacity, rate.as_duration())
+        }
+
         /// Returns a reference to the internal ring buffer.
         pub fn ringbuf(&self) -> &HeapRb<PositionMeasurement> {
             &self.ringbuf
@@ -253,3 +267,21 @@ fn track_velocities(
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::num::NonZeroU64;
+
+    use units::SampleRate;
+
+    use super::{components::PositionTracker, *};
+
+    #[test]
+    fn sample_rate_in_hz_is_converted_to_period() {
+        let rate = SampleRate::from_hz(NonZeroU64::new(10).expect("10 > 0"));
+        let tracker = PositionTracker::with_sample_rate(100, rate);
+
+        assert_eq!(tracker.timer().duration(), Duration::from_millis(100));
+        assert_eq!(tracker.timer().mode(), TimerMode::Repeating);
+    }
+}
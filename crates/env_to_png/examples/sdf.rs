@@ -1,4 +1,6 @@
-use env_to_png::{env_to_image, env_to_sdf_image, Percentage, PixelsPerTile};
+use env_to_png::{
+    env_to_image, env_to_sdf_grid, env_to_sdf_image, save_sdf_npy, Percentage, PixelsPerTile,
+};
 use gbp_environment::Environment;
 
 fn main() {
@@ -25,4 +27,14 @@ fn main() {
         sdf.save("./output/sdf.png")
             .expect("Failed to save SDF image");
     }
+
+    if let Ok(grid) = env_to_sdf_grid(
+        &environment,
+        resolution,
+        Percentage::new(0.015),
+        Percentage::new(0.0),
+    ) {
+        save_sdf_npy(&grid, std::path::Path::new("./output/sdf.npy"))
+            .expect("Failed to save SDF grid");
+    }
 }
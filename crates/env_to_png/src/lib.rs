@@ -3,13 +3,14 @@
 //! different elements of the environment. And it can blur the edges to mimic an
 //! SDF.
 
-use std::num::NonZeroU32;
+use std::{io::Write, num::NonZeroU32, path::Path};
 
 // use magics::config::Environment;
-use gbp_environment::{Environment, PlaceableShape, Polygon, RegularPolygon};
+use gbp_environment::Environment;
 use gbp_geometry::RelativePoint;
 use glam::{Vec2, Vec3Swizzles};
-use image::{imageops::FilterType::Triangle, RgbImage};
+use image::{imageops::FilterType::Triangle, ImageBuffer, Luma, RgbImage};
+use ndarray::Array2;
 
 /// Custom resolution type, as pixels per tile.
 #[derive(Clone, Copy, Debug)]
@@ -206,6 +207,153 @@ pub fn env_to_image(
     Ok(image)
 }
 
+/// Convert [`Environment`] to a grid of signed distances to the nearest
+/// obstacle boundary, with one cell per pixel of [`env_to_image`]
+/// - Distances are in the same units as the tile size of the environment
+/// - Cells inside obstacles are negative, and cells outside are positive
+/// - Cells are `f32::INFINITY` if the environment has no obstacles
+/// - The grid is indexed as `[row, column]`, with row 0 at the top, like the
+///   image
+pub fn env_to_sdf_grid(
+    env: &Environment,
+    resolution: PixelsPerTile,
+    expansion: Percentage,
+    blur_percent: Percentage,
+) -> anyhow::Result<Array2<f32>> {
+    let image = env_to_image(env, resolution, expansion)?;
+    let (width, height) = (image.width() as usize, image.height() as usize);
+    let obstacle = Array2::from_shape_fn((height, width), |(y, x)| {
+        image.get_pixel(x as u32, y as u32)[0] == 0
+    });
+
+    let to_obstacle = euclidean_distance_transform(&obstacle);
+    let to_free = euclidean_distance_transform(&obstacle.mapv(|is_obstacle| !is_obstacle));
+
+    let pixel_size = env.tile_size() / resolution.get() as f32;
+    // distances are between pixel centers, so move the boundary to the edge
+    // between an obstacle and a free pixel
+    let sdf = Array2::from_shape_fn((height, width), |index| {
+        if obstacle[index] {
+            -(to_free[index] - 0.5) * pixel_size
+        } else {
+            (to_obstacle[index] - 0.5) * pixel_size
+        }
+    });
+
+    let blur_pixels = blur_percent.0 * resolution.get() as f32;
+    if blur_pixels < 1.0 {
+        return Ok(sdf);
+    }
+
+    let buffer: ImageBuffer<Luma<f32>, Vec<f32>> =
+        ImageBuffer::from_raw(width as u32, height as u32, sdf.iter().copied().collect())
+            .ok_or_else(|| anyhow::anyhow!("SDF grid does not match the image dimensions"))?;
+    let blurred = image::imageops::blur(&buffer, blur_pixels);
+
+    Ok(Array2::from_shape_vec((height, width), blurred.into_raw())?)
+}
+
+/// Save a grid of `f32` values, e.g. from [`env_to_sdf_grid`], as a `.npy`
+/// file, which can be loaded with `numpy.load`
+pub fn save_sdf_npy(grid: &Array2<f32>, path: &Path) -> std::io::Result<()> {
+    let (rows, cols) = grid.dim();
+    let mut header =
+        format!("{{'descr': '<f4', 'fortran_order': False, 'shape': ({rows}, {cols}), }}");
+    // magic string, version and header length take 10 bytes, and the header
+    // is padded with spaces and terminated by a newline so the data is 64 byte
+    // aligned
+    let padding = 64 - (10 + header.len() + 1) % 64;
+    header.extend(std::iter::repeat(' ').take(padding % 64));
+    header.push('\n');
+
+    let mut file = std::io::BufWriter::new(std::fs::File::create(path)?);
+    file.write_all(b"\x93NUMPY\x01\x00")?;
+    let header_len = u16::try_from(header.len())
+        .map_err(|err| std::io::Error::new(std::io::ErrorKind::InvalidData, err))?;
+    file.write_all(&header_len.to_le_bytes())?;
+    file.write_all(header.as_bytes())?;
+    // `iter` visits the elements in logical, i.e. row-major, order
+    for value in grid {
+        file.write_all(&value.to_le_bytes())?;
+    }
+    file.flush()
+}
+
+/// Euclidean distance from every cell to the nearest `true` cell in `mask`,
+/// in cells, using the separable algorithm by Felzenszwalb and Huttenlocher
+/// Cells are `f32::INFINITY` if no cell in `mask` is `true`
+fn euclidean_distance_transform(mask: &Array2<bool>) -> Array2<f32> {
+    /// Larger than any squared distance within an image
+    const FAR: f64 = 1e20;
+
+    let mut squared = mask.mapv(|feature| if feature { 0.0 } else { FAR });
+    for axis in [ndarray::Axis(0), ndarray::Axis(1)] {
+        for mut lane in squared.lanes_mut(axis) {
+            let f = lane.to_vec();
+            lane.assign(&ndarray::Array1::from(squared_distance_transform_1d(&f)));
+        }
+    }
+
+    #[allow(clippy::cast_possible_truncation)]
+    squared.mapv(|d| {
+        if d >= FAR {
+            f32::INFINITY
+        } else {
+            d.sqrt() as f32
+        }
+    })
+}
+
+/// One dimensional squared distance transform of the sampled function `f`
+/// i.e. `d[q] = min_p (q - p)^2 + f[p]`, computed as the lower envelope of
+/// parabolas rooted at every `p`
+#[allow(clippy::cast_precision_loss)]
+fn squared_distance_transform_1d(f: &[f64]) -> Vec<f64> {
+    let n = f.len();
+    let mut d = vec![0.0; n];
+    if n == 0 {
+        return d;
+    }
+
+    // horizontal position of the intersection between the parabolas rooted at
+    // `q` and `p`
+    let intersection = |q: usize, p: usize| {
+        let (qf, pf) = (q as f64, p as f64);
+        (qf.mul_add(qf, f[q]) - pf.mul_add(pf, f[p])) / (2.0 * (qf - pf))
+    };
+
+    // roots of the parabolas in the lower envelope
+    let mut v = vec![0usize; n];
+    // boundaries between the parabolas in the lower envelope
+    let mut z = vec![0.0; n + 1];
+    let mut k = 0;
+    z[0] = f64::NEG_INFINITY;
+    z[1] = f64::INFINITY;
+
+    for q in 1..n {
+        let mut s = intersection(q, v[k]);
+        while s <= z[k] {
+            k -= 1;
+            s = intersection(q, v[k]);
+        }
+        k += 1;
+        v[k] = q;
+        z[k] = s;
+        z[k + 1] = f64::INFINITY;
+    }
+
+    k = 0;
+    for (q, dq) in d.iter_mut().enumerate() {
+        while z[k + 1] < q as f64 {
+            k += 1;
+        }
+        let offset = q as f64 - v[k] as f64;
+        *dq = offset.mul_add(offset, f[v[k]]);
+    }
+
+    d
+}
+
 /// Convert from image index to tile dimensions
 /// That is; if PixelsPerTile is 100, and the env.tile_size() is 10,
 /// then pixel (23, 56) is (23 / 100 * 10, 56 / 100 * 10) = (2.3, 5.6) units in
@@ -302,19 +450,7 @@ fn is_placeable_obstacle(
         let expanded_shape = obstacle.shape.expanded(expansion.0 as f64);
         let translated = Vec2::from(inverted_percentage) - Vec2::from(obstacle.translation); // - translation_offset;
                                                                                              // rotate the translated coordinated by the obstacle rotation
-        let rotation_offset = match obstacle.shape {
-            PlaceableShape::RegularPolygon(RegularPolygon { sides, radius: _ }) => {
-                std::f32::consts::FRAC_PI_2
-                    + std::f32::consts::FRAC_PI_2
-                    + if sides % 2 != 0 {
-                        std::f32::consts::PI / sides as f32
-                    } else {
-                        0.0
-                    }
-            }
-            PlaceableShape::Polygon { .. } => 0.0,
-            _ => std::f32::consts::FRAC_PI_2,
-        };
+        let rotation_offset = obstacle.shape.render_rotation_offset();
 
         let rotated =
             glam::Quat::from_rotation_z(obstacle.rotation.as_radians() as f32 + rotation_offset)
@@ -531,4 +667,65 @@ mod tests {
             true
         );
     }
+
+    #[test]
+    fn width_of_rectangle_obstacle_is_horizontal() {
+        let mut env = Environment::intersection();
+        env.obstacles =
+            gbp_environment::Obstacles::empty().with_obstacle(gbp_environment::Obstacle::new(
+                (0, 0),
+                gbp_environment::PlaceableShape::rectangle(0.8, 0.4),
+                0.0,
+                (0.5, 0.5),
+            ));
+        let tile_coords = TileCoords { x: 0, y: 0 };
+        let inside = |x, y| {
+            is_placeable_obstacle(
+                &env,
+                tile_coords,
+                PercentageCoords::new(x, y),
+                Percentage::new(0.0),
+            )
+        };
+
+        // the rectangle spans 0.2 of a tile to each side horizontally, and 0.1
+        // vertically
+        assert!(inside(0.65, 0.55));
+        assert!(inside(0.35, 0.45));
+        assert!(!inside(0.55, 0.65));
+        assert!(!inside(0.45, 0.35));
+    }
+
+    #[test]
+    fn sdf_grid_matches_image_and_is_negative_inside_obstacles() {
+        let env = Environment::intersection();
+        let resolution = PixelsPerTile::new(20);
+        let grid = env_to_sdf_grid(&env, resolution, Percentage::new(0.0), Percentage::new(0.0))
+            .expect("every tile exists");
+
+        let (nrows, ncols) = (env.tiles.grid.nrows(), env.tiles.grid.ncols());
+        assert_eq!(grid.dim(), (nrows * 20, ncols * 20));
+
+        // the corners of a `┼` tile are obstacles, and the center is not
+        assert!(grid[[2, 2]] < 0.0);
+        assert!(grid[[10, 10]] > 0.0);
+        // deeper inside the obstacle is further from its boundary
+        assert!(grid[[1, 1]] < grid[[5, 5]]);
+    }
+
+    #[test]
+    fn distance_transform_of_single_feature() {
+        let mut mask = Array2::from_elem((5, 5), false);
+        mask[[2, 2]] = true;
+        let distances = euclidean_distance_transform(&mask);
+
+        assert_eq!(distances[[2, 2]], 0.0);
+        assert_eq!(distances[[2, 4]], 2.0);
+        assert!((distances[[0, 0]] - 8.0_f32.sqrt()).abs() < 1e-6);
+        assert!(
+            euclidean_distance_transform(&Array2::from_elem((2, 2), false))
+                .iter()
+                .all(|d| d.is_infinite())
+        );
+    }
 }
@@ -0,0 +1,156 @@
+#![warn(missing_docs)]
+//! Support for config fields that have been renamed.
+//! Old field names are accepted for a transition period, and migrated to
+//! their new name before the config is deserialized.
+
+/// A field in the config file that has been renamed
+#[derive(Debug, Clone, Copy)]
+pub struct RenamedField {
+    /// Path of tables leading to the field, e.g. `["gbp"]` for `[gbp]`
+    pub section: &'static [&'static str],
+    /// The old, deprecated name of the field
+    pub old:     &'static str,
+    /// The current name of the field
+    pub new:     &'static str,
+}
+
+/// All fields that have been renamed, and are still accepted under their old
+/// name
+pub const RENAMED_FIELDS: &[RenamedField] = &[RenamedField {
+    section: &["gbp"],
+    old:     "iterations-per-timestep",
+    new:     "iteration-schedule",
+}];
+
+/// Warning emitted when a deprecated field name is used in a config file
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DeprecationWarning {
+    /// Dotted path to the deprecated field, e.g. `gbp.iterations-per-timestep`
+    pub old:     String,
+    /// Dotted path to the field replacing it, e.g. `gbp.iteration-schedule`
+    pub new:     String,
+    /// Whether the deprecated field was ignored, because the new field was
+    /// also present
+    pub ignored: bool,
+}
+
+impl std::fmt::Display for DeprecationWarning {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "config field `{}` is deprecated, use `{}` instead",
+            self.old, self.new
+        )?;
+        if self.ignored {
+            write!(f, " (ignored as `{}` is also set)", self.new)?;
+        }
+        Ok(())
+    }
+}
+
+/// Rename every deprecated field in `table` to its current name, and return a
+/// warning for each of them.
+pub(crate) fn migrate(table: &mut toml::Table) -> Vec<DeprecationWarning> {
+    RENAMED_FIELDS
+        .iter()
+        .filter_map(|renamed| {
+            let section = renamed.section.iter().try_fold(&mut *table, |table, key| {
+                table.get_mut(*key).and_then(toml::Value::as_table_mut)
+            })?;
+            let value = section.remove(renamed.old)?;
+            let ignored = section.contains_key(renamed.new);
+            if !ignored {
+                section.insert(renamed.new.to_string(), value);
+            }
+
+            let prefix = renamed.section.join(".");
+            Some(DeprecationWarning {
+                old: format!("{prefix}.{}", renamed.old),
+                new: format!("{prefix}.{}", renamed.new),
+                ignored,
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::DeprecationWarning;
+    use crate::{Config, ConfigWarning};
+
+    const CONFIG_WITH_OLD_FIELD: &str = r#"
+environment_image = "junction"
+environment       = "./config/environment.yaml"
+formation_group   = "./config/formation.ron"
+
+[gbp]
+sigma-pose-fixed        = 0.000000000000001
+sigma-factor-dynamics   = 0.1
+sigma-factor-interrobot = 0.01
+sigma-factor-obstacle   = 0.01
+sigma-factor-tracking   = 0.1
+lookahead-multiple      = 3
+
+[gbp.iterations-per-timestep]
+internal = 7
+external = 3
+schedule = "interleave-evenly"
+
+[robot]
+planning-horizon                       = 5.0
+target-speed                           = 4.0
+inter-robot-safety-distance-multiplier = 2.2
+
+[robot.radius]
+min = 1.0
+max = 2.0
+
+[robot.communication]
+radius       = 20.0
+failure-rate = 0.2
+"#;
+
+    #[test]
+    fn old_field_name_is_migrated() {
+        let (config, warnings) = Config::parse_with_warnings(CONFIG_WITH_OLD_FIELD)
+            .expect("config with deprecated field is valid");
+
+        assert_eq!(config.gbp.iteration_schedule.internal, 7);
+        assert_eq!(config.gbp.iteration_schedule.external, 3);
+
+        assert_eq!(warnings, vec![ConfigWarning::Deprecated(
+            DeprecationWarning {
+                old:     "gbp.iterations-per-timestep".into(),
+                new:     "gbp.iteration-schedule".into(),
+                ignored: false,
+            }
+        )]);
+    }
+
+    #[test]
+    fn new_field_name_takes_precedence() {
+        let contents = CONFIG_WITH_OLD_FIELD.replace(
+            "[robot]",
+            "[gbp.iteration-schedule]\ninternal = 1\nexternal = 2\nschedule = \
+             \"centered\"\n\n[robot]",
+        );
+        let (config, warnings) = Config::parse_with_warnings(&contents).expect("config is valid");
+
+        assert_eq!(config.gbp.iteration_schedule.internal, 1);
+        assert_eq!(config.gbp.iteration_schedule.external, 2);
+        assert!(matches!(warnings.as_slice(), [ConfigWarning::Deprecated(
+            DeprecationWarning { ignored: true, .. }
+        )]));
+    }
+
+    #[test]
+    fn no_warnings_for_current_field_names() {
+        let contents =
+            CONFIG_WITH_OLD_FIELD.replace("iterations-per-timestep", "iteration-schedule");
+        let (_, warnings) = Config::parse_with_warnings(&contents).expect("config is valid");
+
+        assert!(warnings.is_empty());
+    }
+}
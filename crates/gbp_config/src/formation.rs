@@ -19,7 +19,7 @@ use serde::{Deserialize, Serialize};
 use typed_floats::StrictlyPositiveFinite;
 
 use super::geometry::{Point, Shape};
-use crate::line;
+use crate::{line, PartialRobotSection, RobotSection};
 
 /// Strategy to use for the starting point of a formation
 #[derive(Debug, Clone, Copy, Serialize, Deserialize)]
@@ -229,6 +229,11 @@ pub struct Formation {
     pub waypoint_reached_when_intersects: ReachedWhen,
     #[serde(default = "Formation::default_finished_when_intersects")]
     pub finished_when_intersects: ReachedWhen,
+    /// Optional overrides of the global `RobotSection` for individual robots.
+    /// The `i`th entry applies to the `i`th robot spawned in every iteration,
+    /// robots without an entry use the global `RobotSection` unchanged.
+    #[serde(default)]
+    pub robot_overrides: Vec<PartialRobotSection>,
 }
 
 impl Default for Formation {
@@ -270,6 +275,16 @@ impl Formation {
         self.robots.saturating_mul(times)
     }
 
+    /// Returns the parameters of the `index`th robot in the formation, i.e.
+    /// `base` with the robot's entry in `robot_overrides` merged onto it, if it
+    /// has one.
+    #[must_use]
+    pub fn robot_params_for(&self, index: usize, base: &RobotSection) -> RobotSection {
+        self.robot_overrides
+            .get(index)
+            .map_or_else(|| base.clone(), |overrides| overrides.merged_onto(base))
+    }
+
     /// Return a new `Formation` matching the used in the **gbpplanner** paper
     /// for the circle formation scenario
     #[allow(clippy::missing_panics_doc)]
@@ -291,6 +306,7 @@ impl Formation {
             waypoints: one_or_more![Waypoint::new(circle, ProjectionStrategy::Cross)],
             waypoint_reached_when_intersects: ReachedWhen::same_as_paper(),
             finished_when_intersects: ReachedWhen::same_as_paper(),
+            robot_overrides: vec![],
         }
     }
 
@@ -754,6 +770,7 @@ impl FormationGroup {
                         distance: IntersectionDistance::RobotRadius,
                         intersects_with: CheckIntersectionWith::Current,
                     },
+                    robot_overrides: vec![],
                 },
                 Formation {
                     // repeat: Some(Duration::from_secs(4)),
@@ -779,6 +796,7 @@ impl FormationGroup {
                         distance: IntersectionDistance::RobotRadius,
                         intersects_with: CheckIntersectionWith::Current,
                     },
+                    robot_overrides: vec![],
                 },
             ],
         }
@@ -866,5 +884,71 @@ mod tests {
                     .all(|(p, (x, y))| float_eq(p.0, x) && float_eq(p.1, y)));
             }
         }
+
+        mod robot_overrides {
+            use pretty_assertions::assert_eq;
+
+            use super::*;
+
+            const FORMATION_WITH_OVERRIDES: &str = r"
+formations:
+- repeat: null
+  delay:
+    secs: 0
+    nanos: 0
+  robots: 3
+  planning-strategy: only-local
+  initial-position:
+    shape: !line-segment
+    - x: 0.1
+      y: 0.1
+    - x: 0.9
+      y: 0.1
+    placement-strategy: equal
+  waypoints:
+  - shape: !line-segment
+    - x: 0.1
+      y: 0.9
+    - x: 0.9
+      y: 0.9
+    projection-strategy: identity
+  waypoint-reached-when-intersects:
+    distance: robot-radius
+    intersects-with: horizon
+  robot-overrides:
+  - {}
+  - target-speed: 8.0
+";
+
+            #[test]
+            fn second_robot_overrides_target_speed() {
+                let group = FormationGroup::parse_from_yaml(FORMATION_WITH_OVERRIDES)
+                    .expect("formation with overrides is valid");
+                let formation = group.formations.first();
+                let base = RobotSection::default();
+
+                let params: Vec<_> = (0..formation.robots)
+                    .map(|i| formation.robot_params_for(i, &base))
+                    .collect();
+
+                assert_eq!(params[0].target_speed, base.target_speed);
+                assert_eq!(params[1].target_speed.get(), 8.0);
+                assert_eq!(params[2].target_speed, base.target_speed);
+
+                // fields not overridden are inherited
+                assert_eq!(params[1].planning_horizon, base.planning_horizon);
+                assert_eq!(params[1].radius.range(), base.radius.range());
+            }
+
+            #[test]
+            fn overrides_default_to_empty() {
+                let formation = Formation::default();
+                assert!(formation.robot_overrides.is_empty());
+
+                let base = RobotSection::default();
+                let params = formation.robot_params_for(0, &base);
+                assert_eq!(params.target_speed, base.target_speed);
+            }
+        }
     }
 }
@@ -1,21 +1,21 @@
 // pub mod environment;
+pub mod deprecation;
 pub mod formation;
 pub mod geometry;
 pub mod reader;
 
 use std::{num::NonZeroUsize, ops::RangeInclusive};
 
-use bevy::{
-    ecs::system::Resource,
-    reflect::{GetField, Reflect},
-};
+use bevy::ecs::system::Resource;
+pub use deprecation::DeprecationWarning;
 // pub use environment::{Environment, EnvironmentType};
 pub use formation::FormationGroup;
 use gbp_schedule::GbpSchedule;
 pub use reader::read_config;
 use serde::{Deserialize, Serialize};
-use struct_iterable::Iterable;
 use typed_floats::StrictlyPositiveFinite;
+use unit_interval::UnitInterval;
+use units::Hertz;
 
 #[derive(Debug, thiserror::Error)]
 pub enum ParseError {
@@ -23,10 +23,54 @@ pub enum ParseError {
     Io(#[from] std::io::Error),
     #[error("TOML error: {0}")]
     Toml(#[from] toml::de::Error),
+    #[error("TOML serialization error: {0}")]
+    TomlSerialization(#[from] toml::ser::Error),
+    #[error("Validation error: {0}")]
+    Invalid(#[from] ValidationError),
+    #[error("Unknown config field: {0}")]
+    UnknownField(String),
 }
 
-#[derive(Debug, Serialize, Deserialize)]
-pub struct Meter(f64);
+/// Error returned by [`Config::validate`] if fields of the config are not
+/// consistent with each other
+#[derive(Debug, Clone, PartialEq, thiserror::Error)]
+pub enum ValidationError {
+    #[error(
+        "gbp.variables must be at least 2, to have both a current and a horizon state, got {0}"
+    )]
+    TooFewVariables(usize),
+    #[error(
+        "gbp.lookahead-multiple must be in [1, gbp.variables), got lookahead-multiple = \
+         {lookahead_multiple} and variables = {variables}"
+    )]
+    IncompatibleLookaheadMultiple {
+        lookahead_multiple: usize,
+        variables: usize,
+    },
+    #[error("robot.radius.min must be at most robot.radius.max, got min = {min} and max = {max}")]
+    InvertedRadiusRange { min: f32, max: f32 },
+}
+
+/// Warning returned by [`Config::validate`] and
+/// [`Config::parse_with_warnings`] for configs that are valid, but likely not
+/// what was intended
+#[derive(Debug, Clone, PartialEq, thiserror::Error)]
+pub enum ConfigWarning {
+    /// Every factor in `gbp.factors-enabled` is disabled, so the factorgraphs
+    /// of the robots are degenerate and produce no motion
+    #[error("every factor in gbp.factors-enabled is disabled, robots will not move")]
+    AllFactorsDisabled,
+    /// `simulation.time-scale` is above
+    /// [`SimulationSection::MAX_RECOMMENDED_TIME_SCALE`]
+    #[error(
+        "simulation.time-scale = {0} is above {max}, the simulation will likely not keep up",
+        max = SimulationSection::MAX_RECOMMENDED_TIME_SCALE
+    )]
+    TimeScaleAboveSoftCap(f32),
+    /// A deprecated field name is used in the config file
+    #[error("{0}")]
+    Deprecated(DeprecationWarning),
+}
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "kebab-case")]
@@ -162,118 +206,249 @@ pub enum DrawSetting {
     // InfiniteGrid,
 }
 
-// TODO: store in a bitset
-#[allow(clippy::struct_excessive_bools)]
-#[derive(Debug, serde::Serialize, serde::Deserialize, Iterable, Reflect, Clone, Copy)]
-#[serde(rename_all = "kebab-case")]
-pub struct DrawSection {
-    pub robots: bool,
-    pub communication_graph: bool,
-    pub predicted_trajectories: bool,
-    pub waypoints: bool,
-    pub uncertainty: bool,
-    pub paths: bool,
-    pub communication_radius: bool,
-    pub obstacle_factors: bool,
-    pub tracking: bool,
-    pub interrobot_factors: bool,
-    pub interrobot_factors_safety_distance: bool,
-    pub generated_map: bool,
-    // pub height_map: bool,
-    pub sdf: bool,
-    pub robot_colliders: bool,
-    pub environment_colliders: bool,
-    pub robot_robot_collisions: bool,
-    pub robot_environment_collisions: bool,
-    // pub infinite_grid: bool,
+impl DrawSetting {
+    /// The flag in [`DrawFlags`] storing whether this setting is enabled
+    pub const fn flag(self) -> DrawFlags {
+        match self {
+            Self::CommunicationGraph => DrawFlags::COMMUNICATION_GRAPH,
+            Self::PredictedTrajectories => DrawFlags::PREDICTED_TRAJECTORIES,
+            Self::Waypoints => DrawFlags::WAYPOINTS,
+            Self::Uncertainty => DrawFlags::UNCERTAINTY,
+            Self::Paths => DrawFlags::PATHS,
+            Self::GeneratedMap => DrawFlags::GENERATED_MAP,
+            Self::Sdf => DrawFlags::SDF,
+            Self::CommunicationRadius => DrawFlags::COMMUNICATION_RADIUS,
+            Self::Robots => DrawFlags::ROBOTS,
+            Self::ObstacleFactors => DrawFlags::OBSTACLE_FACTORS,
+            Self::Tracking => DrawFlags::TRACKING,
+            Self::InterRobotFactors => DrawFlags::INTERROBOT_FACTORS,
+            Self::InterRobotFactorsSafetyDistance => DrawFlags::INTERROBOT_FACTORS_SAFETY_DISTANCE,
+            Self::RobotColliders => DrawFlags::ROBOT_COLLIDERS,
+            Self::RobotRobotCollisions => DrawFlags::ROBOT_ROBOT_COLLISIONS,
+            Self::EnvironmentColliders => DrawFlags::ENVIRONMENT_COLLIDERS,
+            Self::RobotEnvironmentCollisions => DrawFlags::ROBOT_ENVIRONMENT_COLLISIONS,
+        }
+    }
+
+    /// Name of the setting shown in the UI
+    pub const fn to_display_string(self) -> &'static str {
+        match self {
+            Self::CommunicationGraph => "Communication Graph",
+            Self::PredictedTrajectories => "Trajectories",
+            Self::Waypoints => "Waypoints",
+            Self::Uncertainty => "Uncertainty",
+            Self::Paths => "Paths",
+            Self::GeneratedMap => "Generated Map",
+            Self::Sdf => "SDF",
+            Self::CommunicationRadius => "Communication Radius",
+            Self::Robots => "Robots",
+            Self::Tracking => "Tracking",
+            Self::ObstacleFactors => "Obstacle Factors",
+            Self::InterRobotFactors => "InterRobot Factors",
+            Self::InterRobotFactorsSafetyDistance => "InterRobot Safety Distance",
+            Self::RobotColliders => "Robot Colliders",
+            Self::EnvironmentColliders => "Environment Colliders",
+            Self::RobotRobotCollisions => "Robot-Robot Collisions",
+            Self::RobotEnvironmentCollisions => "Robot-Environment Collisions",
+        }
+    }
 }
 
+bitflags::bitflags! {
+    /// Set of enabled [`DrawSetting`]s
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct DrawFlags: u32 {
+        const ROBOTS                             = 1 << 0;
+        const COMMUNICATION_GRAPH                = 1 << 1;
+        const PREDICTED_TRAJECTORIES             = 1 << 2;
+        const WAYPOINTS                          = 1 << 3;
+        const UNCERTAINTY                        = 1 << 4;
+        const PATHS                              = 1 << 5;
+        const COMMUNICATION_RADIUS               = 1 << 6;
+        const OBSTACLE_FACTORS                   = 1 << 7;
+        const TRACKING                           = 1 << 8;
+        const INTERROBOT_FACTORS                 = 1 << 9;
+        const INTERROBOT_FACTORS_SAFETY_DISTANCE = 1 << 10;
+        const GENERATED_MAP                      = 1 << 11;
+        const SDF                                = 1 << 12;
+        const ROBOT_COLLIDERS                    = 1 << 13;
+        const ENVIRONMENT_COLLIDERS              = 1 << 14;
+        const ROBOT_ROBOT_COLLISIONS             = 1 << 15;
+        const ROBOT_ENVIRONMENT_COLLISIONS       = 1 << 16;
+    }
+}
+
+/// Which parts of the simulation to draw
+/// Stored as a set of [`DrawFlags`], and (de)serialized as a table with a
+/// `bool` for each [`DrawSetting`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(from = "DrawSectionRepr", into = "DrawSectionRepr")]
+pub struct DrawSection(DrawFlags);
+
 impl Default for DrawSection {
     fn default() -> Self {
-        Self {
-            robots: true,
-            communication_graph: false,
-            predicted_trajectories: true,
-            waypoints: false,
-            uncertainty: false,
-            paths: true,
-            generated_map: true,
-            // height_map: false,
-            sdf: false,
-            communication_radius: false,
-            obstacle_factors: false,
-            tracking: false,
-            interrobot_factors: false,
-            interrobot_factors_safety_distance: false,
-            robot_colliders: false,
-            environment_colliders: false,
-            robot_robot_collisions: false,
-            robot_environment_collisions: false,
-            // infinite_grid: true,
-        }
+        Self(
+            DrawFlags::ROBOTS
+                | DrawFlags::PREDICTED_TRAJECTORIES
+                | DrawFlags::PATHS
+                | DrawFlags::GENERATED_MAP,
+        )
     }
 }
 
 impl DrawSection {
-    pub fn to_display_string(name: &str) -> &'static str {
-        match name {
-            "communication_graph" => "Communication Graph",
-            "predicted_trajectories" => "Trajectories",
-            "waypoints" => "Waypoints",
-            "uncertainty" => "Uncertainty",
-            "paths" => "Paths",
-            "generated_map" => "Generated Map",
-            // "height_map" => "Height Map",
-            "sdf" => "SDF",
-            "communication_radius" => "Communication Radius",
-            "robots" => "Robots",
-            "tracking" => "Tracking",
-            "obstacle_factors" => "Obstacle Factors",
-            "interrobot_factors" => "InterRobot Factors",
-            "interrobot_factors_safety_distance" => "InterRobot Safety Distance",
-            "robot_colliders" => "Robot Colliders",
-            "environment_colliders" => "Environment Colliders",
-            "robot_robot_collisions" => "Robot-Robot Collisions",
-            "robot_environment_collisions" => "Robot-Environment Collisions",
-            // "infinite_grid" => "Infinite Grid",
-            _ => "Unknown",
-        }
-    }
-
-    pub fn all_disabled() -> Self {
-        let mut instance = Self::default();
-        let copy = instance;
-
-        for (name, _) in copy.iter() {
-            if let Some(field) = instance.get_field_mut::<bool>(name) {
-                *field = false;
-            }
-        }
+    /// Order the settings are listed in by [`DrawSection::iter`]
+    const ORDER: [DrawSetting; 17] = [
+        DrawSetting::Robots,
+        DrawSetting::CommunicationGraph,
+        DrawSetting::PredictedTrajectories,
+        DrawSetting::Waypoints,
+        DrawSetting::Uncertainty,
+        DrawSetting::Paths,
+        DrawSetting::CommunicationRadius,
+        DrawSetting::ObstacleFactors,
+        DrawSetting::Tracking,
+        DrawSetting::InterRobotFactors,
+        DrawSetting::InterRobotFactorsSafetyDistance,
+        DrawSetting::GeneratedMap,
+        DrawSetting::Sdf,
+        DrawSetting::RobotColliders,
+        DrawSetting::EnvironmentColliders,
+        DrawSetting::RobotRobotCollisions,
+        DrawSetting::RobotEnvironmentCollisions,
+    ];
+
+    pub const fn all_disabled() -> Self {
+        Self(DrawFlags::empty())
+    }
 
-        instance
+    pub const fn all_enabled() -> Self {
+        Self(DrawFlags::all())
     }
 
-    pub fn all_enabled() -> Self {
-        let mut instance = Self::default();
-        let copy = instance;
+    /// The set of enabled settings
+    #[inline]
+    pub const fn flags(&self) -> DrawFlags {
+        self.0
+    }
 
-        for (name, _) in copy.iter() {
-            if let Some(field) = instance.get_field_mut::<bool>(name) {
-                *field = true;
-            }
-        }
+    /// Whether `setting` is enabled
+    #[inline]
+    pub const fn get(&self, setting: DrawSetting) -> bool {
+        self.0.contains(setting.flag())
+    }
+
+    /// Enable or disable `setting`
+    #[inline]
+    pub fn set(&mut self, setting: DrawSetting, on: bool) {
+        self.0.set(setting.flag(), on);
+    }
 
-        instance
+    /// Flip `setting`, and return whether it is now enabled
+    #[inline]
+    pub fn toggle(&mut self, setting: DrawSetting) -> bool {
+        self.0.toggle(setting.flag());
+        self.get(setting)
     }
 
     pub fn flip_all(&mut self) {
-        let copy = *self;
+        self.0 = self.0.complement();
+    }
 
-        copy.iter().for_each(|(name, _)| {
-            if let Some(field) = self.get_field_mut::<bool>(name) {
-                *field = !*field;
-            }
-        });
+    /// Iterate over every setting and whether it is enabled
+    pub fn iter(&self) -> impl Iterator<Item = (DrawSetting, bool)> + '_ {
+        Self::ORDER
+            .into_iter()
+            .map(|setting| (setting, self.get(setting)))
+    }
+}
+
+/// The (de)serialized representation of a [`DrawSection`], kept for
+/// compatibility with existing config files
+#[allow(clippy::struct_excessive_bools)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "kebab-case")]
+struct DrawSectionRepr {
+    robots: bool,
+    communication_graph: bool,
+    predicted_trajectories: bool,
+    waypoints: bool,
+    uncertainty: bool,
+    paths: bool,
+    communication_radius: bool,
+    obstacle_factors: bool,
+    tracking: bool,
+    interrobot_factors: bool,
+    interrobot_factors_safety_distance: bool,
+    generated_map: bool,
+    sdf: bool,
+    robot_colliders: bool,
+    environment_colliders: bool,
+    robot_robot_collisions: bool,
+    robot_environment_collisions: bool,
+}
+
+impl From<DrawSectionRepr> for DrawSection {
+    fn from(repr: DrawSectionRepr) -> Self {
+        let mut draw = Self::all_disabled();
+        draw.set(DrawSetting::Robots, repr.robots);
+        draw.set(DrawSetting::CommunicationGraph, repr.communication_graph);
+        draw.set(
+            DrawSetting::PredictedTrajectories,
+            repr.predicted_trajectories,
+        );
+        draw.set(DrawSetting::Waypoints, repr.waypoints);
+        draw.set(DrawSetting::Uncertainty, repr.uncertainty);
+        draw.set(DrawSetting::Paths, repr.paths);
+        draw.set(DrawSetting::CommunicationRadius, repr.communication_radius);
+        draw.set(DrawSetting::ObstacleFactors, repr.obstacle_factors);
+        draw.set(DrawSetting::Tracking, repr.tracking);
+        draw.set(DrawSetting::InterRobotFactors, repr.interrobot_factors);
+        draw.set(
+            DrawSetting::InterRobotFactorsSafetyDistance,
+            repr.interrobot_factors_safety_distance,
+        );
+        draw.set(DrawSetting::GeneratedMap, repr.generated_map);
+        draw.set(DrawSetting::Sdf, repr.sdf);
+        draw.set(DrawSetting::RobotColliders, repr.robot_colliders);
+        draw.set(
+            DrawSetting::EnvironmentColliders,
+            repr.environment_colliders,
+        );
+        draw.set(
+            DrawSetting::RobotRobotCollisions,
+            repr.robot_robot_collisions,
+        );
+        draw.set(
+            DrawSetting::RobotEnvironmentCollisions,
+            repr.robot_environment_collisions,
+        );
+        draw
+    }
+}
+
+impl From<DrawSection> for DrawSectionRepr {
+    fn from(draw: DrawSection) -> Self {
+        Self {
+            robots: draw.get(DrawSetting::Robots),
+            communication_graph: draw.get(DrawSetting::CommunicationGraph),
+            predicted_trajectories: draw.get(DrawSetting::PredictedTrajectories),
+            waypoints: draw.get(DrawSetting::Waypoints),
+            uncertainty: draw.get(DrawSetting::Uncertainty),
+            paths: draw.get(DrawSetting::Paths),
+            communication_radius: draw.get(DrawSetting::CommunicationRadius),
+            obstacle_factors: draw.get(DrawSetting::ObstacleFactors),
+            tracking: draw.get(DrawSetting::Tracking),
+            interrobot_factors: draw.get(DrawSetting::InterRobotFactors),
+            interrobot_factors_safety_distance: draw
+                .get(DrawSetting::InterRobotFactorsSafetyDistance),
+            generated_map: draw.get(DrawSetting::GeneratedMap),
+            sdf: draw.get(DrawSetting::Sdf),
+            robot_colliders: draw.get(DrawSetting::RobotColliders),
+            environment_colliders: draw.get(DrawSetting::EnvironmentColliders),
+            robot_robot_collisions: draw.get(DrawSetting::RobotRobotCollisions),
+            robot_environment_collisions: draw.get(DrawSetting::RobotEnvironmentCollisions),
+        }
     }
 }
 
@@ -301,7 +476,7 @@ pub struct SimulationSection {
 
     /// The fixed time step size to be used in the simulation.
     /// SI unit: s
-    pub hz: f64,
+    pub hz: Hertz,
 
     // /// The side length of the smallest square that contains the entire
     // /// simulated environment. Size of the environment in meters.
@@ -325,9 +500,20 @@ pub struct SimulationSection {
 }
 
 impl SimulationSection {
+    /// Soft cap on `time_scale`, above which [`Config::validate`] warns, as the
+    /// simulation can not keep up and becomes unusable
+    pub const MAX_RECOMMENDED_TIME_SCALE: f32 = 100.0;
+
     fn default_exit_application_on_scenario_finished() -> bool {
         false
     }
+
+    /// Returns `true` if the simulation runs in real-time, i.e. `time_scale`
+    /// is 1.0
+    #[must_use]
+    pub fn is_real_time(&self) -> bool {
+        (self.time_scale.get() - 1.0).abs() < f32::EPSILON
+    }
 }
 
 impl Default for SimulationSection {
@@ -337,7 +523,7 @@ impl Default for SimulationSection {
             max_time: 10000.0.try_into().expect("10000.0 > 0.0"),
             time_scale: 1.0.try_into().expect("1.0 > 0.0"),
             manual_step_factor: 1,
-            hz: 60.0,
+            hz: Hertz::new(60.0).expect("60.0 > 0.0"),
             // world_size: 100.0.try_into().expect("100.0 > 0.0"),
             // world_size:         StrictlyPositiveFinite::<f32>::new(100.0).expect("100.0 > 0.0"),
             prng_seed: 0,
@@ -352,8 +538,9 @@ impl Default for SimulationSection {
 #[derive(
     Debug,
     Clone,
-    Copy,
     Default,
+    PartialEq,
+    Eq,
     serde::Serialize,
     serde::Deserialize,
     strum_macros::EnumIter,
@@ -373,9 +560,54 @@ pub enum GbpIterationScheduleKind {
     InterleaveEvenly,
     #[strum(serialize = "Half Beginning Half End")]
     HalfBeginningHalfEnd,
+    /// Replay a user supplied pattern of `(internal, external)` steps
+    /// The number of internal and external iterations is given by the
+    /// pattern, so [`GbpIterationSchedule::internal`] and
+    /// [`GbpIterationSchedule::external`] are ignored
+    #[strum(serialize = "Custom")]
+    Custom(Vec<(bool, bool)>),
 }
 
 impl GbpIterationScheduleKind {
+    /// Returns `true` if the schedule is a [`GbpIterationScheduleKind::Custom`]
+    /// pattern
+    #[must_use]
+    pub const fn is_custom(&self) -> bool {
+        matches!(self, Self::Custom(_))
+    }
+
+    /// Name of the schedule kind in the config file, e.g. `interleave-evenly`
+    #[must_use]
+    pub fn config_name(&self) -> Option<String> {
+        match toml::Value::try_from(self) {
+            Ok(toml::Value::String(name)) => Some(name),
+            _ => None,
+        }
+    }
+
+    /// Parse a fixed schedule kind given on the command line
+    /// Case and the use of `-` or `_` as separator are ignored, so both
+    /// `soon-as-possible` and `Soon_As_Possible` are accepted
+    ///
+    /// # Errors
+    ///
+    /// Returns a message listing the valid schedule kinds, if `s` is not one of
+    /// them
+    pub fn parse_cli(s: &str) -> Result<Self, String> {
+        s.trim()
+            .to_lowercase()
+            .replace('_', "-")
+            .parse()
+            .map_err(|_| {
+                let valid = <Self as strum::IntoEnumIterator>::iter()
+                    .filter(|kind| !kind.is_custom())
+                    .filter_map(|kind| kind.config_name())
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                format!("unknown gbp schedule `{s}`, expected one of: {valid}")
+            })
+    }
+
     pub fn get(
         &self,
         config: gbp_schedule::GbpScheduleParams,
@@ -396,13 +628,37 @@ impl GbpIterationScheduleKind {
             GbpIterationScheduleKind::HalfBeginningHalfEnd => {
                 Box::new(gbp_schedule::HalfBeginningHalfEnd::schedule(config))
             }
+            GbpIterationScheduleKind::Custom(pattern) => Box::new(gbp_schedule::CustomIter::new(
+                pattern
+                    .iter()
+                    .map(
+                        |&(internal, external)| gbp_schedule::GbpScheduleAtIteration {
+                            internal,
+                            external,
+                        },
+                    )
+                    .collect(),
+            )),
         }
     }
 }
 
+/// Parse a fixed schedule kind from its kebab-case config name, e.g.
+/// `interleave-evenly`
+/// [`GbpIterationScheduleKind::Custom`] can not be parsed, as it requires a
+/// pattern
+impl std::str::FromStr for GbpIterationScheduleKind {
+    type Err = serde::de::value::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        use serde::de::IntoDeserializer;
+        Self::deserialize(s.into_deserializer())
+    }
+}
+
 /// Configuration for how many iterations to run different parts of the GBP
 /// algorithm per timestep
-#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "kebab-case")]
 pub struct GbpIterationSchedule {
     /// Internal iteration i.e. Variables, and factors excluding interrobot
@@ -414,6 +670,34 @@ pub struct GbpIterationSchedule {
     pub schedule: GbpIterationScheduleKind,
 }
 
+impl GbpIterationSchedule {
+    /// Number of internal iterations per timestep
+    /// For a [`GbpIterationScheduleKind::Custom`] schedule this is the number
+    /// of steps in its pattern with an internal iteration
+    #[must_use]
+    pub fn internal_iterations(&self) -> usize {
+        match &self.schedule {
+            GbpIterationScheduleKind::Custom(pattern) => {
+                pattern.iter().filter(|(internal, _)| *internal).count()
+            }
+            _ => self.internal,
+        }
+    }
+
+    /// Number of external iterations per timestep
+    /// For a [`GbpIterationScheduleKind::Custom`] schedule this is the number
+    /// of steps in its pattern with an external iteration
+    #[must_use]
+    pub fn external_iterations(&self) -> usize {
+        match &self.schedule {
+            GbpIterationScheduleKind::Custom(pattern) => {
+                pattern.iter().filter(|(_, external)| *external).count()
+            }
+            _ => self.external,
+        }
+    }
+}
+
 impl Default for GbpIterationSchedule {
     fn default() -> Self {
         let n = 10;
@@ -425,6 +709,36 @@ impl Default for GbpIterationSchedule {
     }
 }
 
+/// Overrides of the fields of a [`GbpIterationSchedule`], e.g. given as
+/// command line flags, that take precedence over the values in the config file
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct GbpIterationScheduleOverrides {
+    pub internal: Option<usize>,
+    pub external: Option<usize>,
+    pub schedule: Option<GbpIterationScheduleKind>,
+}
+
+impl GbpIterationScheduleOverrides {
+    /// Returns `true` if no field is overridden
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.internal.is_none() && self.external.is_none() && self.schedule.is_none()
+    }
+
+    /// Override the fields of `schedule` that are set
+    pub fn apply(&self, schedule: &mut GbpIterationSchedule) {
+        if let Some(internal) = self.internal {
+            schedule.internal = internal;
+        }
+        if let Some(external) = self.external {
+            schedule.external = external;
+        }
+        if let Some(ref kind) = self.schedule {
+            schedule.schedule = kind.clone();
+        }
+    }
+}
+
 // macro_rules! impl_factor_section {
 //     ($name:ident) => {
 //         paste::paste! {
@@ -464,6 +778,12 @@ pub struct FactorsEnabledSection {
 }
 
 impl FactorsEnabledSection {
+    /// Returns `true` if at least one factor is enabled
+    #[must_use]
+    pub const fn any_enabled(&self) -> bool {
+        self.dynamic || self.interrobot || self.obstacle || self.tracking
+    }
+
     fn default_tracking() -> bool {
         false
     }
@@ -605,16 +925,15 @@ pub struct CommunicationSection {
     /// other SI unit: m
     pub radius: StrictlyPositiveFinite<f32>,
 
-    // TODO: use a percentage type instead of f32
     /// Probability for failing to send/receive a message
-    pub failure_rate: f32,
+    pub failure_rate: UnitInterval,
 }
 
 impl Default for CommunicationSection {
     fn default() -> Self {
         Self {
             radius:       20.0.try_into().expect("20.0 > 0.0"),
-            failure_rate: 0.2,
+            failure_rate: UnitInterval::new(0.2).expect("0.2 in [0.0, 1.0]"),
         }
     }
 }
@@ -633,6 +952,15 @@ impl RobotRadiusSection {
     pub fn range(&self) -> RangeInclusive<f32> {
         self.min.get()..=self.max.get()
     }
+
+    /// Sample a radius uniformly from [`RobotRadiusSection::range`]
+    /// Returns `min` if `min` is not less than `max`
+    pub fn sample<R: rand::Rng>(&self, rng: &mut R) -> f32 {
+        if self.min.get() >= self.max.get() {
+            return self.min.get();
+        }
+        rng.gen_range(self.range())
+    }
 }
 
 impl Default for RobotRadiusSection {
@@ -679,6 +1007,43 @@ impl Default for RobotSection {
     }
 }
 
+/// **Partial Robot Section**
+/// Overrides for a subset of the parameters in a [`RobotSection`]
+/// Every field left as `None` is inherited from the [`RobotSection`] it is
+/// merged onto
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case", default)]
+pub struct PartialRobotSection {
+    /// SI unit: s
+    pub planning_horizon: Option<StrictlyPositiveFinite<f32>>,
+    /// SI unit: m/s
+    pub target_speed: Option<StrictlyPositiveFinite<f32>>,
+    /// Radius of the robot
+    pub radius: Option<RobotRadiusSection>,
+    /// Communication parameters
+    pub communication: Option<CommunicationSection>,
+    pub inter_robot_safety_distance_multiplier: Option<StrictlyPositiveFinite<f32>>,
+}
+
+impl PartialRobotSection {
+    /// Returns a copy of `base` with every field set in `self` overridden
+    #[must_use]
+    pub fn merged_onto(&self, base: &RobotSection) -> RobotSection {
+        RobotSection {
+            planning_horizon: self.planning_horizon.unwrap_or(base.planning_horizon),
+            target_speed: self.target_speed.unwrap_or(base.target_speed),
+            radius: self.radius.clone().unwrap_or_else(|| base.radius.clone()),
+            communication: self
+                .communication
+                .clone()
+                .unwrap_or_else(|| base.communication.clone()),
+            inter_robot_safety_distance_multiplier: self
+                .inter_robot_safety_distance_multiplier
+                .unwrap_or(base.inter_robot_safety_distance_multiplier),
+        }
+    }
+}
+
 /// Interaction Section
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "kebab-case")]
@@ -714,11 +1079,21 @@ pub struct RRTSection {
     pub collision_radius: StrictlyPositiveFinite<f32>,
     /// Neighbourhood radius for RRT*
     pub neighbourhood_radius: StrictlyPositiveFinite<f32>,
+    /// Probability of sampling the goal instead of a uniformly random point
+    /// Speeds up convergence in narrow corridors
+    #[serde(default = "RRTSection::default_goal_bias")]
+    pub goal_bias: UnitInterval,
     /// The smoothing parameters
     #[serde(default)]
     pub smoothing: SmoothingSection,
 }
 
+impl RRTSection {
+    fn default_goal_bias() -> UnitInterval {
+        UnitInterval::new(0.0).expect("0.0 in [0.0, 1.0]")
+    }
+}
+
 impl Default for RRTSection {
     fn default() -> Self {
         Self {
@@ -726,6 +1101,7 @@ impl Default for RRTSection {
             step_size: StrictlyPositiveFinite::<f32>::new(1.0).expect("1.0 > 0.0"),
             collision_radius: StrictlyPositiveFinite::<f32>::new(1.0).expect("1.0 > 0.0"),
             neighbourhood_radius: StrictlyPositiveFinite::<f32>::new(1.0).expect("1.0 > 0.0"),
+            goal_bias: Self::default_goal_bias(),
             smoothing: SmoothingSection::default(),
         }
     }
@@ -793,6 +1169,120 @@ impl Default for OnVariableClickedSection {
     }
 }
 
+/// A config file containing only some of the fields of a [`Config`], used to
+/// override the fields of a base config with [`Config::merge`]
+/// Tables are merged recursively, so e.g. `[gbp] variables = 12` only
+/// overrides `gbp.variables`, and keeps every other field of `[gbp]`
+/// Every field is checked to exist in [`Config`] and have the right type
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(try_from = "toml::Table", into = "toml::Table")]
+pub struct PartialConfig(toml::Table);
+
+impl PartialConfig {
+    /// Parse a partial config file from a given path
+    pub fn from_file<P>(path: P) -> Result<Self, ParseError>
+    where
+        P: AsRef<std::path::Path>,
+    {
+        std::fs::read_to_string(path)
+            .map_err(Into::into)
+            .and_then(|contents| Self::parse(contents.as_str()))
+    }
+
+    /// Parse a partial config file
+    /// Returns a `ParseError` if a field is not a field of [`Config`], or has
+    /// the wrong type
+    /// Every warning returned by [`PartialConfig::parse_with_warnings`] is
+    /// logged
+    pub fn parse(contents: &str) -> Result<Self, ParseError> {
+        let (partial, warnings) = Self::parse_with_warnings(contents)?;
+        for warning in &warnings {
+            bevy::log::warn!("{warning}");
+        }
+        Ok(partial)
+    }
+
+    /// Parse a partial config file, migrating deprecated field names listed in
+    /// [`deprecation::RENAMED_FIELDS`] to their current name
+    /// Returns the parsed partial config together with a
+    /// [`ConfigWarning::Deprecated`] for every deprecated field name used
+    pub fn parse_with_warnings(contents: &str) -> Result<(Self, Vec<ConfigWarning>), ParseError> {
+        let mut table: toml::Table = toml::from_str(contents)?;
+        let warnings = deprecation::migrate(&mut table)
+            .into_iter()
+            .map(ConfigWarning::Deprecated)
+            .collect();
+        Ok((Self::try_from(table)?, warnings))
+    }
+
+    /// Returns `true` if no field is overridden
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+impl TryFrom<toml::Table> for PartialConfig {
+    type Error = ParseError;
+
+    /// Check that every field of `table` is a field of [`Config`], and that
+    /// it has the right type, by merging it onto [`Config::default`]
+    fn try_from(table: toml::Table) -> Result<Self, Self::Error> {
+        let mut fields = toml::Table::try_from(Config::default())?;
+        check_known_fields(&fields, &table, "")?;
+        merge_tables(&mut fields, table.clone());
+        let _: Config = fields.try_into()?;
+        Ok(Self(table))
+    }
+}
+
+impl From<PartialConfig> for toml::Table {
+    fn from(partial: PartialConfig) -> Self {
+        partial.0
+    }
+}
+
+/// Check that every key in `overrides` is also a key in `fields`, recursing
+/// into tables present in both
+/// `path` is the dotted path of the table `overrides` is found at
+fn check_known_fields(
+    fields: &toml::Table,
+    overrides: &toml::Table,
+    path: &str,
+) -> Result<(), ParseError> {
+    for (key, value) in overrides {
+        let field = if path.is_empty() {
+            key.clone()
+        } else {
+            format!("{path}.{key}")
+        };
+        match (fields.get(key), value) {
+            (None, _) => return Err(ParseError::UnknownField(field)),
+            (Some(toml::Value::Table(fields)), toml::Value::Table(overrides)) => {
+                check_known_fields(fields, overrides, &field)?;
+            }
+            _ => {}
+        }
+    }
+    Ok(())
+}
+
+/// Recursively merge `overrides` into `base`
+/// Tables present in both are merged, any other value in `overrides` replaces
+/// the one in `base`
+fn merge_tables(base: &mut toml::Table, overrides: toml::Table) {
+    for (key, value) in overrides {
+        match (base.get_mut(&key), value) {
+            (Some(toml::Value::Table(base)), toml::Value::Table(overrides)) => {
+                merge_tables(base, overrides);
+            }
+            (_, value) => {
+                base.insert(key, value);
+            }
+        }
+    }
+}
+
 /// Collection of all the sections in the config file
 #[derive(Debug, Clone, Serialize, Deserialize, Resource)]
 pub struct Config {
@@ -872,6 +1362,20 @@ impl Default for Config {
 }
 
 impl Config {
+    /// Fields that can be applied to a running simulation without restarting
+    /// it, given as the path of keys leading to them in the config file
+    pub const HOT_RELOADABLE_FIELDS: &'static [&'static [&'static str]] = &[
+        &["visualisation"],
+        &["interaction"],
+        &["graphviz"],
+        &["manual"],
+        &["debug"],
+        &["simulation", "time-scale"],
+        &["simulation", "manual-step-factor"],
+        &["simulation", "max-time"],
+        &["simulation", "pause-on-spawn"],
+    ];
+
     /// Parse a config file from a given path
     pub fn from_file<P>(path: P) -> Result<Self, ParseError>
     where
@@ -886,9 +1390,465 @@ impl Config {
 
     /// Parse a config file
     /// Returns a `ParseError` if the file cannot be parsed
+    /// Every warning returned by [`Config::parse_with_warnings`] is logged
     pub fn parse(contents: &str) -> Result<Self, ParseError> {
-        toml::from_str(contents).map_err(Into::into)
-        // let config = toml::from_str(contents)?;
-        // Ok(config)
+        let (config, warnings) = Self::parse_with_warnings(contents)?;
+        for warning in &warnings {
+            bevy::log::warn!("{warning}");
+        }
+        Ok(config)
+    }
+
+    /// Parse a config file, accepting deprecated field names listed in
+    /// [`deprecation::RENAMED_FIELDS`]
+    /// Returns the parsed config together with a [`ConfigWarning::Deprecated`]
+    /// for every deprecated field name used, followed by the warnings of
+    /// [`Config::validate`]
+    pub fn parse_with_warnings(contents: &str) -> Result<(Self, Vec<ConfigWarning>), ParseError> {
+        let mut table: toml::Table = toml::from_str(contents)?;
+        let mut warnings = deprecation::migrate(&mut table)
+            .into_iter()
+            .map(ConfigWarning::Deprecated)
+            .collect::<Vec<_>>();
+        let config: Self = table.try_into()?;
+        warnings.extend(config.validate()?);
+        Ok((config, warnings))
+    }
+
+    /// Check that fields of the config are consistent with each other
+    /// - `gbp.variables` must be at least 2, as the factorgraph of a robot
+    ///   always has a variable for its current and horizon state
+    /// - `gbp.lookahead-multiple` must be at least 1 and less than
+    ///   `gbp.variables`, otherwise the variables can not be spaced out over
+    ///   the planning horizon
+    /// - `robot.radius.min` must be at most `robot.radius.max`
+    ///
+    /// Returns a [`ConfigWarning`] for every field that is valid, but likely
+    /// not what was intended
+    ///
+    /// # Errors
+    ///
+    /// Returns the first [`ValidationError`] found
+    pub fn validate(&self) -> Result<Vec<ConfigWarning>, ValidationError> {
+        let variables = self.gbp.variables;
+        let lookahead_multiple = self.gbp.lookahead_multiple;
+
+        if variables < 2 {
+            return Err(ValidationError::TooFewVariables(variables));
+        }
+        if lookahead_multiple == 0 || lookahead_multiple >= variables {
+            return Err(ValidationError::IncompatibleLookaheadMultiple {
+                lookahead_multiple,
+                variables,
+            });
+        }
+
+        let (min, max) = (self.robot.radius.min.get(), self.robot.radius.max.get());
+        if min > max {
+            return Err(ValidationError::InvertedRadiusRange { min, max });
+        }
+
+        let mut warnings = Vec::new();
+        if !self.gbp.factors_enabled.any_enabled() {
+            warnings.push(ConfigWarning::AllFactorsDisabled);
+        }
+        let time_scale = self.simulation.time_scale.get();
+        if time_scale > SimulationSection::MAX_RECOMMENDED_TIME_SCALE {
+            warnings.push(ConfigWarning::TimeScaleAboveSoftCap(time_scale));
+        }
+
+        Ok(warnings)
+    }
+
+    /// Override the fields of `self` with the ones set in `overrides`
+    /// Fields are only checked one at a time, when `overrides` is parsed, so
+    /// call [`Config::validate`] to check that the merged config is consistent
+    ///
+    /// # Panics
+    ///
+    /// Never, as every field of `overrides` has been checked to be a field of
+    /// [`Config`] with the right type
+    #[must_use]
+    pub fn merge(self, overrides: PartialConfig) -> Self {
+        let mut table = toml::Table::try_from(self).expect("a config can be serialized");
+        merge_tables(&mut table, overrides.0);
+        table
+            .try_into()
+            .expect("the fields of a partial config are checked when it is parsed")
+    }
+
+    /// Check if changing from `self` to `other` requires a restart of the
+    /// simulation, i.e. if they differ in any field not listed in
+    /// [`Config::HOT_RELOADABLE_FIELDS`]
+    /// Conservatively returns `true` if either config can not be serialized
+    #[must_use]
+    pub fn requires_restart(&self, other: &Self) -> bool {
+        /// Serialize `config` and remove all hot reloadable fields from it
+        fn restart_relevant(config: &Config) -> Option<toml::Table> {
+            let mut table = toml::Table::try_from(config).ok()?;
+            for path in Config::HOT_RELOADABLE_FIELDS {
+                let Some((field, sections)) = path.split_last() else {
+                    continue;
+                };
+                let section = sections.iter().try_fold(&mut table, |table, key| {
+                    table.get_mut(*key).and_then(toml::Value::as_table_mut)
+                });
+                if let Some(section) = section {
+                    section.remove(*field);
+                }
+            }
+            Some(table)
+        }
+
+        match (restart_relevant(self), restart_relevant(other)) {
+            (Some(this), Some(other)) => this != other,
+            _ => true,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use strum::IntoEnumIterator;
+
+    use super::*;
+
+    #[test]
+    fn default_config_is_valid() {
+        assert_eq!(Config::default().validate(), Ok(vec![]));
+    }
+
+    #[test]
+    fn single_variable_is_invalid() {
+        let mut config = Config::default();
+        config.gbp.variables = 1;
+        assert_eq!(config.validate(), Err(ValidationError::TooFewVariables(1)));
+    }
+
+    #[test]
+    fn lookahead_multiple_must_be_less_than_variables() {
+        let mut config = Config::default();
+        config.gbp.variables = 3;
+        config.gbp.lookahead_multiple = 3;
+        assert_eq!(
+            config.validate(),
+            Err(ValidationError::IncompatibleLookaheadMultiple {
+                lookahead_multiple: 3,
+                variables: 3,
+            })
+        );
+
+        config.gbp.lookahead_multiple = 0;
+        assert!(config.validate().is_err());
+
+        config.gbp.lookahead_multiple = 2;
+        assert_eq!(config.validate(), Ok(vec![]));
+    }
+
+    #[test]
+    fn all_factors_disabled_warns() {
+        let mut config = Config::default();
+        config.gbp.factors_enabled = FactorsEnabledSection {
+            dynamic:    false,
+            interrobot: false,
+            obstacle:   false,
+            tracking:   false,
+        };
+        assert!(!config.gbp.factors_enabled.any_enabled());
+        assert_eq!(
+            config.validate(),
+            Ok(vec![ConfigWarning::AllFactorsDisabled])
+        );
+
+        config.gbp.factors_enabled.tracking = true;
+        assert_eq!(config.validate(), Ok(vec![]));
+    }
+
+    #[test]
+    fn sampled_radius_is_within_range() {
+        use rand::SeedableRng;
+
+        let mut rng = rand::rngs::StdRng::seed_from_u64(0);
+        let radius = RobotRadiusSection {
+            min: 0.5.try_into().expect("0.5 > 0.0"),
+            max: 2.0.try_into().expect("2.0 > 0.0"),
+        };
+        for _ in 0..1000 {
+            let sample = radius.sample(&mut rng);
+            assert!(radius.range().contains(&sample), "{sample} not in range");
+        }
+
+        let fixed = RobotRadiusSection::default();
+        assert!((0..100).all(|_| fixed.sample(&mut rng).to_bits() == fixed.min.get().to_bits()));
+    }
+
+    #[test]
+    fn real_time_only_at_time_scale_one() {
+        let mut simulation = SimulationSection::default();
+        simulation.time_scale = 1.0.try_into().expect("1.0 > 0.0");
+        assert!(simulation.is_real_time());
+
+        simulation.time_scale = 2.0.try_into().expect("2.0 > 0.0");
+        assert!(!simulation.is_real_time());
+    }
+
+    #[test]
+    fn time_scale_above_soft_cap_warns() {
+        let mut config = Config::default();
+        config.simulation.time_scale = 100.0.try_into().expect("100.0 > 0.0");
+        assert_eq!(config.validate(), Ok(vec![]));
+
+        config.simulation.time_scale = 1000.0.try_into().expect("1000.0 > 0.0");
+        assert_eq!(
+            config.validate(),
+            Ok(vec![ConfigWarning::TimeScaleAboveSoftCap(1000.0)])
+        );
+    }
+
+    #[test]
+    fn inverted_radius_range_is_invalid() {
+        let mut config = Config::default();
+        config.robot.radius.min = 3.0.try_into().expect("3.0 > 0.0");
+        config.robot.radius.max = 2.0.try_into().expect("2.0 > 0.0");
+        assert_eq!(
+            config.validate(),
+            Err(ValidationError::InvertedRadiusRange { min: 3.0, max: 2.0 })
+        );
+    }
+
+    #[test]
+    fn failure_rate_must_be_a_probability() {
+        let parse = |failure_rate: &str| {
+            toml::from_str::<CommunicationSection>(&format!(
+                "radius = 20.0\nfailure-rate = {failure_rate}"
+            ))
+        };
+
+        let err = parse("1.5").expect_err("failure rate above 1.0 is invalid");
+        assert!(
+            err.to_string().contains("value 1.5 is out of bounds"),
+            "unexpected error: {err}"
+        );
+        assert_eq!(
+            parse("0.2").map(|section| section.failure_rate.get()).ok(),
+            Some(0.2)
+        );
+    }
+
+    #[test]
+    fn non_positive_hz_is_rejected_when_parsed() {
+        let config = Config::default();
+        let contents = toml::to_string(&config).expect("config can be serialized");
+        assert!(contents.contains("hz = 60.0"));
+
+        let zero_hz = contents.replace("hz = 60.0", "hz = 0.0");
+        assert!(matches!(Config::parse(&zero_hz), Err(ParseError::Toml(_))));
+        assert!(Config::parse(&contents).is_ok());
+    }
+
+    #[test]
+    fn invalid_config_is_rejected_when_parsed() {
+        let mut config = Config::default();
+        config.robot.radius.min = 3.0.try_into().expect("3.0 > 0.0");
+        let contents = toml::to_string(&config).expect("config can be serialized");
+        assert!(matches!(
+            Config::parse(&contents),
+            Err(ParseError::Invalid(
+                ValidationError::InvertedRadiusRange { .. }
+            ))
+        ));
+    }
+
+    #[test]
+    fn merge_only_changes_overridden_fields() {
+        let base = Config::default();
+        let overrides =
+            PartialConfig::parse("[gbp]\nvariables = 12\n").expect("partial config is valid");
+        let merged = base.clone().merge(overrides);
+
+        assert_eq!(merged.gbp.variables, 12);
+        assert_eq!(merged.validate(), Ok(vec![]));
+
+        let without_variables = |config: &Config| {
+            let mut table = toml::Table::try_from(config).expect("config can be serialized");
+            table
+                .get_mut("gbp")
+                .and_then(toml::Value::as_table_mut)
+                .expect("config has a gbp section")
+                .remove("variables");
+            table
+        };
+        assert_eq!(without_variables(&merged), without_variables(&base));
+    }
+
+    #[test]
+    fn partial_config_rejects_invalid_fields() {
+        assert!(matches!(
+            PartialConfig::parse("[gbp]\nvariables = \"many\"\n"),
+            Err(ParseError::Toml(_))
+        ));
+        assert!(matches!(
+            PartialConfig::parse("[gbp]\nvaraibles = 12\n"),
+            Err(ParseError::UnknownField(field)) if field == "gbp.varaibles"
+        ));
+        assert!(matches!(
+            PartialConfig::parse("[gpb]\nvariables = 12\n"),
+            Err(ParseError::UnknownField(field)) if field == "gpb"
+        ));
+
+        let too_few_variables =
+            PartialConfig::parse("[gbp]\nvariables = 1\n").expect("partial config is valid");
+        assert_eq!(
+            Config::default().merge(too_few_variables).validate(),
+            Err(ValidationError::TooFewVariables(1))
+        );
+    }
+
+    #[test]
+    fn visualisation_change_does_not_require_restart() {
+        let config = Config::default();
+        let mut changed = config.clone();
+        changed.visualisation.draw.toggle(DrawSetting::Robots);
+        changed.simulation.time_scale = 2.0.try_into().expect("2.0 > 0.0");
+
+        assert!(!config.requires_restart(&config));
+        assert!(!config.requires_restart(&changed));
+    }
+
+    #[test]
+    fn variables_change_requires_restart() {
+        let config = Config::default();
+        let mut changed = config.clone();
+        changed.gbp.variables += 1;
+
+        assert!(config.requires_restart(&changed));
+        assert!(changed.requires_restart(&config));
+    }
+
+    #[test]
+    fn schedule_kind_is_parsed_from_config_name() {
+        assert_eq!(
+            "interleave-evenly".parse::<GbpIterationScheduleKind>().ok(),
+            Some(GbpIterationScheduleKind::InterleaveEvenly)
+        );
+        assert_eq!(
+            "soon-as-possible".parse::<GbpIterationScheduleKind>().ok(),
+            Some(GbpIterationScheduleKind::SoonAsPossible)
+        );
+        assert_eq!("custom".parse::<GbpIterationScheduleKind>().ok(), None);
+        assert_eq!("sideways".parse::<GbpIterationScheduleKind>().ok(), None);
+    }
+
+    #[test]
+    fn overrides_win_over_config_file() {
+        let mut schedule: GbpIterationSchedule =
+            toml::from_str("internal = 7\nexternal = 3\nschedule = \"centered\"")
+                .expect("schedule is valid");
+
+        let overrides = GbpIterationScheduleOverrides {
+            internal: Some(12),
+            external: None,
+            schedule: Some("late-as-possible".parse().expect("valid schedule kind")),
+        };
+        assert!(!overrides.is_empty());
+        overrides.apply(&mut schedule);
+
+        assert_eq!(schedule.internal, 12);
+        assert_eq!(schedule.external, 3, "fields not overridden are kept");
+        assert_eq!(schedule.schedule, GbpIterationScheduleKind::LateAsPossible);
+    }
+
+    #[test]
+    fn draw_section_round_trips_through_toml() {
+        let mut draw = DrawSection::default();
+        draw.set(DrawSetting::Sdf, true);
+        draw.set(DrawSetting::Robots, false);
+
+        let serialized = toml::to_string(&draw).expect("draw section can be serialized");
+        assert!(serialized.contains("interrobot-factors-safety-distance = false"));
+        assert!(serialized.contains("sdf = true"));
+
+        let deserialized: DrawSection =
+            toml::from_str(&serialized).expect("serialized draw section can be deserialized");
+        assert_eq!(deserialized, draw);
+    }
+
+    #[test]
+    fn draw_settings_are_toggled_individually() {
+        let mut draw = DrawSection::all_disabled();
+        assert!(draw.toggle(DrawSetting::Waypoints));
+        assert!(draw.get(DrawSetting::Waypoints));
+        assert_eq!(draw.flags(), DrawFlags::WAYPOINTS);
+
+        assert!(!draw.toggle(DrawSetting::Waypoints));
+        assert_eq!(draw, DrawSection::all_disabled());
+
+        draw.flip_all();
+        assert_eq!(draw, DrawSection::all_enabled());
+        assert!(draw.iter().all(|(_, enabled)| enabled));
+        assert_eq!(draw.iter().count(), DrawSetting::iter().count());
+    }
+
+    #[test]
+    fn schedule_kind_is_parsed_from_cli() {
+        use GbpIterationScheduleKind as Kind;
+        for (input, expected) in [
+            ("centered", Kind::Centered),
+            ("soon-as-possible", Kind::SoonAsPossible),
+            ("late-as-possible", Kind::LateAsPossible),
+            ("interleave-evenly", Kind::InterleaveEvenly),
+            ("half-beginning-half-end", Kind::HalfBeginningHalfEnd),
+            ("Soon_As_Possible", Kind::SoonAsPossible),
+            (" late_as_possible ", Kind::LateAsPossible),
+        ] {
+            assert_eq!(GbpIterationScheduleKind::parse_cli(input), Ok(expected));
+        }
+    }
+
+    #[test]
+    fn unknown_cli_schedule_lists_valid_kinds() {
+        assert_eq!(
+            GbpIterationScheduleKind::parse_cli("sideways"),
+            Err(
+                "unknown gbp schedule `sideways`, expected one of: centered, soon-as-possible, \
+                 late-as-possible, interleave-evenly, half-beginning-half-end"
+                    .to_string()
+            )
+        );
+        assert_eq!(GbpIterationScheduleKind::parse_cli("custom").ok(), None);
+    }
+
+    #[test]
+    fn custom_schedule_replays_pattern() {
+        let pattern = vec![(true, false), (false, false), (true, true), (false, true)];
+        let schedule = GbpIterationSchedule {
+            internal: 1,
+            external: 1,
+            schedule: GbpIterationScheduleKind::Custom(pattern.clone()),
+        };
+
+        let serialized = toml::to_string(&schedule).expect("schedule can be serialized");
+        let deserialized: GbpIterationSchedule =
+            toml::from_str(&serialized).expect("serialized schedule can be deserialized");
+        assert_eq!(deserialized.schedule, schedule.schedule);
+
+        let params = gbp_schedule::GbpScheduleParams {
+            internal: 1,
+            external: 1,
+        };
+        let steps = deserialized
+            .schedule
+            .get(params)
+            .map(|step| (step.internal, step.external))
+            .collect::<Vec<_>>();
+        assert_eq!(steps, pattern);
+
+        assert_eq!(schedule.internal_iterations(), 2);
+        assert_eq!(schedule.external_iterations(), 2);
+        let fixed = GbpIterationSchedule {
+            schedule: GbpIterationScheduleKind::Centered,
+            ..schedule
+        };
+        assert_eq!(fixed.internal_iterations(), 1);
     }
 }
@@ -1,6 +1,6 @@
 #![warn(missing_docs)]
 
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
 use directories::BaseDirs;
 
@@ -24,34 +24,98 @@ pub enum ConfigReaderError {
 /// Result type for [`read_config`]
 pub type Result<T> = std::result::Result<T, ConfigReaderError>;
 
-fn default_paths() -> Vec<std::path::PathBuf> {
+/// The default locations of the config file, in order of precedence
+/// - `config_dir` is the user's config directory, e.g. `~/.config` on Linux
+/// - `cwd` is the current working directory
+fn default_paths(config_dir: Option<&Path>, cwd: Option<&Path>) -> Vec<PathBuf> {
     let mut paths = vec![];
 
-    if let Some(base_dirs) = BaseDirs::new() {
-        paths.push(
-            base_dirs
-                .config_dir()
-                .join("gbpplanner")
-                .join("config.toml"),
-        );
+    if let Some(config_dir) = config_dir {
+        paths.push(config_dir.join("gbpplanner").join("config.toml"));
     }
 
-    if let Ok(cwd) = std::env::current_dir() {
+    if let Some(cwd) = cwd {
         paths.push(cwd.join("config/config.toml"));
     }
 
     paths
 }
 
-pub fn read_config<P: AsRef<Path>>(path: Option<P>) -> Result<Config> {
-    if let Some(path) = path
-        .map(|p| p.as_ref().to_path_buf())
+/// The first of the [`default_paths`] that exists
+fn resolve_path(config_dir: Option<&Path>, cwd: Option<&Path>) -> Option<PathBuf> {
+    default_paths(config_dir, cwd)
         .into_iter()
-        .chain(default_paths())
         .find(|p| p.exists())
-    {
-        Ok(Config::from_file(path)?)
-    } else {
-        Err(ConfigReaderError::NoConfigFile)
+}
+
+/// Read the config file at `path` if given and it exists, otherwise the first
+/// config file found among the default locations, see
+/// [`Config::resolve_default_path`]
+///
+/// # Errors
+///
+/// Returns [`ConfigReaderError::NoConfigFile`] if no config file is found, or
+/// an error if the found config file can not be read or parsed
+pub fn read_config<P: AsRef<Path>>(path: Option<P>) -> Result<Config> {
+    let path = path
+        .map(|p| p.as_ref().to_path_buf())
+        .filter(|p| p.exists())
+        .or_else(Config::resolve_default_path)
+        .ok_or(ConfigReaderError::NoConfigFile)?;
+    Ok(Config::from_file(path)?)
+}
+
+impl Config {
+    /// Find the first config file that exists among the default locations:
+    /// 1. `gbpplanner/config.toml` in the user's config directory, i.e.
+    ///    `$XDG_CONFIG_HOME` or `~/.config` on Linux
+    /// 2. `config/config.toml` in the current working directory
+    #[must_use]
+    pub fn resolve_default_path() -> Option<PathBuf> {
+        let base_dirs = BaseDirs::new();
+        let cwd = std::env::current_dir().ok();
+        resolve_path(base_dirs.as_ref().map(BaseDirs::config_dir), cwd.as_deref())
+    }
+
+    /// Read the config file found by [`Config::resolve_default_path`]
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ConfigReaderError::NoConfigFile`] if no config file is found,
+    /// or an error if the found config file can not be read or parsed
+    pub fn from_default_locations() -> Result<Self> {
+        read_config(None::<&Path>)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn config_dir_takes_precedence_over_cwd() {
+        let root = std::env::temp_dir().join(format!("gbp-config-paths-{}", std::process::id()));
+        let config_dir = root.join("config-dir");
+        let cwd = root.join("cwd");
+        let in_config_dir = config_dir.join("gbpplanner").join("config.toml");
+        let in_cwd = cwd.join("config").join("config.toml");
+        let (config_dir, cwd) = (Some(config_dir.as_path()), Some(cwd.as_path()));
+
+        assert_eq!(resolve_path(config_dir, cwd), None);
+
+        let contents = toml::to_string(&Config::default()).expect("default config serializes");
+        for path in [&in_config_dir, &in_cwd] {
+            std::fs::create_dir_all(path.parent().expect("path has a parent"))
+                .expect("temp dir can be created");
+            std::fs::write(path, &contents).expect("config can be written");
+        }
+
+        assert_eq!(resolve_path(config_dir, cwd), Some(in_config_dir.clone()));
+        assert_eq!(resolve_path(None, cwd), Some(in_cwd.clone()));
+
+        std::fs::remove_file(&in_config_dir).expect("config can be removed");
+        assert_eq!(resolve_path(config_dir, cwd), Some(in_cwd));
+
+        std::fs::remove_dir_all(&root).expect("temp dir can be removed");
     }
 }
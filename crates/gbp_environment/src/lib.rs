@@ -26,6 +26,57 @@ impl TileCoordinates {
     }
 }
 
+/// Bitflags of the neighbouring tiles a tile has a path to
+const NORTH: u8 = 1 << 0;
+const EAST: u8 = 1 << 1;
+const SOUTH: u8 = 1 << 2;
+const WEST: u8 = 1 << 3;
+
+/// Box-drawing glyphs of the tiles with a path through them, indexed by the
+/// bitflags of the neighbours the path connects to
+const TILE_GLYPHS: [Option<char>; 16] = [
+    None,
+    Some('╵'),
+    Some('╶'),
+    Some('└'),
+    Some('╷'),
+    Some('│'),
+    Some('┌'),
+    Some('├'),
+    Some('╴'),
+    Some('┘'),
+    Some('─'),
+    Some('┴'),
+    Some('┐'),
+    Some('┤'),
+    Some('┬'),
+    Some('┼'),
+];
+
+/// Bitflags of the neighbours the path through `tile` connects to, or `None`
+/// if `tile` is not a box-drawing glyph
+fn tile_connections(tile: char) -> Option<u8> {
+    (0u8..)
+        .zip(TILE_GLYPHS)
+        .find_map(|(connections, glyph)| (glyph == Some(tile)).then_some(connections))
+}
+
+/// Box-drawing glyph of a tile with a path to the neighbours in `connections`
+fn tile_glyph(connections: u8) -> Option<char> {
+    TILE_GLYPHS.get(usize::from(connections)).copied().flatten()
+}
+
+/// Remap every box-drawing glyph in `row` with `remap` applied to its
+/// connections, keeping every other tile as is
+fn remap_glyphs(row: impl Iterator<Item = char>, remap: impl Fn(u8) -> u8) -> String {
+    row.map(|tile| {
+        tile_connections(tile)
+            .and_then(|connections| tile_glyph(remap(connections)))
+            .unwrap_or(tile)
+    })
+    .collect()
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, IntoIterator)]
 #[into_iterator(owned, ref)]
 #[serde(rename_all = "kebab-case")]
@@ -68,6 +119,138 @@ impl TileGrid {
         self.0.get(row).and_then(|r| r.chars().nth(col))
     }
 
+    /// Returns an iterator over every tile and its coordinates, row by row
+    pub fn cells(&self) -> impl Iterator<Item = (TileCoordinates, char)> + '_ {
+        self.0.iter().enumerate().flat_map(|(row, tiles)| {
+            tiles
+                .chars()
+                .enumerate()
+                .map(move |(col, tile)| (TileCoordinates::new(row, col), tile))
+        })
+    }
+
+    /// Returns `true` if all rows have the same number of columns
+    pub fn is_rectangular(&self) -> bool {
+        self.0.iter().map(|row| row.chars().count()).all_equal()
+    }
+
+    /// Returns a new tilegrid surrounded by `border` rings of the `fill` tile
+    /// e.g. padding a generated maze with empty space, `' '`
+    #[must_use]
+    pub fn padded(&self, border: usize, fill: char) -> Self {
+        let ncols = self.0.first().map_or(0, |row| row.chars().count()) + 2 * border;
+        let border_row = fill.to_string().repeat(ncols);
+        let side = fill.to_string().repeat(border);
+
+        let rows = std::iter::repeat(border_row.clone())
+            .take(border)
+            .chain(self.0.iter().map(|row| format!("{side}{row}{side}")))
+            .chain(std::iter::repeat(border_row).take(border))
+            .collect();
+
+        Self(rows)
+    }
+
+    /// Returns a new tilegrid with `top`, `bottom` rows and `left`, `right`
+    /// columns removed
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if:
+    /// 1. All rows or all columns are removed
+    /// 2. The rows of the resulting tilegrid are not the same length
+    pub fn cropped(
+        &self,
+        top: usize,
+        bottom: usize,
+        left: usize,
+        right: usize,
+    ) -> Result<Self, EnvironmentError> {
+        let nrows = self.nrows().saturating_sub(top + bottom);
+        let rows: Vec<String> = self
+            .0
+            .iter()
+            .skip(top)
+            .take(nrows)
+            .map(|row| {
+                let ncols = row.chars().count().saturating_sub(left + right);
+                row.chars().skip(left).take(ncols).collect()
+            })
+            .collect();
+
+        let cropped = Self(rows);
+        if cropped.is_empty() || cropped.0.iter().all(String::is_empty) {
+            Err(EnvironmentError::EmptyGrid)
+        } else if !cropped.is_rectangular() {
+            Err(EnvironmentError::DifferentLengthRows)
+        } else {
+            Ok(cropped)
+        }
+    }
+
+    /// Returns a new tilegrid rotated 90 degrees clockwise, with every
+    /// box-drawing glyph rotated along with it
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if the rows of the tilegrid are not the same length
+    pub fn rotated_90(&self) -> Result<Self, EnvironmentError> {
+        if !self.is_rectangular() {
+            return Err(EnvironmentError::DifferentLengthRows);
+        }
+
+        let rotate = |connections: u8| {
+            let mut rotated = 0;
+            for (from, to) in [(NORTH, EAST), (EAST, SOUTH), (SOUTH, WEST), (WEST, NORTH)] {
+                if connections & from != 0 {
+                    rotated |= to;
+                }
+            }
+            rotated
+        };
+
+        let rows: Vec<Vec<char>> = self.0.iter().map(|row| row.chars().collect()).collect();
+        let ncols = rows.first().map_or(0, Vec::len);
+        Ok(Self(
+            (0..ncols)
+                .map(|col| remap_glyphs(rows.iter().rev().map(|row| row[col]), rotate))
+                .collect(),
+        ))
+    }
+
+    /// Returns a new tilegrid mirrored left to right, with every box-drawing
+    /// glyph mirrored along with it e.g. `┌` becomes `┐`
+    #[must_use]
+    pub fn mirrored_horizontal(&self) -> Self {
+        let mirror = |connections: u8| {
+            let swapped = (connections & EAST) << 2 | (connections & WEST) >> 2;
+            connections & (NORTH | SOUTH) | swapped
+        };
+        Self(
+            self.0
+                .iter()
+                .map(|row| remap_glyphs(row.chars().rev(), mirror))
+                .collect(),
+        )
+    }
+
+    /// Returns a new tilegrid mirrored top to bottom, with every box-drawing
+    /// glyph mirrored along with it e.g. `┌` becomes `└`
+    #[must_use]
+    pub fn mirrored_vertical(&self) -> Self {
+        let mirror = |connections: u8| {
+            let swapped = (connections & NORTH) << 2 | (connections & SOUTH) >> 2;
+            connections & (EAST | WEST) | swapped
+        };
+        Self(
+            self.0
+                .iter()
+                .rev()
+                .map(|row| remap_glyphs(row.chars(), mirror))
+                .collect(),
+        )
+    }
+
     // /// override the index operator to allow for easy access to the grid
     // pub fn get(&self, row: usize, col: usize) -> Option<char> {
     //     self.0.get(row).and_then(|r| r.chars().nth(col))
@@ -134,10 +317,16 @@ impl Circle {
     }
 
     /// Check if a given point is inside the circle
-    /// Expects translation and rotation to be performed beforehand
+    /// Expects translation and rotation to be performed beforehand, i.e.
+    /// `point` is relative to the center of the circle, in the same units as
+    /// the radius
+    /// Points on the boundary are inside
+    #[allow(clippy::cast_possible_truncation)]
     pub fn inside(&self, point: Vec2) -> bool {
-        let squared_distance = point.length_squared();
-        squared_distance <= self.radius.get().powi(2) as f32
+        // compare in the precision of `point`, so a point placed exactly at the
+        // radius is not misjudged by rounding the radius after squaring it
+        let radius = self.radius.get() as f32;
+        point.length_squared() <= radius * radius
     }
 }
 
@@ -230,8 +419,15 @@ fn sign(p1: Vec2, p2: Vec2, p3: Vec2) -> f32 {
     (p1.x - p3.x) * (p2.y - p3.y) - (p2.x - p3.x) * (p1.y - p3.y)
 }
 
+/// Scale from the lengths of a [`RegularPolygon`] or [`Rectangle`] to tile
+/// units, i.e. their lengths are relative to half the side length of a tile
+/// This matches the size of the meshes spawned for them in the simulation
+pub const HALF_TILE_SCALE: Float = 0.5;
+
 /// A regular polygon to be placed in the environment
 /// - A [`PlaceableShape`] variant
+/// - `radius` is scaled by [`HALF_TILE_SCALE`] to get the circumradius in tile
+///   units
 #[derive(Debug, Serialize, Deserialize, Clone, derive_more::Constructor)]
 #[serde(rename_all = "kebab-case")]
 pub struct RegularPolygon {
@@ -293,15 +489,37 @@ impl RegularPolygon {
             .collect()
     }
 
+    /// Get the vertices of the polygon in tile units
+    pub fn vertices(&self) -> Vec<(Float, Float)> {
+        (0..self.sides)
+            .map(|i| self.point_at(i))
+            .map(|(x, y)| (x * HALF_TILE_SCALE, y * HALF_TILE_SCALE))
+            .collect()
+    }
+
+    /// Offset in radians added to an obstacle's rotation, when spawning the
+    /// polygon as a collider in the simulation
+    /// - Squares are turned half a revolution, odd-sided polygons three
+    ///   quarters, and other even-sided polygons a quarter
+    pub fn collider_rotation_offset(&self) -> f32 {
+        use std::f32::consts::{FRAC_PI_2, PI};
+        PI + match self.sides {
+            4 => 0.0,
+            n if n % 2 != 0 => FRAC_PI_2,
+            _ => -FRAC_PI_2,
+        }
+    }
+
     /// Check if a given point is inside the polygon
-    /// Expects translation and rotation to be performed beforehand
+    /// Expects translation and rotation to be performed beforehand, i.e.
+    /// `point` is relative to the center of the polygon, in tile units
     pub fn inside(&self, point: Vec2) -> bool {
         let mut inside = false;
-        let (x, y) = (point.x as f64 * 2.0, point.y as f64 * 2.0);
-        let mut j = self.sides - 1;
-        for i in 0..self.sides {
-            let (xi, yi) = self.point_at(i);
-            let (xj, yj) = self.point_at(j);
+        let (x, y) = (point.x as f64, point.y as f64);
+        let vertices = self.vertices();
+        let mut j = vertices.len() - 1;
+        for (i, &(xi, yi)) in vertices.iter().enumerate() {
+            let (xj, yj) = vertices[j];
             if yi < y && yj >= y || yj < y && yi >= y {
                 if xi + (y - yi) / (yj - yi) * (xj - xi) < x {
                     inside = !inside;
@@ -315,6 +533,8 @@ impl RegularPolygon {
 
 /// A rectangle to be placed in the environment
 /// - A [`PlaceableShape`] variant
+/// - `width` and `height` are scaled by [`HALF_TILE_SCALE`] to get the size in
+///   tile units
 #[derive(Debug, Serialize, Deserialize, Clone, derive_more::Constructor)]
 #[serde(rename_all = "kebab-case")]
 pub struct Rectangle {
@@ -344,15 +564,23 @@ impl Rectangle {
         )
     }
 
+    /// Get half the width and half the height of the rectangle in tile units
+    pub fn half_extents(&self) -> (Float, Float) {
+        (
+            self.width.get() * HALF_TILE_SCALE / 2.0,
+            self.height.get() * HALF_TILE_SCALE / 2.0,
+        )
+    }
+
     /// Check if a given point is inside the rectangle
-    /// Expects translation and rotation to be performed beforehand
+    /// Expects translation and rotation to be performed beforehand, i.e.
+    /// `point` is relative to the center of the rectangle, in tile units
     pub fn inside(&self, point: Vec2) -> bool {
         let (x, y) = (point.x as f64, point.y as f64);
 
-        let half_width = self.width.get() / 4.0;
-        let half_height = self.height.get() / 4.0;
+        let (half_width, half_height) = self.half_extents();
 
-        if x >= -half_height && x <= half_height && y >= -half_width && y <= half_width {
+        if x >= -half_width && x <= half_width && y >= -half_height && y <= half_height {
             return true;
         }
 
@@ -371,27 +599,16 @@ pub struct Polygon {
 }
 
 impl Polygon {
-    /// Expand the polygon's size by scaling around the average pointmass by
+    /// Expand the polygon's size by scaling around its centroid by
     /// `expansion` as an addition
     pub fn expanded(&self, expansion: Float) -> Self {
-        let point_center = {
-            let acc = self
-                .points
-                .clone()
-                .into_iter()
-                .fold([0.0, 0.0], |acc, p| [acc[0] + p.x, acc[1] + p.y]);
-
-            [
-                acc[0] / self.points.len() as Float,
-                acc[1] / self.points.len() as Float,
-            ]
-        };
+        let center = self.centroid();
 
         let new_points = {
             self.points
                 .iter()
                 .map(|p| {
-                    let direction = [p.x - point_center[0], p.y - point_center[1]];
+                    let direction = [p.x - center.x, p.y - center.y];
                     Point::new(
                         p.x + direction[0] * 4.0 * expansion,
                         p.y + direction[1] * 4.0 * expansion,
@@ -403,6 +620,27 @@ impl Polygon {
         Polygon::new(new_points)
     }
 
+    /// Signed area of the polygon
+    /// Positive if the points are in counter-clockwise order, and negative if
+    /// they are in clockwise order
+    pub fn signed_area(&self) -> Float {
+        gbp_geometry::polygon_signed_area(&self.points)
+    }
+
+    /// Returns `true` if the points of the polygon are in clockwise order
+    pub fn is_clockwise(&self) -> bool {
+        self.signed_area() < 0.0
+    }
+
+    /// Centroid of the area enclosed by the polygon
+    ///
+    /// # Panics
+    ///
+    /// If the polygon has no points
+    pub fn centroid(&self) -> Point {
+        gbp_geometry::polygon_centroid(&self.points)
+    }
+
     /// Check if a given point is inside the polygon by checking if a ray cast
     /// directly to the left from any given point intersects the walls of
     /// the polygon an even or odd amount of times Expects translation and
@@ -527,6 +765,99 @@ impl PlaceableShape {
             Self::Rectangle(rectangle) => rectangle.inside(point),
         }
     }
+
+    /// Area of the shape in square tile units
+    #[allow(clippy::cast_precision_loss)]
+    pub fn area(&self) -> Float {
+        match self {
+            Self::Circle(circle) => std::f64::consts::PI * circle.radius.get().powi(2),
+            Self::Triangle(triangle) => {
+                let [a, b, c] = triangle.points();
+                Float::from((b - a).perp_dot(c - a).abs()) / 2.0
+            }
+            Self::RegularPolygon(regular_polygon) => {
+                let sides = regular_polygon.sides as Float;
+                let circumradius = regular_polygon.radius.get() * HALF_TILE_SCALE;
+                sides / 2.0 * circumradius.powi(2) * (std::f64::consts::TAU / sides).sin()
+            }
+            Self::Polygon(polygon) => polygon.signed_area().abs(),
+            Self::Rectangle(rectangle) => {
+                let (half_width, half_height) = rectangle.half_extents();
+                4.0 * half_width * half_height
+            }
+        }
+    }
+
+    /// Centroid of the area of the shape, in the same coordinate space as
+    /// [`PlaceableShape::outline`]
+    /// Every shape except triangles and polygons is centered on its own center
+    ///
+    /// # Panics
+    ///
+    /// If the shape is a polygon with no points
+    pub fn centroid(&self) -> Vec2 {
+        match self {
+            Self::Circle(_) | Self::RegularPolygon(_) | Self::Rectangle(_) => Vec2::ZERO,
+            Self::Triangle(triangle) => triangle.points().into_iter().sum::<Vec2>() / 3.0,
+            Self::Polygon(polygon) => Vec2::from(polygon.centroid()),
+        }
+    }
+
+    /// Outline of the shape relative to its own center, in tile units and
+    /// before any rotation is applied
+    /// - Circles are tessellated into `circle_segments` vertices
+    #[allow(clippy::cast_possible_truncation, clippy::cast_precision_loss)]
+    pub fn outline(&self, circle_segments: usize) -> Vec<Vec2> {
+        match self {
+            Self::Circle(circle) => {
+                let radius = circle.radius.get() as f32;
+                (0..circle_segments)
+                    .map(|i| {
+                        let angle = std::f32::consts::TAU * i as f32 / circle_segments as f32;
+                        Vec2::from_angle(angle) * radius
+                    })
+                    .collect()
+            }
+            Self::Triangle(triangle) => triangle.points().to_vec(),
+            Self::RegularPolygon(regular_polygon) => regular_polygon
+                .vertices()
+                .into_iter()
+                .map(|(x, y)| Vec2::new(x as f32, y as f32))
+                .collect(),
+            Self::Polygon(polygon) => polygon.points.iter().copied().map(Vec2::from).collect(),
+            Self::Rectangle(rectangle) => {
+                let (half_width, half_height) = rectangle.half_extents();
+                let (half_width, half_height) = (half_width as f32, half_height as f32);
+                vec![
+                    Vec2::new(-half_width, -half_height),
+                    Vec2::new(half_width, -half_height),
+                    Vec2::new(half_width, half_height),
+                    Vec2::new(-half_width, half_height),
+                ]
+            }
+        }
+    }
+
+    /// Offset in radians added to an obstacle's rotation, when rendering the
+    /// shape into an image, so it lines up with how it is spawned in the
+    /// simulation
+    /// - Regular polygons are turned half a revolution, and odd-sided ones an
+    ///   additional half of the angle between two vertices
+    /// - Polygons and rectangles are not offset, as the width of a rectangle is
+    ///   along the x-axis of the image
+    /// - Every other shape is turned a quarter revolution
+    #[allow(clippy::cast_precision_loss)]
+    pub fn render_rotation_offset(&self) -> f32 {
+        use std::f32::consts::{FRAC_PI_2, PI};
+        match self {
+            Self::RegularPolygon(RegularPolygon { sides, .. }) if sides % 2 != 0 => {
+                PI + PI / *sides as f32
+            }
+            Self::RegularPolygon(_) => PI,
+            Self::Polygon(_) | Self::Rectangle(_) => 0.0,
+            Self::Circle(_) | Self::Triangle(_) => FRAC_PI_2,
+        }
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -563,6 +894,70 @@ impl Obstacle {
                 .expect("Invalid relative point"),
         }
     }
+
+    /// Outline of the obstacle in world coordinates, i.e. translated into its
+    /// tile, rotated and scaled by `tile_size`
+    /// - `grid_shape` is the `(rows, cols)` shape of the [`TileGrid`] the
+    ///   obstacle is placed in, as the grid is centered around the origin
+    /// - Each shape is placed the same way as its collider is spawned in the
+    ///   simulation, which differs between shapes
+    #[allow(clippy::cast_possible_truncation, clippy::cast_precision_loss)]
+    pub fn world_points(
+        &self,
+        (nrows, ncols): (usize, usize),
+        tile_size: f32,
+        circle_segments: usize,
+    ) -> Vec<Vec2> {
+        use std::f32::consts::FRAC_PI_2;
+
+        let grid_offset_x = ncols as f32 / 2.0 - 0.5;
+        let grid_offset_y = nrows as f32 / 2.0 - 0.5;
+
+        let offset_x = (self.tile_coordinates.col as f32 - grid_offset_x) * tile_size;
+        let offset_y = (self.tile_coordinates.row as f32 - grid_offset_y) * tile_size;
+        let pos_offset = tile_size / 2.0;
+
+        let x = (self.translation.x.get() as f32).mul_add(tile_size, offset_x) - pos_offset;
+        let y = (self.translation.y.get() as f32).mul_add(tile_size, offset_y) - pos_offset;
+        let rotation = self.rotation.as_radians() as f32;
+
+        let outline = self.shape.outline(circle_segments).into_iter();
+        let (center, points): (Vec2, Vec<Vec2>) = match &self.shape {
+            PlaceableShape::Circle(_) => {
+                let y = (1.0 - self.translation.y.get() as f32).mul_add(tile_size, offset_y)
+                    - pos_offset;
+                (Vec2::new(x, y), outline.collect())
+            }
+            PlaceableShape::Triangle(_) => {
+                // The points are mirrored along the x-axis, rotated by the
+                // inverse of `FRAC_PI_2 - rotation`, and then placed with that
+                // angle minus `FRAC_PI_2`
+                let angle = FRAC_PI_2 - rotation;
+                let pre_rotation = Vec2::from_angle(-angle);
+                let rotation = Vec2::from_angle(angle - FRAC_PI_2);
+                let points = outline
+                    .map(|point| rotation.rotate(pre_rotation.rotate(Vec2::new(-point.x, point.y))))
+                    .collect();
+                (Vec2::new(x, -y), points)
+            }
+            PlaceableShape::RegularPolygon(polygon) => {
+                // The points are rotated by the offset rotation, and then
+                // placed with that same angle
+                let rotation = Vec2::from_angle(rotation + polygon.collider_rotation_offset());
+                let points = outline
+                    .map(|point| rotation.rotate(rotation.rotate(point)))
+                    .collect();
+                (Vec2::new(x, -y), points)
+            }
+            PlaceableShape::Polygon(_) => (Vec2::new(x, y), outline.collect()),
+            PlaceableShape::Rectangle(_) => (Vec2::new(x, -y), outline.collect()),
+        };
+
+        points
+            .into_iter()
+            .map(|point| center + point * tile_size)
+            .collect()
+    }
 }
 
 /// Struct to represent a list of shapes that can be placed in the map [`Grid`]
@@ -586,6 +981,30 @@ impl Obstacles {
     pub fn iter(&self) -> std::slice::Iter<Obstacle> {
         self.0.iter()
     }
+
+    /// Add an [`Obstacle`] to the end
+    pub fn push(&mut self, obstacle: Obstacle) {
+        self.0.push(obstacle);
+    }
+
+    /// Add an [`Obstacle`] to the end, builder style
+    #[must_use]
+    pub fn with_obstacle(mut self, obstacle: Obstacle) -> Self {
+        self.push(obstacle);
+        self
+    }
+
+    /// Returns the number of obstacles
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Returns `true` if there are no obstacles
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -616,6 +1035,28 @@ impl Default for SdfSettings {
     }
 }
 
+impl SdfSettings {
+    /// Ensure that the [`SdfSettings`] are within bounds
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if:
+    /// 1. `resolution` is 0, as the signed distance field image would be empty
+    /// 2. `expansion` is not in [0.0, 1.0]
+    /// 3. `blur` is not in [0.0, 1.0]
+    pub fn validate(&self) -> Result<(), EnvironmentError> {
+        if self.resolution == 0 {
+            Err(EnvironmentError::SdfResolutionZero)
+        } else if !(0.0..=1.0).contains(&self.expansion) {
+            Err(EnvironmentError::SdfExpansionOutOfRange(self.expansion))
+        } else if !(0.0..=1.0).contains(&self.blur) {
+            Err(EnvironmentError::SdfBlurOutOfRange(self.blur))
+        } else {
+            Ok(())
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "kebab-case")]
 pub struct Tiles {
@@ -653,8 +1094,20 @@ impl Tiles {
     }
 }
 
-#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(
+    Debug,
+    Default,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    strum_macros::EnumIter,
+    strum_macros::AsRefStr,
+)]
 #[cfg_attr(not(target_arch = "wasm32"), derive(clap::ValueEnum))]
+#[strum(serialize_all = "kebab-case")]
 pub enum EnvironmentType {
     #[default]
     Intersection,
@@ -665,6 +1118,21 @@ pub enum EnvironmentType {
     Test,
 }
 
+impl EnvironmentType {
+    /// The predefined [`Environment`] of this type
+    #[must_use]
+    pub fn environment(self) -> Environment {
+        match self {
+            Self::Intersection => Environment::intersection(),
+            Self::Intermediate => Environment::intermediate(),
+            Self::Complex => Environment::complex(),
+            Self::Circle => Environment::circle(),
+            Self::Maze => Environment::maze(),
+            Self::Test => Environment::test(),
+        }
+    }
+}
+
 /// **Bevy** [`Resource`]
 /// The environment configuration for the simulation
 #[derive(Debug, Clone, Serialize, Deserialize, Resource)]
@@ -680,6 +1148,23 @@ impl Default for Environment {
     }
 }
 
+/// Statistics of an [`Environment`], for comparing scenarios
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct EnvironmentStats {
+    /// Number of rows in the tilegrid
+    pub rows: usize,
+    /// Number of columns in the tilegrid
+    pub cols: usize,
+    /// Number of tiles that are entirely filled, i.e. `' '`
+    pub wall_tiles: usize,
+    /// Number of tiles with a path through them
+    pub free_tiles: usize,
+    /// Number of placed obstacles
+    pub obstacle_count: usize,
+    /// Sum of the areas of the placed obstacles in square tile units
+    pub total_obstacle_area: Float,
+}
+
 #[derive(Debug, thiserror::Error)]
 pub enum ParseError {
     #[error("IO error: {0}")]
@@ -700,9 +1185,23 @@ pub enum EnvironmentError {
     EmptyGrid,
     #[error("Environment matrix representation has rows of different lengths")]
     DifferentLengthRows,
+    #[error("Environments to concatenate have {0} and {1} rows, expected the same")]
+    DifferentRowCounts(usize, usize),
+    #[error("Environments to concatenate have {0} and {1} columns, expected the same")]
+    DifferentColumnCounts(usize, usize),
+    #[error("tiles.settings.sdf.resolution must be at least 1")]
+    SdfResolutionZero,
+    #[error("tiles.settings.sdf.expansion must be in [0.0, 1.0], got {0}")]
+    SdfExpansionOutOfRange(f32),
+    #[error("tiles.settings.sdf.blur must be in [0.0, 1.0], got {0}")]
+    SdfBlurOutOfRange(f32),
 }
 
 impl Environment {
+    /// Number of vertices used to tessellate circular obstacles in
+    /// [`Environment::obstacle_footprints`]
+    pub const CIRCLE_FOOTPRINT_SEGMENTS: usize = 32;
+
     /// Attempt to parse an [`Environment`] from a YAML file at `path`
     ///
     /// # Errors
@@ -744,7 +1243,10 @@ impl Environment {
     /// Will return `Err` if:
     /// 1. The matrix representation is not empty
     /// 2. All rows in the matrix representation are the same length
+    /// 3. The [`SdfSettings`] are not within bounds, see
+    ///    [`SdfSettings::validate`]
     pub fn validate(self) -> Result<Self, EnvironmentError> {
+        self.tiles.settings.sdf.validate()?;
         if self.tiles.grid.is_empty() {
             Err(EnvironmentError::EmptyGrid)
         } else if self
@@ -840,6 +1342,81 @@ impl Environment {
         }
     }
 
+    /// Generate a perfect maze of `rows` x `cols` tiles, i.e. a maze where
+    /// every tile can be reached from every other tile by exactly one path
+    /// - Generated with a recursive backtracker seeded with `seed`
+    /// - The maze is surrounded by a ring of filled tiles, like
+    ///   [`Environment::maze`]
+    ///
+    /// # Panics
+    ///
+    /// If `rows` or `cols` is 0
+    #[must_use]
+    pub fn random_maze(rows: usize, cols: usize, seed: u64) -> Self {
+        use rand::{seq::SliceRandom, SeedableRng};
+
+        assert!(rows > 0 && cols > 0, "a maze must have at least one tile");
+
+        // `StdRng` is not guaranteed to produce the same values across
+        // versions of `rand`, so the same seed could give a different maze
+        let mut rng = bevy_prng::WyRand::seed_from_u64(seed);
+        let mut connections = vec![0u8; rows * cols];
+        let mut visited = vec![false; rows * cols];
+        let mut stack = vec![(0, 0)];
+        visited[0] = true;
+
+        while let Some(&(row, col)) = stack.last() {
+            let mut neighbours = Vec::with_capacity(4);
+            if row > 0 {
+                neighbours.push((row - 1, col, NORTH, SOUTH));
+            }
+            if col + 1 < cols {
+                neighbours.push((row, col + 1, EAST, WEST));
+            }
+            if row + 1 < rows {
+                neighbours.push((row + 1, col, SOUTH, NORTH));
+            }
+            if col > 0 {
+                neighbours.push((row, col - 1, WEST, EAST));
+            }
+            neighbours.retain(|&(r, c, _, _)| !visited[r * cols + c]);
+
+            let Some(&(next_row, next_col, towards, back)) = neighbours.choose(&mut rng) else {
+                stack.pop();
+                continue;
+            };
+            connections[row * cols + col] |= towards;
+            connections[next_row * cols + next_col] |= back;
+            visited[next_row * cols + next_col] = true;
+            stack.push((next_row, next_col));
+        }
+
+        let grid = TileGrid::new(
+            connections
+                .chunks(cols)
+                .map(|row| {
+                    row.iter()
+                        .map(|&connections| tile_glyph(connections).unwrap_or(' '))
+                        .collect::<String>()
+                })
+                .collect(),
+        )
+        .padded(1, ' ');
+
+        Self {
+            tiles:     Tiles {
+                grid,
+                settings: TileSettings {
+                    tile_size: 10.0,
+                    path_width: 0.75,
+                    obstacle_height: 1.0,
+                    sdf: SdfSettings::default(),
+                },
+            },
+            obstacles: Obstacles::empty(),
+        }
+    }
+
     #[must_use]
     #[rustfmt::skip]
     pub fn maze() -> Self {
@@ -968,4 +1545,709 @@ impl Environment {
     pub const fn tile_size(&self) -> f32 {
         self.tiles.settings.tile_size
     }
+
+    /// Place the tilegrid of `other` to the right of the tilegrid of `self`
+    /// - The obstacles of `other` are moved along with its tiles
+    /// - The tile settings of `self` are kept
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if the tilegrids do not have the same number of rows
+    pub fn concat_horizontal(&self, other: &Self) -> Result<Self, EnvironmentError> {
+        let (rows, other_rows) = (self.tiles.grid.nrows(), other.tiles.grid.nrows());
+        if rows != other_rows {
+            return Err(EnvironmentError::DifferentRowCounts(rows, other_rows));
+        }
+
+        let grid = TileGrid::new(
+            self.tiles
+                .grid
+                .iter()
+                .zip(other.tiles.grid.iter())
+                .map(|(left, right)| format!("{left}{right}"))
+                .collect(),
+        );
+        Ok(self.concat_with(grid, other, (0, self.tiles.grid.ncols())))
+    }
+
+    /// Place the tilegrid of `other` below the tilegrid of `self`
+    /// - The obstacles of `other` are moved along with its tiles
+    /// - The tile settings of `self` are kept
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if the tilegrids do not have the same number of
+    /// columns
+    pub fn concat_vertical(&self, other: &Self) -> Result<Self, EnvironmentError> {
+        let (cols, other_cols) = (self.tiles.grid.ncols(), other.tiles.grid.ncols());
+        if cols != other_cols {
+            return Err(EnvironmentError::DifferentColumnCounts(cols, other_cols));
+        }
+
+        let grid = TileGrid::new(
+            self.tiles
+                .grid
+                .iter()
+                .chain(other.tiles.grid.iter())
+                .cloned()
+                .collect(),
+        );
+        Ok(self.concat_with(grid, other, (self.tiles.grid.nrows(), 0)))
+    }
+
+    /// Combine the obstacles of `self` and `other` in a new environment with
+    /// `grid`, offsetting the tile coordinates of the obstacles of `other` by
+    /// `(row_offset, col_offset)`
+    fn concat_with(
+        &self,
+        grid: TileGrid,
+        other: &Self,
+        (row_offset, col_offset): (usize, usize),
+    ) -> Self {
+        let mut obstacles = self.obstacles.clone();
+        for obstacle in &other.obstacles {
+            let mut obstacle = obstacle.clone();
+            obstacle.tile_coordinates.row += row_offset;
+            obstacle.tile_coordinates.col += col_offset;
+            obstacles.push(obstacle);
+        }
+
+        Self {
+            tiles: Tiles {
+                grid,
+                settings: self.tiles.settings.clone(),
+            },
+            obstacles,
+        }
+    }
+
+    /// Compute the [`EnvironmentStats`] of the environment
+    pub fn stats(&self) -> EnvironmentStats {
+        let (rows, cols) = self.tiles.grid.shape();
+        let wall_tiles = self
+            .tiles
+            .grid
+            .cells()
+            .filter(|&(_, tile)| tile == ' ')
+            .count();
+
+        EnvironmentStats {
+            rows,
+            cols,
+            wall_tiles,
+            free_tiles: rows * cols - wall_tiles,
+            obstacle_count: self.obstacles.len(),
+            total_obstacle_area: self
+                .obstacles
+                .iter()
+                .map(|obstacle| obstacle.shape.area())
+                .sum(),
+        }
+    }
+
+    /// Footprint of every obstacle as a polygon in world coordinates
+    /// - Each footprint is paired with the index of its [`Obstacle`] in
+    ///   [`Environment::obstacles`]
+    /// - Ready to be fed into a collision mesher e.g. `parry2d`, or a renderer
+    pub fn obstacle_footprints(&self) -> Vec<(usize, Vec<Vec2>)> {
+        let grid_shape = self.tiles.grid.shape();
+        self.obstacles
+            .iter()
+            .enumerate()
+            .map(|(index, obstacle)| {
+                (
+                    index,
+                    obstacle.world_points(
+                        grid_shape,
+                        self.tile_size(),
+                        Self::CIRCLE_FOOTPRINT_SEGMENTS,
+                    ),
+                )
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rotate_tile_grid_a_full_revolution() {
+        let grid = TileGrid::new(vec!["┌┬╴", "│└┐", "╵ ┼"]);
+
+        let quarter = grid.rotated_90().expect("grid is rectangular");
+        assert_eq!(quarter.iter().collect::<Vec<_>>(), vec![
+            "╶─┐",
+            " ┌┤",
+            "┼┘╵"
+        ]);
+
+        let full = (0..3).try_fold(quarter, |grid, _| grid.rotated_90());
+        assert_eq!(
+            full.expect("grid is rectangular")
+                .iter()
+                .collect::<Vec<_>>(),
+            grid.iter().collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn rotate_ragged_tile_grid() {
+        let grid = TileGrid::new(vec!["┌┬╴", "│└"]);
+        assert!(matches!(
+            grid.rotated_90(),
+            Err(EnvironmentError::DifferentLengthRows)
+        ));
+    }
+
+    #[test]
+    fn mirror_tile_grid() {
+        let grid = TileGrid::new(vec!["┌┬╴", "│└┐", "╵ ┼"]);
+
+        assert_eq!(grid.mirrored_horizontal().iter().collect::<Vec<_>>(), vec![
+            "╶┬┐",
+            "┌┘│",
+            "┼ ╵"
+        ]);
+        assert_eq!(grid.mirrored_vertical().iter().collect::<Vec<_>>(), vec![
+            "╷ ┼",
+            "│┌┘",
+            "└┴╴"
+        ]);
+    }
+
+    #[test]
+    fn pad_and_crop_tile_grid() {
+        let grid = TileGrid::new(vec!["┌┐", "└┘"]);
+        let padded = grid.padded(1, ' ');
+
+        assert_eq!(padded.shape(), (4, 4));
+        assert!(padded.is_rectangular());
+        assert_eq!(padded.iter().collect::<Vec<_>>(), vec![
+            "    ", " ┌┐ ", " └┘ ", "    "
+        ]);
+
+        let cropped = padded.cropped(1, 1, 1, 1).expect("crop within bounds");
+        assert_eq!(
+            cropped.iter().collect::<Vec<_>>(),
+            grid.iter().collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn crop_everything_is_an_error() {
+        let grid = TileGrid::new(vec!["┌┐", "└┘"]);
+        assert!(matches!(
+            grid.cropped(1, 1, 0, 0),
+            Err(EnvironmentError::EmptyGrid)
+        ));
+        assert!(matches!(
+            grid.cropped(0, 0, 2, 0),
+            Err(EnvironmentError::EmptyGrid)
+        ));
+    }
+
+    #[test]
+    fn obstacle_footprints_of_circle_environment() {
+        let env = Environment::circle();
+        let footprints = env.obstacle_footprints();
+
+        assert_eq!(footprints.len(), env.obstacles.iter().count());
+        for ((index, points), obstacle) in footprints.iter().zip(env.obstacles.iter()) {
+            let expected = match obstacle.shape {
+                PlaceableShape::Triangle(_) => 3,
+                PlaceableShape::RegularPolygon(ref polygon) => polygon.sides,
+                PlaceableShape::Rectangle(_) => 4,
+                PlaceableShape::Polygon(ref polygon) => polygon.points.len(),
+                PlaceableShape::Circle(_) => Environment::CIRCLE_FOOTPRINT_SEGMENTS,
+            };
+            assert_eq!(points.len(), expected, "obstacle {index}");
+            assert!(points.iter().all(|p| p.is_finite()));
+        }
+    }
+
+    #[test]
+    fn circle_footprint_is_tessellated() {
+        let obstacle = Obstacle::new(
+            (0, 0),
+            PlaceableShape::circle(0.1.try_into().expect("positive and finite")),
+            0.0,
+            (0.5, 0.5),
+        );
+        let points = obstacle.world_points((1, 1), 100.0, 16);
+
+        assert_eq!(points.len(), 16);
+        for point in points {
+            assert!((point.length() - 10.0).abs() < 1e-3);
+        }
+    }
+
+    /// Vertices of the collider that `build_obstacles` in the simulation
+    /// spawns for `obstacle`, using the same rotations and isometry angle
+    #[allow(clippy::cast_possible_truncation, clippy::cast_precision_loss)]
+    fn collider_points(
+        obstacle: &Obstacle,
+        (nrows, ncols): (usize, usize),
+        tile_size: f32,
+    ) -> Vec<Vec2> {
+        use std::f32::consts::{FRAC_PI_2, PI, TAU};
+
+        use bevy::math::{Quat, Vec3, Vec3Swizzles};
+
+        let offset_x =
+            (obstacle.tile_coordinates.col as f32 - (ncols as f32 / 2.0 - 0.5)) * tile_size;
+        let offset_z =
+            (obstacle.tile_coordinates.row as f32 - (nrows as f32 / 2.0 - 0.5)) * tile_size;
+        let pos_offset = tile_size / 2.0;
+        let (tx, ty) = (
+            obstacle.translation.x.get() as f32,
+            obstacle.translation.y.get() as f32,
+        );
+        let x = tx.mul_add(tile_size, offset_x) - pos_offset;
+        let z = ty.mul_add(tile_size, offset_z) - pos_offset;
+        let rotation = obstacle.rotation.as_radians() as f32;
+
+        let (center, points, isometry_angle): (Vec2, Vec<Vec2>, f32) = match &obstacle.shape {
+            PlaceableShape::Circle(Circle { radius }) => {
+                let center = Vec2::new(x, (1.0 - ty).mul_add(tile_size, offset_z) - pos_offset);
+                let points = (0..16)
+                    .map(|i| Vec2::from_angle(TAU * i as f32 / 16.0) * radius.get() as f32)
+                    .map(|point| point * tile_size)
+                    .collect();
+                (center, points, 0.0)
+            }
+            PlaceableShape::Triangle(triangle) => {
+                let angle = FRAC_PI_2 - rotation;
+                let quat = Quat::from_rotation_y(angle);
+                let points = triangle
+                    .points()
+                    .map(|point| Vec2::new(-point.x * tile_size, point.y * tile_size))
+                    .map(|point| quat.mul_vec3(point.extend(0.0).xzy()).xz())
+                    .to_vec();
+                (Vec2::new(x, -z), points, angle - FRAC_PI_2)
+            }
+            PlaceableShape::RegularPolygon(polygon) => {
+                let offset = PI
+                    + match polygon.sides {
+                        4 => 0.0,
+                        n if n % 2 != 0 => FRAC_PI_2,
+                        _ => -FRAC_PI_2,
+                    };
+                let quat = Quat::from_rotation_z(rotation + offset);
+                let points = polygon
+                    .points()
+                    .iter()
+                    .map(|[x, y]| quat.mul_vec3(Vec3::new(*x as f32, *y as f32, 0.0)))
+                    .map(|point| point.xy() * tile_size / 2.0)
+                    .collect();
+                (Vec2::new(x, -z), points, rotation + offset)
+            }
+            PlaceableShape::Polygon(polygon) => {
+                let points = polygon
+                    .points
+                    .iter()
+                    .map(|point| Vec2::new(point.x as f32, point.y as f32) * tile_size)
+                    .collect();
+                (Vec2::new(x, z), points, 0.0)
+            }
+            PlaceableShape::Rectangle(Rectangle { width, height }) => {
+                let half_width = width.get() as f32 * tile_size / 4.0;
+                let half_height = height.get() as f32 * tile_size / 4.0;
+                let points = vec![
+                    Vec2::new(-half_width, -half_height),
+                    Vec2::new(half_width, -half_height),
+                    Vec2::new(half_width, half_height),
+                    Vec2::new(-half_width, half_height),
+                ];
+                (Vec2::new(x, -z), points, 0.0)
+            }
+        };
+
+        let isometry = Vec2::from_angle(isometry_angle);
+        points
+            .into_iter()
+            .map(|point| center + isometry.rotate(point))
+            .collect()
+    }
+
+    #[test]
+    fn world_points_match_spawned_colliders() {
+        let grid_shape = (2, 3);
+        let tile_size = 50.0;
+        let shapes = [
+            PlaceableShape::circle(0.1.try_into().expect("positive and finite")),
+            PlaceableShape::triangle(
+                [
+                    Angle::from_degrees(60.0).expect("Invalid angle"),
+                    Angle::from_degrees(50.0).expect("Invalid angle"),
+                ],
+                0.1.try_into().expect("positive and finite"),
+            ),
+            PlaceableShape::regular_polygon(3, 0.2),
+            PlaceableShape::regular_polygon(4, 0.2),
+            PlaceableShape::regular_polygon(6, 0.2),
+            PlaceableShape::Polygon(Polygon::new(vec![
+                Point::new(0.0, 0.0),
+                Point::new(0.2, 0.0),
+                Point::new(0.1, 0.3),
+            ])),
+            PlaceableShape::rectangle(0.2, 0.6),
+        ];
+
+        for shape in shapes {
+            let obstacle = Obstacle::new((1, 2), shape, 0.7, (0.3, 0.8));
+            let points = obstacle.world_points(grid_shape, tile_size, 16);
+            let expected = collider_points(&obstacle, grid_shape, tile_size);
+
+            assert_eq!(points.len(), expected.len(), "{:?}", obstacle.shape);
+            for (point, expected) in points.iter().zip(&expected) {
+                assert!(
+                    point.abs_diff_eq(*expected, 1e-3),
+                    "{:?}: {point} != {expected}",
+                    obstacle.shape
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn render_rotation_offset_of_each_shape() {
+        use std::f32::consts::{FRAC_PI_2, PI};
+        let radius = 1.0;
+
+        let triangle = PlaceableShape::regular_polygon(3, radius);
+        assert!((triangle.render_rotation_offset() - (PI + PI / 3.0)).abs() < 1e-6);
+        let pentagon = PlaceableShape::regular_polygon(5, radius);
+        assert!((pentagon.render_rotation_offset() - (PI + PI / 5.0)).abs() < 1e-6);
+
+        let square = PlaceableShape::regular_polygon(4, radius);
+        assert!((square.render_rotation_offset() - PI).abs() < f32::EPSILON);
+        let hexagon = PlaceableShape::regular_polygon(6, radius);
+        assert!((hexagon.render_rotation_offset() - PI).abs() < f32::EPSILON);
+
+        assert!(
+            (PlaceableShape::circle(radius.try_into().expect("1.0 > 0.0"))
+                .render_rotation_offset()
+                - FRAC_PI_2)
+                .abs()
+                < f32::EPSILON
+        );
+        let rectangle = PlaceableShape::rectangle(radius, radius);
+        assert!(rectangle.render_rotation_offset().abs() < f32::EPSILON);
+        let polygon = PlaceableShape::Polygon(Polygon::new(vec![
+            Point::new(0.0, 0.0),
+            Point::new(1.0, 0.0),
+            Point::new(0.0, 1.0),
+        ]));
+        assert!(polygon.render_rotation_offset().abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn every_environment_type_round_trips_through_yaml() {
+        use strum::IntoEnumIterator;
+
+        for env_type in EnvironmentType::iter() {
+            let yaml = serde_yaml::to_string(&env_type.environment())
+                .expect("environment can be serialized");
+            assert!(
+                Environment::parse(&yaml).is_ok(),
+                "{} does not re-parse",
+                env_type.as_ref()
+            );
+        }
+    }
+
+    #[test]
+    fn point_on_circle_boundary_is_inside() {
+        let radius = 0.0525;
+        let circle =
+            Circle::new(StrictlyPositiveFinite::<Float>::new(radius).expect("radius is positive"));
+        #[allow(clippy::cast_possible_truncation)]
+        let radius = radius as f32;
+
+        assert!(circle.inside(Vec2::new(radius, 0.0)));
+        assert!(circle.inside(Vec2::new(0.0, -radius)));
+        assert!(circle.inside(Vec2::new(radius * 0.9999, 0.0)));
+        assert!(!circle.inside(Vec2::new(radius * 1.0001, 0.0)));
+
+        let diagonal = radius * std::f32::consts::FRAC_1_SQRT_2;
+        assert!(circle.inside(Vec2::splat(diagonal * 0.9999)));
+        assert!(!circle.inside(Vec2::splat(diagonal * 1.0001)));
+    }
+
+    #[test]
+    fn square_as_rectangle_and_regular_polygon_agree() {
+        let side_length = 0.5;
+        let rectangle = PlaceableShape::rectangle(side_length, side_length);
+        // the vertices of a regular polygon are on its circumcircle, and the
+        // square is turned so its sides are axis aligned
+        let square = PlaceableShape::square(side_length / std::f64::consts::SQRT_2);
+
+        // the square spans [-0.125, 0.125] in tile units in both axes
+        let points = [
+            (Vec2::ZERO, true),
+            (Vec2::new(0.1, 0.1), true),
+            (Vec2::new(0.12, -0.05), true),
+            (Vec2::new(-0.11, 0.0), true),
+            (Vec2::new(0.13, 0.0), false),
+            (Vec2::new(0.1, 0.2), false),
+            (Vec2::new(-0.2, -0.2), false),
+        ];
+        for (point, expected) in points {
+            assert_eq!(rectangle.inside(point), expected, "rectangle at {point}");
+            assert_eq!(square.inside(point), expected, "square at {point}");
+        }
+    }
+
+    #[test]
+    fn width_of_rectangle_is_along_the_x_axis() {
+        let rectangle = PlaceableShape::rectangle(0.8, 0.4);
+
+        // the rectangle spans [-0.2, 0.2] along x and [-0.1, 0.1] along y in
+        // tile units
+        let points = [
+            (Vec2::new(0.15, 0.05), true),
+            (Vec2::new(-0.19, 0.0), true),
+            (Vec2::new(0.0, -0.09), true),
+            (Vec2::new(0.05, 0.15), false),
+            (Vec2::new(0.0, 0.11), false),
+            (Vec2::new(0.21, 0.0), false),
+        ];
+        for (point, expected) in points {
+            assert_eq!(rectangle.inside(point), expected, "rectangle at {point}");
+
+            // rotated the same way as when rendering the environment to an image
+            let rotated = Vec2::from_angle(rectangle.render_rotation_offset()).rotate(point);
+            assert_eq!(
+                rectangle.inside(rotated),
+                expected,
+                "rendered rectangle at {point}"
+            );
+        }
+    }
+
+    #[test]
+    fn area_and_centroid_of_placeable_shapes() {
+        let radius = 0.2;
+        let circle = PlaceableShape::circle(
+            StrictlyPositiveFinite::<Float>::new(radius).expect("radius is positive"),
+        );
+        assert!((circle.area() - std::f64::consts::PI * radius * radius).abs() < 1e-12);
+        assert_eq!(circle.centroid(), Vec2::ZERO);
+
+        let (width, height) = (0.6, 0.3);
+        let rectangle = PlaceableShape::rectangle(width, height);
+        let expected = width * HALF_TILE_SCALE * height * HALF_TILE_SCALE;
+        assert!((rectangle.area() - expected).abs() < 1e-12);
+        assert_eq!(rectangle.centroid(), Vec2::ZERO);
+
+        // a square of the same size as a regular polygon and as a rectangle
+        let square = PlaceableShape::square(width / std::f64::consts::SQRT_2);
+        let same_square = PlaceableShape::rectangle(width, width);
+        assert!((square.area() - same_square.area()).abs() < 1e-12);
+
+        let unit_square = PlaceableShape::Polygon(Polygon::new(vec![
+            Point::new(0.0, 0.0),
+            Point::new(0.0, 1.0),
+            Point::new(1.0, 1.0),
+            Point::new(1.0, 0.0),
+        ]));
+        assert!((unit_square.area() - 1.0).abs() < 1e-12);
+        assert!(unit_square.centroid().distance(Vec2::splat(0.5)) < 1e-6);
+    }
+
+    #[test]
+    fn stats_of_maze_environment() {
+        let stats = Environment::maze().stats();
+
+        assert!(stats.wall_tiles > 0);
+        assert!(stats.free_tiles > 0);
+        assert_eq!(stats.wall_tiles + stats.free_tiles, stats.rows * stats.cols);
+        assert_eq!(stats.obstacle_count, 0);
+    }
+
+    #[test]
+    fn random_maze_is_valid_and_perfect() {
+        const GLYPHS: &str = "╵╶╷╴│─┌┐└┘┬┴├┤┼";
+        let (rows, cols) = (7, 11);
+        let env = Environment::random_maze(rows, cols, 42)
+            .validate()
+            .expect("generated maze is valid");
+
+        let grid = &env.tiles.grid;
+        assert_eq!(grid.shape(), (rows + 2, cols + 2));
+
+        let mut path_ends = 0;
+        for (TileCoordinates { row, col }, tile) in grid.cells() {
+            let interior = (1..=rows).contains(&row) && (1..=cols).contains(&col);
+            if interior {
+                assert!(GLYPHS.contains(tile), "unrecognized glyph {tile:?}");
+                path_ends += match tile {
+                    '╵' | '╶' | '╷' | '╴' => 1,
+                    '┬' | '┴' | '├' | '┤' => 3,
+                    '┼' => 4,
+                    _ => 2,
+                };
+            } else {
+                assert_eq!(tile, ' ');
+            }
+        }
+        // a perfect maze is a spanning tree of its tiles
+        assert_eq!(path_ends / 2, rows * cols - 1);
+
+        let same_seed = Environment::random_maze(rows, cols, 42);
+        assert_eq!(
+            grid.iter().collect::<Vec<_>>(),
+            same_seed.tiles.grid.iter().collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn build_obstacles() {
+        let radius = StrictlyPositiveFinite::<Float>::new(0.1).expect("radius is positive");
+        let mut obstacles = Obstacles::empty();
+        assert!(obstacles.is_empty());
+
+        obstacles.push(Obstacle::new(
+            (0, 0),
+            PlaceableShape::circle(radius),
+            0.0,
+            (0.5, 0.5),
+        ));
+        let obstacles = obstacles
+            .with_obstacle(Obstacle::new(
+                (1, 0),
+                PlaceableShape::rectangle(0.2, 0.4),
+                0.0,
+                (0.25, 0.75),
+            ))
+            .with_obstacle(Obstacle::new(
+                (0, 1),
+                PlaceableShape::square(0.3),
+                0.0,
+                (0.5, 0.5),
+            ));
+
+        assert_eq!(obstacles.len(), 3);
+        assert!(!obstacles.is_empty());
+    }
+
+    #[test]
+    fn concat_intersection_environments() {
+        let radius = StrictlyPositiveFinite::<Float>::new(0.1).expect("radius is positive");
+        let mut right = Environment::intersection();
+        right.obstacles.push(Obstacle::new(
+            (0, 0),
+            PlaceableShape::circle(radius),
+            0.0,
+            (0.5, 0.5),
+        ));
+
+        let obstacle_coordinates = |env: &Environment| {
+            env.obstacles
+                .iter()
+                .map(|obstacle| {
+                    let TileCoordinates { row, col } = obstacle.tile_coordinates;
+                    (row, col)
+                })
+                .collect::<Vec<_>>()
+        };
+
+        let horizontal = Environment::intersection()
+            .concat_horizontal(&right)
+            .expect("same number of rows");
+        assert_eq!(horizontal.tiles.grid.shape(), (1, 2));
+        assert_eq!(obstacle_coordinates(&horizontal), vec![(0, 1)]);
+
+        let vertical = horizontal
+            .concat_vertical(&horizontal)
+            .expect("same number of columns");
+        assert_eq!(vertical.tiles.grid.shape(), (2, 2));
+        assert_eq!(obstacle_coordinates(&vertical), vec![(0, 1), (1, 1)]);
+
+        assert!(matches!(
+            vertical.concat_horizontal(&horizontal),
+            Err(EnvironmentError::DifferentRowCounts(2, 1))
+        ));
+        assert!(matches!(
+            horizontal.concat_vertical(&Environment::intersection()),
+            Err(EnvironmentError::DifferentColumnCounts(2, 1))
+        ));
+    }
+
+    #[test]
+    fn sdf_settings_out_of_range() {
+        assert!(SdfSettings::default().validate().is_ok());
+
+        let with_sdf = |sdf: SdfSettings| {
+            let mut env = Environment::intersection();
+            env.tiles.settings.sdf = sdf;
+            env.validate()
+        };
+
+        assert!(matches!(
+            with_sdf(SdfSettings {
+                resolution: 0,
+                ..Default::default()
+            }),
+            Err(EnvironmentError::SdfResolutionZero)
+        ));
+        assert!(matches!(
+            with_sdf(SdfSettings {
+                expansion: -0.1,
+                ..Default::default()
+            }),
+            Err(EnvironmentError::SdfExpansionOutOfRange(_))
+        ));
+        assert!(matches!(
+            with_sdf(SdfSettings {
+                expansion: 1.5,
+                ..Default::default()
+            }),
+            Err(EnvironmentError::SdfExpansionOutOfRange(_))
+        ));
+        assert!(matches!(
+            with_sdf(SdfSettings {
+                blur: 2.0,
+                ..Default::default()
+            }),
+            Err(EnvironmentError::SdfBlurOutOfRange(_))
+        ));
+        assert!(matches!(
+            with_sdf(SdfSettings {
+                blur: f32::NAN,
+                ..Default::default()
+            }),
+            Err(EnvironmentError::SdfBlurOutOfRange(_))
+        ));
+    }
+
+    #[test]
+    fn polygon_area_and_centroid() {
+        let square = Polygon::new(vec![
+            Point::new(0.0, 0.0),
+            Point::new(0.0, 1.0),
+            Point::new(1.0, 1.0),
+            Point::new(1.0, 0.0),
+        ]);
+        assert!((square.signed_area() + 1.0).abs() < f64::EPSILON);
+        assert!(square.is_clockwise());
+        let centroid = square.centroid();
+        assert!((centroid.x - 0.5).abs() < f64::EPSILON && (centroid.y - 0.5).abs() < f64::EPSILON);
+
+        // the centroid of the area is not pulled towards clustered points like
+        // the average of the points is
+        let triangle = Polygon::new(vec![
+            Point::new(0.0, 0.0),
+            Point::new(0.1, 0.0),
+            Point::new(0.2, 0.0),
+            Point::new(0.3, 0.0),
+            Point::new(0.6, 0.0),
+            Point::new(0.0, 0.6),
+        ]);
+        assert!((triangle.signed_area() - 0.18).abs() < 1e-12);
+        let centroid = triangle.centroid();
+        assert!((centroid.x - 0.2).abs() < 1e-12 && (centroid.y - 0.2).abs() < 1e-12);
+    }
 }
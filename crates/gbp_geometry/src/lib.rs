@@ -20,6 +20,12 @@ impl Point {
     }
 }
 
+impl From<(f64, f64)> for Point {
+    fn from((x, y): (f64, f64)) -> Self {
+        Self::new(x, y)
+    }
+}
+
 impl From<Point> for bevy::math::Vec2 {
     #[allow(clippy::cast_possible_truncation)]
     fn from(value: Point) -> Self {
@@ -29,36 +35,12 @@ impl From<Point> for bevy::math::Vec2 {
 
 /// A relative point within the boundaries of the map.
 /// ...
-// #[derive(Debug, Serialize, Deserialize, Clone, Copy, derive_more::Sub, derive_more::Add)]
 #[derive(Debug, Serialize, Deserialize, Clone, Copy)]
 pub struct RelativePoint {
     pub x: UnitInterval,
     pub y: UnitInterval,
 }
 
-// impl sub and add for `RelativePoint`
-impl std::ops::Add<RelativePoint> for RelativePoint {
-    type Output = Self;
-
-    fn add(self, rhs: Self) -> Self::Output {
-        Self {
-            x: UnitInterval::new(self.x.get() + rhs.x.get()).unwrap(),
-            y: UnitInterval::new(self.y.get() + rhs.y.get()).unwrap(),
-        }
-    }
-}
-
-impl std::ops::Sub<RelativePoint> for RelativePoint {
-    type Output = Self;
-
-    fn sub(self, rhs: Self) -> Self::Output {
-        Self {
-            x: UnitInterval::new(self.x.get() - rhs.x.get()).unwrap(),
-            y: UnitInterval::new(self.y.get() - rhs.y.get()).unwrap(),
-        }
-    }
-}
-
 impl RelativePoint {
     /// Create a new `RelativePoint` from a pair of values.
     /// Returns an error if either `x` or `y` is not in the interval [0.0, 1.0].
@@ -105,6 +87,70 @@ impl RelativePoint {
         self.x.get().powi(2) + self.y.get().powi(2)
     }
 
+    /// Add `other` component-wise
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if the sum leaves [0.0, 1.0] in either component
+    pub fn checked_add(&self, other: &Self) -> Result<Self, unit_interval::UnitIntervalError> {
+        Self::new(self.x.get() + other.x.get(), self.y.get() + other.y.get())
+    }
+
+    /// Subtract `other` component-wise
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if the difference leaves [0.0, 1.0] in either
+    /// component
+    pub fn checked_sub(&self, other: &Self) -> Result<Self, unit_interval::UnitIntervalError> {
+        Self::new(self.x.get() - other.x.get(), self.y.get() - other.y.get())
+    }
+
+    /// Add `other` component-wise, clamping the sum to [0.0, 1.0]
+    #[must_use]
+    pub fn saturating_add(&self, other: &Self) -> Self {
+        Self::clamped(self.x.get() + other.x.get(), self.y.get() + other.y.get())
+    }
+
+    /// Subtract `other` component-wise, clamping the difference to [0.0, 1.0]
+    #[must_use]
+    pub fn saturating_sub(&self, other: &Self) -> Self {
+        Self::clamped(self.x.get() - other.x.get(), self.y.get() - other.y.get())
+    }
+
+    /// Create a new `RelativePoint` with `x` and `y` clamped to [0.0, 1.0]
+    fn clamped(x: f64, y: f64) -> Self {
+        Self::new(x.clamp(0.0, 1.0), y.clamp(0.0, 1.0)).expect("clamped to [0.0, 1.0]")
+    }
+
+    /// Calculate the squared euclidean distance to `other`
+    pub fn squared_distance(&self, other: &Self) -> f64 {
+        let dx = self.x.get() - other.x.get();
+        let dy = self.y.get() - other.y.get();
+        dx.mul_add(dx, dy * dy)
+    }
+
+    /// Calculate the euclidean distance to `other`
+    pub fn distance(&self, other: &Self) -> f64 {
+        self.squared_distance(other).sqrt()
+    }
+
+    /// Linearly interpolate between `self` and `other`
+    /// `t` = 0.0 returns `self`, and `t` = 1.0 returns `other`
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if `t` is not in [0.0, 1.0]
+    pub fn lerp(&self, other: &Self, t: f64) -> Result<Self, unit_interval::UnitIntervalError> {
+        let t = UnitInterval::new(t)?.get();
+        // guard against rounding errors pushing the result out of [0.0, 1.0]
+        let lerp = |a: f64, b: f64| (b - a).mul_add(t, a).clamp(0.0, 1.0);
+        Self::new(
+            lerp(self.x.get(), other.x.get()),
+            lerp(self.y.get(), other.y.get()),
+        )
+    }
+
     // /// Returns the x and y values as a tuple
     // #[inline]
     // pub const fn get(&self) -> (f64, f64) {
@@ -142,7 +188,70 @@ pub enum Shape {
     LineSegment((Point, Point)),
 }
 
+/// An error that can occur when constructing a [`Shape`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GeometryError {
+    /// A polygon needs at least 3 points, got the contained amount
+    TooFewPoints(usize),
+    /// Two non-adjacent edges of a polygon intersect, given by the index of
+    /// the point each of them starts at
+    SelfIntersecting {
+        /// Index of the first point of the first edge
+        first:  usize,
+        /// Index of the first point of the second edge
+        second: usize,
+    },
+}
+
+impl std::fmt::Display for GeometryError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::TooFewPoints(n) => {
+                write!(f, "a polygon needs at least 3 points, got {n}")
+            }
+            Self::SelfIntersecting { first, second } => write!(
+                f,
+                "polygon is self-intersecting, the edges starting at point {first} and point \
+                 {second} intersect"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for GeometryError {}
+
 impl Shape {
+    /// Create a new `Shape::Polygon` from `points`, checking that they form a
+    /// simple polygon
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if:
+    /// 1. There are fewer than 3 points
+    /// 2. Any two non-adjacent edges of the polygon intersect
+    #[allow(clippy::missing_panics_doc)] // length checked beforehand
+    pub fn try_polygon(points: Vec<Point>) -> Result<Self, GeometryError> {
+        let n = points.len();
+        if n < 3 {
+            return Err(GeometryError::TooFewPoints(n));
+        }
+
+        let edges: Vec<_> = edges(&points).collect();
+        for first in 0..n {
+            // adjacent edges share a point, so they always intersect
+            for second in (first + 2)..n {
+                let adjacent = first == 0 && second == n - 1;
+                if !adjacent && segment_intersects_segment(edges[first], edges[second]) {
+                    return Err(GeometryError::SelfIntersecting { first, second });
+                }
+            }
+        }
+
+        Ok(Self::Polygon(
+            OneOrMore::new(points).expect("at least 3 points"),
+        ))
+    }
+
     pub const fn as_polygon(&self) -> Option<&OneOrMore<Point>> {
         if let Self::Polygon(v) = self {
             Some(v)
@@ -150,6 +259,172 @@ impl Shape {
             None
         }
     }
+
+    /// Calculate the euclidean distance from `p` to the shape
+    /// - Circles and polygons are filled, so the distance is 0.0 if `p` is
+    ///   inside them
+    pub fn distance_to_point(&self, p: Point) -> f64 {
+        match self {
+            Self::Circle { radius, center } => {
+                (p.distance(center) - f64::from(radius.get())).max(0.0)
+            }
+            Self::Polygon(vertices) => {
+                if vertices.len() > 2 && point_in_polygon(p, vertices) {
+                    return 0.0;
+                }
+                edges(vertices)
+                    .map(|edge| point_to_segment_distance(p, edge))
+                    .fold(f64::INFINITY, f64::min)
+            }
+            Self::LineSegment(segment) => point_to_segment_distance(p, *segment),
+        }
+    }
+
+    /// Calculate the signed area of the shape
+    /// - Positive if the vertices of a polygon are in counter-clockwise order,
+    ///   and negative if they are in clockwise order
+    /// - Circles always have a positive area, and line segments have no area
+    pub fn signed_area(&self) -> f64 {
+        match self {
+            Self::Circle { radius, .. } => std::f64::consts::PI * f64::from(radius.get()).powi(2),
+            Self::Polygon(vertices) => polygon_signed_area(vertices),
+            Self::LineSegment(_) => 0.0,
+        }
+    }
+
+    /// Returns `true` if the shape is a polygon with its vertices in clockwise
+    /// order
+    pub fn is_clockwise(&self) -> bool {
+        self.signed_area() < 0.0
+    }
+
+    /// Calculate the centroid of the shape
+    /// See [`polygon_centroid`] for polygons
+    pub fn centroid(&self) -> Point {
+        match self {
+            Self::Circle { center, .. } => *center,
+            Self::Polygon(vertices) => polygon_centroid(vertices),
+            Self::LineSegment((a, b)) => Point::new((a.x + b.x) / 2.0, (a.y + b.y) / 2.0),
+        }
+    }
+}
+
+/// Calculate the signed area of the polygon with the given `vertices`, using
+/// the shoelace formula
+/// Positive if the vertices are in counter-clockwise order, and negative if
+/// they are in clockwise order
+pub fn polygon_signed_area(vertices: &[Point]) -> f64 {
+    edges(vertices)
+        .map(|(p, q)| p.x.mul_add(q.y, -(q.x * p.y)))
+        .sum::<f64>()
+        / 2.0
+}
+
+/// Calculate the centroid of the area enclosed by the polygon with the given
+/// `vertices`
+/// Falls back to the average of the vertices if the polygon has no area, e.g.
+/// if all vertices are collinear
+///
+/// # Panics
+///
+/// If `vertices` is empty
+pub fn polygon_centroid(vertices: &[Point]) -> Point {
+    assert!(!vertices.is_empty(), "a polygon has at least one vertex");
+    let area = polygon_signed_area(vertices);
+    if area == 0.0 {
+        #[allow(clippy::cast_precision_loss)]
+        let n = vertices.len() as f64;
+        let (x, y) = vertices
+            .iter()
+            .fold((0.0, 0.0), |(x, y), p| (x + p.x, y + p.y));
+        return Point::new(x / n, y / n);
+    }
+
+    let (x, y) = edges(vertices).fold((0.0, 0.0), |(x, y), (p, q)| {
+        let cross = p.x.mul_add(q.y, -(q.x * p.y));
+        ((p.x + q.x).mul_add(cross, x), (p.y + q.y).mul_add(cross, y))
+    });
+    Point::new(x / (6.0 * area), y / (6.0 * area))
+}
+
+/// Iterate over the edges of the polygon with the given `vertices`, including
+/// the edge from the last to the first vertex
+fn edges(vertices: &[Point]) -> impl Iterator<Item = (Point, Point)> + '_ {
+    vertices
+        .iter()
+        .copied()
+        .zip(vertices.iter().copied().cycle().skip(1))
+}
+
+impl Point {
+    /// Calculate the euclidean distance to `other`
+    #[inline]
+    pub fn distance(&self, other: &Self) -> f64 {
+        (self.x - other.x).hypot(self.y - other.y)
+    }
+}
+
+/// 2D cross product of the vectors `o -> a` and `o -> b`
+/// Positive if `o`, `a`, `b` are in counter-clockwise order, negative if
+/// clockwise and 0.0 if they are collinear
+fn cross(o: Point, a: Point, b: Point) -> f64 {
+    (a.x - o.x).mul_add(b.y - o.y, -((a.y - o.y) * (b.x - o.x)))
+}
+
+/// Calculate the euclidean distance from `p` to the line segment from `a` to
+/// `b`
+pub fn point_to_segment_distance(p: Point, (a, b): (Point, Point)) -> f64 {
+    let (dx, dy) = (b.x - a.x, b.y - a.y);
+    let length_squared = dx.mul_add(dx, dy * dy);
+    if length_squared == 0.0 {
+        return p.distance(&a);
+    }
+    let t = ((p.x - a.x).mul_add(dx, (p.y - a.y) * dy) / length_squared).clamp(0.0, 1.0);
+    p.distance(&Point::new(t.mul_add(dx, a.x), t.mul_add(dy, a.y)))
+}
+
+/// Check if `p` is inside the polygon with the given `vertices`, by counting
+/// how many edges a ray cast from `p` in the positive x direction crosses
+fn point_in_polygon(p: Point, vertices: &[Point]) -> bool {
+    let mut inside = false;
+    let mut j = vertices.len() - 1;
+    for (i, vi) in vertices.iter().enumerate() {
+        let vj = vertices[j];
+        if (vi.y > p.y) != (vj.y > p.y)
+            && p.x < (vj.x - vi.x).mul_add((p.y - vi.y) / (vj.y - vi.y), vi.x)
+        {
+            inside = !inside;
+        }
+        j = i;
+    }
+    inside
+}
+
+/// Check if the line segments `a` and `b` intersect, including if they only
+/// touch or overlap. Parallel segments that are not collinear never intersect.
+pub fn segment_intersects_segment(a: (Point, Point), b: (Point, Point)) -> bool {
+    /// Check if `q` lies within the bounding box of the segment from `p` to `r`
+    fn within_bounds(p: Point, q: Point, r: Point) -> bool {
+        q.x >= p.x.min(r.x) && q.x <= p.x.max(r.x) && q.y >= p.y.min(r.y) && q.y <= p.y.max(r.y)
+    }
+
+    let (p1, q1) = a;
+    let (p2, q2) = b;
+    let d1 = cross(p2, q2, p1);
+    let d2 = cross(p2, q2, q1);
+    let d3 = cross(p1, q1, p2);
+    let d4 = cross(p1, q1, q2);
+
+    if ((d1 > 0.0 && d2 < 0.0) || (d1 < 0.0 && d2 > 0.0))
+        && ((d3 > 0.0 && d4 < 0.0) || (d3 < 0.0 && d4 > 0.0))
+    {
+        return true;
+    }
+
+    (d1 == 0.0 && within_bounds(p2, p1, q2))
+        || (d2 == 0.0 && within_bounds(p2, q1, q2))
+        || (d3 == 0.0 && within_bounds(p1, p2, q1))
+        || (d4 == 0.0 && within_bounds(p1, q2, q1))
 }
 
 /// Shorthand to construct `Shape::Polygon(vec![Point {x: $x, y: $y}, ... ])`
@@ -176,3 +451,172 @@ macro_rules! line {
         $crate::config::geometry::Shape::LineSegment(($crate::config::geometry::Point::new($x1, $y1), $crate::config::geometry::Point::new($x2, $y2)))
     };
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn distance_between_corners() {
+        let (min, max) = (RelativePoint::min(), RelativePoint::max());
+        assert!((min.squared_distance(&max) - 2.0).abs() < f64::EPSILON);
+        assert!((min.distance(&max) - std::f64::consts::SQRT_2).abs() < f64::EPSILON);
+        assert!(max.distance(&max).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn lerp_midpoint() {
+        let (min, max) = (RelativePoint::min(), RelativePoint::max());
+        let midpoint = min.lerp(&max, 0.5).expect("0.5 in [0.0, 1.0]");
+        assert!(midpoint.distance(&RelativePoint::center()) < f64::EPSILON);
+
+        let start = min.lerp(&max, 0.0).expect("0.0 in [0.0, 1.0]");
+        assert!(start.distance(&min) < f64::EPSILON);
+        let end = min.lerp(&max, 1.0).expect("1.0 in [0.0, 1.0]");
+        assert!(end.distance(&max) < f64::EPSILON);
+    }
+
+    #[test]
+    fn lerp_outside_unit_interval_is_an_error() {
+        let (min, max) = (RelativePoint::min(), RelativePoint::max());
+        assert!(min.lerp(&max, 1.5).is_err());
+        assert!(min.lerp(&max, -0.5).is_err());
+    }
+
+    #[test]
+    fn distance_to_horizontal_segment() {
+        let segment = Shape::LineSegment((Point::new(0.0, 0.0), Point::new(2.0, 0.0)));
+        // above the segment
+        assert!((segment.distance_to_point(Point::new(1.0, 3.0)) - 3.0).abs() < f64::EPSILON);
+        // beyond the end of the segment
+        assert!((segment.distance_to_point(Point::new(5.0, 4.0)) - 5.0).abs() < f64::EPSILON);
+        // on the segment
+        assert!(segment.distance_to_point(Point::new(0.5, 0.0)).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn distance_to_circle_and_polygon() {
+        let circle = Shape::Circle {
+            radius: 1.0.try_into().expect("1.0 > 0.0"),
+            center: Point::new(0.0, 0.0),
+        };
+        assert!((circle.distance_to_point(Point::new(3.0, 0.0)) - 2.0).abs() < f64::EPSILON);
+        assert!(circle.distance_to_point(Point::new(0.5, 0.0)).abs() < f64::EPSILON);
+
+        let square = Shape::Polygon(
+            OneOrMore::new(vec![
+                Point::new(0.0, 0.0),
+                Point::new(1.0, 0.0),
+                Point::new(1.0, 1.0),
+                Point::new(0.0, 1.0),
+            ])
+            .expect("at least one vertex"),
+        );
+        assert!(square.distance_to_point(Point::new(0.5, 0.5)).abs() < f64::EPSILON);
+        assert!((square.distance_to_point(Point::new(0.5, 3.0)) - 2.0).abs() < f64::EPSILON);
+        // closing edge from the last to the first vertex
+        assert!((square.distance_to_point(Point::new(-1.0, 0.5)) - 1.0).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn crossing_segments_intersect() {
+        let a = (Point::new(0.0, 0.0), Point::new(1.0, 1.0));
+        let b = (Point::new(0.0, 1.0), Point::new(1.0, 0.0));
+        assert!(segment_intersects_segment(a, b));
+        assert!(segment_intersects_segment(b, a));
+    }
+
+    #[test]
+    fn parallel_segments_do_not_intersect() {
+        let a = (Point::new(0.0, 0.0), Point::new(1.0, 0.0));
+        let b = (Point::new(0.0, 1.0), Point::new(1.0, 1.0));
+        assert!(!segment_intersects_segment(a, b));
+
+        // collinear but overlapping segments do intersect
+        let c = (Point::new(0.5, 0.0), Point::new(2.0, 0.0));
+        assert!(segment_intersects_segment(a, c));
+        let d = (Point::new(1.5, 0.0), Point::new(2.0, 0.0));
+        assert!(!segment_intersects_segment(a, d));
+    }
+
+    #[test]
+    fn unit_square_area_and_centroid() {
+        let vertices = [
+            Point::new(0.0, 0.0),
+            Point::new(1.0, 0.0),
+            Point::new(1.0, 1.0),
+            Point::new(0.0, 1.0),
+        ];
+        let square =
+            Shape::Polygon(OneOrMore::new(vertices.to_vec()).expect("at least one vertex"));
+        assert!((square.signed_area() - 1.0).abs() < f64::EPSILON);
+        assert!(!square.is_clockwise());
+        let centroid = square.centroid();
+        assert!(centroid.distance(&Point::new(0.5, 0.5)) < f64::EPSILON);
+
+        let mut reversed = vertices;
+        reversed.reverse();
+        assert!((polygon_signed_area(&reversed) + 1.0).abs() < f64::EPSILON);
+        let clockwise =
+            Shape::Polygon(OneOrMore::new(reversed.to_vec()).expect("at least one vertex"));
+        assert!(clockwise.is_clockwise());
+    }
+
+    #[test]
+    fn triangle_area_and_centroid() {
+        let triangle = [
+            Point::new(0.0, 0.0),
+            Point::new(4.0, 0.0),
+            Point::new(0.0, 3.0),
+        ];
+        assert!((polygon_signed_area(&triangle) - 6.0).abs() < f64::EPSILON);
+        let centroid = polygon_centroid(&triangle);
+        assert!(centroid.distance(&Point::new(4.0 / 3.0, 1.0)) < 1e-12);
+    }
+
+    #[test]
+    fn saturating_and_checked_arithmetic() {
+        let a = RelativePoint::new(0.7, 0.7).expect("in [0.0, 1.0]");
+        let b = RelativePoint::new(0.5, 0.5).expect("in [0.0, 1.0]");
+
+        assert!(a.saturating_add(&b).distance(&RelativePoint::max()) < f64::EPSILON);
+        assert!(a.checked_add(&b).is_err());
+
+        assert!(b.saturating_sub(&a).distance(&RelativePoint::min()) < f64::EPSILON);
+        assert!(b.checked_sub(&a).is_err());
+
+        let difference = a.checked_sub(&b).expect("0.2 in [0.0, 1.0]");
+        assert!(difference.distance(&RelativePoint::new(0.2, 0.2).expect("in [0.0, 1.0]")) < 1e-12);
+    }
+
+    #[test]
+    fn try_polygon_accepts_square() {
+        let square = Shape::try_polygon(vec![
+            (0.0, 0.0).into(),
+            (1.0, 0.0).into(),
+            (1.0, 1.0).into(),
+            (0.0, 1.0).into(),
+        ]);
+        assert!(matches!(square, Ok(Shape::Polygon(ref points)) if points.len() == 4));
+    }
+
+    #[test]
+    fn try_polygon_rejects_bowtie_and_too_few_points() {
+        let bowtie = Shape::try_polygon(vec![
+            (0.0, 0.0).into(),
+            (1.0, 1.0).into(),
+            (1.0, 0.0).into(),
+            (0.0, 1.0).into(),
+        ]);
+        assert!(matches!(
+            bowtie,
+            Err(GeometryError::SelfIntersecting {
+                first:  0,
+                second: 2,
+            })
+        ));
+
+        let line = Shape::try_polygon(vec![(0.0, 0.0).into(), (1.0, 1.0).into()]);
+        assert!(matches!(line, Err(GeometryError::TooFewPoints(2))));
+    }
+}
@@ -0,0 +1,223 @@
+use std::{
+    collections::{HashMap, VecDeque},
+    num::NonZeroUsize,
+};
+
+use bevy::{
+    ecs::system::Resource,
+    math::{IVec2, Vec2},
+};
+
+use crate::Path;
+
+/// Key of a cached path query
+/// The start and goal positions snapped to a grid of cells of size
+/// [`PathCache::quantization`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+struct QueryKey {
+    start: IVec2,
+    goal:  IVec2,
+}
+
+/// **Bevy** [`Resource`] for caching planned paths between start and goal
+/// positions
+/// - Queries are keyed by their start and goal positions snapped to a grid, so
+///   nearby queries share the same cached path
+/// - When more than `capacity` paths are cached, the least recently used one is
+///   evicted
+#[derive(Debug, Resource)]
+pub struct PathCache {
+    quantization: f32,
+    capacity:     NonZeroUsize,
+    entries:      HashMap<QueryKey, Path>,
+    /// Keys ordered from least to most recently used
+    recency:      VecDeque<QueryKey>,
+}
+
+impl Default for PathCache {
+    fn default() -> Self {
+        Self::new(
+            NonZeroUsize::new(64).expect("64 > 0"),
+            Self::DEFAULT_QUANTIZATION,
+        )
+    }
+}
+
+impl PathCache {
+    /// Default side length of the grid cells used to quantize queries
+    pub const DEFAULT_QUANTIZATION: f32 = 1.0;
+
+    /// Create a new empty `PathCache`
+    /// - `capacity`: Maximum number of paths to cache
+    /// - `quantization`: Side length of the grid cells start and goal positions
+    ///   are snapped to
+    ///
+    /// # Panics
+    ///
+    /// If `quantization` is not strictly positive and finite
+    #[must_use]
+    pub fn new(capacity: NonZeroUsize, quantization: f32) -> Self {
+        assert!(
+            quantization.is_finite() && quantization > 0.0,
+            "quantization must be strictly positive and finite, got {quantization}"
+        );
+        Self {
+            quantization,
+            capacity,
+            entries: HashMap::with_capacity(capacity.get()),
+            recency: VecDeque::with_capacity(capacity.get()),
+        }
+    }
+
+    /// Side length of the grid cells start and goal positions are snapped to
+    #[inline]
+    pub const fn quantization(&self) -> f32 {
+        self.quantization
+    }
+
+    /// Maximum number of paths to cache
+    #[inline]
+    pub const fn capacity(&self) -> NonZeroUsize {
+        self.capacity
+    }
+
+    /// Number of cached paths
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Whether the cache is empty
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Remove all cached paths
+    pub fn clear(&mut self) {
+        self.entries.clear();
+        self.recency.clear();
+    }
+
+    #[allow(clippy::cast_possible_truncation)]
+    fn key(&self, start: Vec2, goal: Vec2) -> QueryKey {
+        QueryKey {
+            start: (start / self.quantization).round().as_ivec2(),
+            goal:  (goal / self.quantization).round().as_ivec2(),
+        }
+    }
+
+    /// Mark `key` as the most recently used
+    fn touch(&mut self, key: QueryKey) {
+        if let Some(index) = self.recency.iter().position(|k| *k == key) {
+            self.recency.remove(index);
+        }
+        self.recency.push_back(key);
+    }
+
+    /// Get the cached path between `start` and `goal`, if any
+    pub fn get(&mut self, start: Vec2, goal: Vec2) -> Option<Path> {
+        let key = self.key(start, goal);
+        let path = self.entries.get(&key).cloned()?;
+        self.touch(key);
+        Some(path)
+    }
+
+    /// Cache `path` as the path between `start` and `goal`
+    /// Evicts the least recently used path if the cache is full
+    pub fn insert(&mut self, start: Vec2, goal: Vec2, path: Path) {
+        let key = self.key(start, goal);
+        self.entries.insert(key, path);
+        self.touch(key);
+
+        while self.entries.len() > self.capacity.get() {
+            let Some(evicted) = self.recency.pop_front() else {
+                break;
+            };
+            self.entries.remove(&evicted);
+        }
+    }
+
+    /// Get the cached path between `start` and `goal`, or compute it with
+    /// `plan` and cache it if it is not cached
+    /// - Nothing is cached if `plan` returns `None`
+    pub fn get_or_plan(
+        &mut self,
+        start: Vec2,
+        goal: Vec2,
+        plan: impl FnOnce() -> Option<Path>,
+    ) -> Option<Path> {
+        if let Some(path) = self.get(start, goal) {
+            return Some(path);
+        }
+
+        let path = plan()?;
+        self.insert(start, goal, path.clone());
+        Some(path)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn straight_path(start: Vec2, goal: Vec2) -> Path {
+        Path(vec![start, goal])
+    }
+
+    #[test]
+    fn identical_query_hits_cache() {
+        let mut cache = PathCache::default();
+        let (start, goal) = (Vec2::new(0.0, 0.0), Vec2::new(10.0, 5.0));
+
+        let mut planned = 0;
+        for _ in 0..3 {
+            let path = cache.get_or_plan(start, goal, || {
+                planned += 1;
+                Some(straight_path(start, goal))
+            });
+            assert_eq!(path.map(|p| p.len()), Some(2));
+        }
+
+        assert_eq!(planned, 1);
+        assert_eq!(cache.len(), 1);
+    }
+
+    #[test]
+    fn nearby_query_hits_cache_and_distant_query_misses() {
+        let mut cache = PathCache::new(NonZeroUsize::new(8).expect("8 > 0"), 1.0);
+        let (start, goal) = (Vec2::new(0.0, 0.0), Vec2::new(10.0, 5.0));
+        cache.insert(start, goal, straight_path(start, goal));
+
+        assert!(cache
+            .get(start + Vec2::splat(0.2), goal - Vec2::splat(0.2))
+            .is_some());
+        assert!(cache.get(start, goal + Vec2::new(3.0, 0.0)).is_none());
+
+        let mut planned = false;
+        cache.get_or_plan(start, Vec2::new(-10.0, 5.0), || {
+            planned = true;
+            None
+        });
+        assert!(planned);
+        assert_eq!(cache.len(), 1, "failed plans are not cached");
+    }
+
+    #[test]
+    fn least_recently_used_is_evicted() {
+        let mut cache = PathCache::new(NonZeroUsize::new(2).expect("2 > 0"), 1.0);
+        let origin = Vec2::ZERO;
+        let (a, b, c) = (Vec2::X * 10.0, Vec2::Y * 10.0, Vec2::NEG_X * 10.0);
+
+        cache.insert(origin, a, straight_path(origin, a));
+        cache.insert(origin, b, straight_path(origin, b));
+        // use `a`, so `b` becomes the least recently used
+        assert!(cache.get(origin, a).is_some());
+        cache.insert(origin, c, straight_path(origin, c));
+
+        assert_eq!(cache.len(), 2);
+        assert!(cache.get(origin, a).is_some());
+        assert!(cache.get(origin, b).is_none());
+        assert!(cache.get(origin, c).is_some());
+    }
+}
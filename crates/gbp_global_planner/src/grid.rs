@@ -0,0 +1,86 @@
+use std::collections::HashMap;
+
+use bevy::math::{IVec2, Vec2};
+use parry2d::bounding_volume::Aabb;
+
+/// Uniform grid spatial index over the axis-aligned bounding boxes of a list
+/// of colliders
+/// Each cell stores the indices of the colliders whose bounding box overlaps
+/// it
+#[derive(Debug, Clone, Default)]
+pub(crate) struct ColliderGrid {
+    cells:     HashMap<IVec2, Vec<usize>>,
+    /// Colliders with a bounding box too large to store in the grid, which are
+    /// always returned as candidates
+    unbounded: Vec<usize>,
+}
+
+impl ColliderGrid {
+    /// Side length of a grid cell
+    pub(crate) const CELL_SIZE: f32 = 10.0;
+    /// Colliders overlapping more cells than this are stored in
+    /// `unbounded` instead
+    const MAX_CELLS_PER_COLLIDER: i64 = 4096;
+
+    /// Index of the cell containing `point`
+    #[allow(clippy::cast_possible_truncation)]
+    fn cell(point: Vec2) -> IVec2 {
+        (point / Self::CELL_SIZE).floor().as_ivec2()
+    }
+
+    /// Minimum and maximum cell overlapped by the box from `mins` to `maxs`,
+    /// or `None` if it spans too many cells
+    fn cell_range(mins: Vec2, maxs: Vec2) -> Option<(IVec2, IVec2)> {
+        if !mins.is_finite() || !maxs.is_finite() {
+            return None;
+        }
+        let (min, max) = (Self::cell(mins), Self::cell(maxs));
+        let size = max.as_i64vec2() - min.as_i64vec2() + 1;
+        (size.x * size.y <= Self::MAX_CELLS_PER_COLLIDER).then_some((min, max))
+    }
+
+    /// Add the collider at `index` with bounding box `aabb`
+    pub(crate) fn insert(&mut self, index: usize, aabb: &Aabb) {
+        let mins = Vec2::new(aabb.mins.x, aabb.mins.y);
+        let maxs = Vec2::new(aabb.maxs.x, aabb.maxs.y);
+        let Some((min, max)) = Self::cell_range(mins, maxs) else {
+            self.unbounded.push(index);
+            return;
+        };
+
+        for x in min.x..=max.x {
+            for y in min.y..=max.y {
+                self.cells.entry(IVec2::new(x, y)).or_default().push(index);
+            }
+        }
+    }
+
+    /// Remove all colliders
+    pub(crate) fn clear(&mut self) {
+        self.cells.clear();
+        self.unbounded.clear();
+    }
+
+    /// Sorted indices of all colliders whose bounding box may overlap the
+    /// square of half side length `radius` around `point`
+    pub(crate) fn query(&self, point: Vec2, radius: f32) -> Vec<usize> {
+        let mut indices = self.unbounded.clone();
+        match Self::cell_range(point - radius, point + radius) {
+            Some((min, max)) => {
+                for x in min.x..=max.x {
+                    for y in min.y..=max.y {
+                        if let Some(cell) = self.cells.get(&IVec2::new(x, y)) {
+                            indices.extend_from_slice(cell);
+                        }
+                    }
+                }
+            }
+            // the query covers too many cells to visit, so every stored
+            // collider is a candidate
+            None => indices.extend(self.cells.values().flatten()),
+        }
+        indices.sort_unstable();
+        indices.dedup();
+        indices
+    }
+}
@@ -1,6 +1,10 @@
 //! Global path planning module
 
+pub mod cache;
+mod grid;
+pub mod planner;
 pub mod rrtstar;
+pub mod smoothing;
 
 use std::sync::Arc;
 
@@ -9,17 +13,26 @@ use bevy::{
     math::Vec2,
     tasks::Task,
 };
+pub use cache::PathCache;
 use delegate::delegate;
 use derive_more::Index;
+use gbp_geometry::{point_to_segment_distance, Point};
+use grid::ColliderGrid;
 use parry2d::{
+    bounding_volume::BoundingVolume,
     na::{self, Isometry2, Vector2},
     query::intersection_test,
     shape,
 };
+pub use planner::{plan, PlannerAlgorithm};
 use rand::{
     distributions::{Distribution, Uniform},
-    RngCore,
+    Rng, RngCore,
 };
+pub use rrtstar::plan_blocking;
+use serde::{Deserialize, Serialize};
+pub use smoothing::shortcut_smooth;
+use unit_interval::UnitInterval;
 
 /// **Bevy** [`Resource`] for storing an RRT* Tree
 /// Simply a wrapper for [`rrt::rrtstar::Tree`]
@@ -27,11 +40,65 @@ use rand::{
 #[derive(Debug, Resource, Default)]
 pub struct RRTStarTree(rrt::rrtstar::Tree<f64, f32>);
 
+impl From<rrt::rrtstar::Tree<f64, f32>> for RRTStarTree {
+    fn from(tree: rrt::rrtstar::Tree<f64, f32>) -> Self {
+        Self(tree)
+    }
+}
+
+impl RRTStarTree {
+    /// Number of nodes in the tree, including the root
+    #[inline]
+    pub fn node_count(&self) -> usize {
+        self.0.vertices.len()
+    }
+
+    /// Iterate over all edges of the tree, as `(parent, child)` positions
+    /// - Used to visualise the search tree
+    pub fn edges(&self) -> impl Iterator<Item = (Vec2, Vec2)> + '_ {
+        self.0.vertices.iter().filter_map(|node| {
+            let parent = &self.0.vertices[node.parent_index?];
+            Some((to_vec2(&parent.data), to_vec2(&node.data)))
+        })
+    }
+
+    /// Position of the node in the tree closest to `point`
+    /// Returns `None` if the tree is empty
+    pub fn nearest(&self, point: Vec2) -> Option<Vec2> {
+        self.0
+            .vertices
+            .iter()
+            .map(|node| to_vec2(&node.data))
+            .min_by(|a, b| {
+                a.distance_squared(point)
+                    .total_cmp(&b.distance_squared(point))
+            })
+    }
+}
+
+/// Convert a point in the planners `f64` representation to a [`Vec2`]
+#[allow(clippy::cast_possible_truncation)]
+fn to_vec2(point: &[f64]) -> Vec2 {
+    Vec2::new(point[0] as f32, point[1] as f32)
+}
+
 /// **Bevy** [`Resource`] for storing a path
 /// Simply a wrapper for a list of [`Vec2`] points
-#[derive(Debug, Resource, Default, Index)]
+/// - Serialized as the list of points, so planned paths can be saved and
+///   compared across runs
+#[derive(Debug, Clone, PartialEq, Resource, Default, Index, Serialize, Deserialize)]
+#[serde(transparent)]
 pub struct Path(pub Vec<Vec2>);
 
+/// Possible errors when reading or writing a [`Path`] file
+#[derive(Debug, thiserror::Error)]
+pub enum PathFileError {
+    #[error("IO error: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("RON error: {0}")]
+    Ron(#[from] ron::Error),
+}
+
 impl Path {
     delegate! {
         to self.0 {
@@ -56,20 +123,157 @@ impl Path {
         self.0 = path;
     }
 
-    #[allow(unused)]
-    fn euclidean_length(&self) -> f32 {
-        let mut length = 0.0;
-        for i in 0..self.len() - 1 {
-            length += (self[i] - self[i + 1]).length();
+    /// Attempt to read a `Path` from a RON file
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if:
+    /// 1. `path` does not exist on the filesystem.
+    /// 2. The contents of `path` is not a valid RON encoded `Path`.
+    pub fn from_file<P: AsRef<std::path::Path>>(path: P) -> Result<Self, PathFileError> {
+        std::fs::read_to_string(path)
+            .map(|file_contents| Self::parse_from_ron(file_contents.as_str()))?
+    }
+
+    /// Write the `Path` to a RON file, overwriting it if it exists
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if the `Path` could not be serialized, or `path`
+    /// could not be written to
+    pub fn to_file<P: AsRef<std::path::Path>>(&self, path: P) -> Result<(), PathFileError> {
+        std::fs::write(path, self.to_ron_string()?)?;
+        Ok(())
+    }
+
+    /// Attempt to parse a `Path` from a RON encoded string
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if `contents` is not a valid RON encoded `Path`
+    pub fn parse_from_ron(contents: &str) -> Result<Self, PathFileError> {
+        Ok(ron::from_str::<Self>(contents).map_err(|span| span.code)?)
+    }
+
+    /// Serialize the `Path` to a pretty printed RON string
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if the `Path` could not be serialized
+    pub fn to_ron_string(&self) -> Result<String, PathFileError> {
+        Ok(ron::ser::to_string_pretty(
+            self,
+            ron::ser::PrettyConfig::default(),
+        )?)
+    }
+
+    /// Total length of the polyline through all points of the path
+    /// Paths with fewer than two points have length 0.0
+    pub fn euclidean_length(&self) -> f32 {
+        self.0
+            .windows(2)
+            .map(|segment| segment[0].distance(segment[1]))
+            .sum()
+    }
+
+    /// Resample the path into points spaced `spacing` apart along the polyline
+    /// The first and last points are always kept, so the last gap may be
+    /// shorter than `spacing`. Paths with fewer than two points are returned
+    /// unchanged.
+    ///
+    /// # Panics
+    ///
+    /// If `spacing` is not strictly positive and finite
+    #[must_use]
+    pub fn resample(&self, spacing: f32) -> Self {
+        assert!(
+            spacing.is_finite() && spacing > 0.0,
+            "spacing must be strictly positive and finite, got {spacing}"
+        );
+        if self.len() < 2 {
+            return self.clone();
         }
-        length
+        let (first, last) = (self.0[0], self.0[self.len() - 1]);
+
+        // points closer than this to the last point are skipped, so rounding
+        // errors do not place a point on top of it
+        let tolerance = spacing * 1e-3;
+        let total_length = self.euclidean_length();
+
+        let mut points = vec![first];
+        let mut next = spacing;
+        let mut travelled = 0.0;
+        for segment in self.0.windows(2) {
+            let (start, end) = (segment[0], segment[1]);
+            let length = start.distance(end);
+            while next <= travelled + length && next < total_length - tolerance {
+                points.push(start.lerp(end, (next - travelled) / length));
+                next += spacing;
+            }
+            travelled += length;
+        }
+        points.push(last);
+
+        Self(points)
+    }
+
+    /// Simplify the path with the Ramer-Douglas-Peucker algorithm
+    /// Removes every point that is within `epsilon` of the simplified path
+    /// between the points kept around it. The first and last points are
+    /// always kept. Paths with fewer than three points are returned unchanged.
+    #[must_use]
+    pub fn simplified(&self, epsilon: f32) -> Self {
+        let points = &self.0;
+        if points.len() < 3 {
+            return self.clone();
+        }
+
+        let mut keep = vec![false; points.len()];
+        keep[0] = true;
+        keep[points.len() - 1] = true;
+
+        let to_point = |point: Vec2| Point::new(f64::from(point.x), f64::from(point.y));
+        let mut ranges = vec![(0, points.len() - 1)];
+        while let Some((start, end)) = ranges.pop() {
+            let segment = (to_point(points[start]), to_point(points[end]));
+            let farthest = (start + 1..end)
+                .map(|i| (i, point_to_segment_distance(to_point(points[i]), segment)))
+                .max_by(|(_, a), (_, b)| a.total_cmp(b));
+
+            if let Some((index, distance)) = farthest {
+                if distance > f64::from(epsilon) {
+                    keep[index] = true;
+                    ranges.push((start, index));
+                    ranges.push((index, end));
+                }
+            }
+        }
+
+        Self(
+            points
+                .iter()
+                .zip(keep)
+                .filter_map(|(&point, keep)| keep.then_some(point))
+                .collect(),
+        )
     }
 }
 
 /// Possible pathfinding errors
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, thiserror::Error)]
 pub enum PathfindingError {
+    /// No path to the goal was found within the maximum number of iterations
+    #[error("reached the maximum number of iterations without finding a path to the goal")]
     ReachedMaxIterations,
+    /// The planner terminated without finding a path to the goal
+    #[error("no path to the goal exists")]
+    NoPathExists,
+    /// The start position is in collision, so no path can start from it
+    #[error("the start position is in collision")]
+    StartInCollision,
+    /// The goal position is in collision, so no path can reach it
+    #[error("the goal position is in collision")]
+    GoalInCollision,
 }
 
 /// **Bevy** [`Component`] for storing the pathfinding task
@@ -98,12 +302,17 @@ impl Collider {
     }
 }
 /// **Bevy** [`Resource`] for storing a list of colliders
+/// - Colliders are indexed in a uniform grid by their bounding box as they are
+///   added, so [`Colliders::candidates_near`] only visits nearby colliders
 #[derive(Resource, Default, Clone)]
-pub struct Colliders(Vec<Collider>);
+pub struct Colliders {
+    colliders: Vec<Collider>,
+    grid:      ColliderGrid,
+}
 
 impl Colliders {
     delegate! {
-        to self.0 {
+        to self.colliders {
             #[call(iter)]
             pub fn iter(&self) -> impl Iterator<Item = &Collider>;
 
@@ -112,53 +321,190 @@ impl Colliders {
 
             #[call(is_empty)]
             pub fn is_empty(&self) -> bool;
-
-            #[call(clear)]
-            pub fn clear(&mut self);
         }
     }
 
+    /// Remove all colliders
+    pub fn clear(&mut self) {
+        self.colliders.clear();
+        self.grid.clear();
+    }
+
     pub fn push(
         &mut self,
         associated_mesh: Option<Entity>,
         position: Isometry2<f32>,
         shape: Arc<dyn shape::Shape>,
     ) {
-        self.0.push(Collider {
+        let collider = Collider {
             associated_mesh,
             isometry: position,
             shape,
-        });
+        };
+        self.grid.insert(self.colliders.len(), &collider.aabb());
+        self.colliders.push(collider);
+    }
+
+    /// Get the colliders that may intersect the circle of `radius` around
+    /// `point`
+    /// - Every collider whose bounding box overlaps the circle is returned,
+    ///   together with some further away colliders in the same grid cells
+    pub fn candidates_near(&self, point: Vec2, radius: f32) -> impl Iterator<Item = &Collider> {
+        self.grid
+            .query(point, radius)
+            .into_iter()
+            .map(|index| &self.colliders[index])
     }
 }
 
-struct CollisionProblem {
+/// Collision checking and sampling for planning a path through a set of
+/// [`Colliders`]
+pub struct CollisionProblem {
     colliders: Colliders,
     collision_checker: shape::Ball,
+    /// Minimum and maximum corner of the area random samples are drawn from
+    sampling_bounds: (Vec2, Vec2),
+    /// Whether `sampling_bounds` were set with
+    /// [`CollisionProblem::with_sampling_bounds`], instead of derived from the
+    /// colliders
+    explicit_sampling_bounds: bool,
+    /// Goal sampled with probability `goal_bias` instead of a random point
+    goal: Vec2,
+    goal_bias: UnitInterval,
 }
 
 impl CollisionProblem {
-    fn new(colliders: Colliders) -> Self {
+    /// Half side length of the area random samples are drawn from, if there
+    /// are no colliders to derive it from
+    pub const DEFAULT_SAMPLING_EXTENT: f32 = 2000.0;
+    /// Margin added around the derived sampling bounds, once the start and goal
+    /// of a path are included in them, as a fraction of their larger side
+    pub const ENDPOINT_SAMPLING_MARGIN: f32 = 0.1;
+
+    /// Create a new `CollisionProblem` checking for collisions with
+    /// `colliders`
+    /// - Random samples are drawn from the bounding box of all colliders, see
+    ///   [`CollisionProblem::with_sampling_bounds`] to override it
+    /// - When planning, the bounding box is grown to include the start and goal
+    ///   of the path, see [`CollisionProblem::sampling_bounds_including`]
+    pub fn new(colliders: Colliders) -> Self {
         let ball = shape::Ball::new(0.1f32);
+        let sampling_bounds = colliders
+            .iter()
+            .map(Collider::aabb)
+            .reduce(|a, b| a.merged(&b))
+            .map_or(
+                (
+                    Vec2::splat(-Self::DEFAULT_SAMPLING_EXTENT),
+                    Vec2::splat(Self::DEFAULT_SAMPLING_EXTENT),
+                ),
+                |aabb| {
+                    (
+                        Vec2::new(aabb.mins.x, aabb.mins.y),
+                        Vec2::new(aabb.maxs.x, aabb.maxs.y),
+                    )
+                },
+            );
         Self {
             colliders,
             collision_checker: ball,
+            sampling_bounds,
+            explicit_sampling_bounds: false,
+            goal: Vec2::ZERO,
+            goal_bias: UnitInterval::new(0.0).expect("0.0 in [0.0, 1.0]"),
+        }
+    }
+
+    /// Sample `goal` with probability `goal_bias` instead of a uniformly
+    /// random point
+    pub const fn with_goal_bias(mut self, goal: Vec2, goal_bias: UnitInterval) -> Self {
+        self.goal = goal;
+        self.goal_bias = goal_bias;
+        self
+    }
+
+    /// Set the area random samples are drawn from, given by its minimum and
+    /// maximum corner
+    ///
+    /// # Panics
+    ///
+    /// If `min` is not less than or equal to `max` in both dimensions, or
+    /// either is not finite
+    pub fn with_sampling_bounds(mut self, min: Vec2, max: Vec2) -> Self {
+        assert!(
+            min.is_finite() && max.is_finite() && min.cmple(max).all(),
+            "sampling bounds must be finite with min <= max, got min: {min}, max: {max}"
+        );
+        self.sampling_bounds = (min, max);
+        self.explicit_sampling_bounds = true;
+        self
+    }
+
+    /// Minimum and maximum corner of the area random samples are drawn from
+    #[inline]
+    pub const fn sampling_bounds(&self) -> (Vec2, Vec2) {
+        self.sampling_bounds
+    }
+
+    /// Minimum and maximum corner of the area random samples are drawn from,
+    /// when planning a path between `points`
+    /// - Bounds set with [`CollisionProblem::with_sampling_bounds`] are
+    ///   returned as is
+    /// - Derived bounds are grown to include every point, and then a margin of
+    ///   [`CollisionProblem::ENDPOINT_SAMPLING_MARGIN`], so a start or goal
+    ///   outside of the colliders can still be reached, also by going around
+    ///   the colliders
+    pub fn sampling_bounds_including(&self, points: &[Vec2]) -> (Vec2, Vec2) {
+        if self.explicit_sampling_bounds || points.is_empty() {
+            return self.sampling_bounds;
         }
+
+        let (min, max) = points
+            .iter()
+            .fold(self.sampling_bounds, |(min, max), &point| {
+                (min.min(point), max.max(point))
+            });
+        let margin = (max - min).max_element() * Self::ENDPOINT_SAMPLING_MARGIN;
+        (min - margin, max + margin)
     }
 
-    fn with_collision_radius(mut self, radius: f32) -> Self {
+    /// Set the radius of the ball used to check for collisions at each point
+    pub fn with_collision_radius(mut self, radius: f32) -> Self {
         let ball = shape::Ball::new(radius);
         self.collision_checker = ball;
         self
     }
 
-    fn is_feasible(&self, point: &[f64]) -> bool {
+    /// Check that neither `start` nor `end` is colliding with any of the
+    /// colliders, as no path can be found between them otherwise
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PathfindingError::StartInCollision`] or
+    /// [`PathfindingError::GoalInCollision`] if the respective point is in
+    /// collision
+    pub fn check_endpoints(&self, start: &[f64], end: &[f64]) -> Result<(), PathfindingError> {
+        if !self.is_feasible(start) {
+            Err(PathfindingError::StartInCollision)
+        } else if !self.is_feasible(end) {
+            Err(PathfindingError::GoalInCollision)
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Check if `point` is not colliding with any of the colliders
+    pub fn is_feasible(&self, point: &[f64]) -> bool {
         // place the intersection ball at the point
         let ball_pos = Isometry2::new(Vector2::new(point[0] as f32, point[1] as f32), na::zero());
 
         let mut intersecting = false;
 
-        for collider in self.colliders.iter() {
+        let candidates = self.colliders.candidates_near(
+            Vec2::new(point[0] as f32, point[1] as f32),
+            self.collision_checker.radius,
+        );
+        for collider in candidates {
             let isometry = collider.isometry;
             let shape = &collider.shape;
             intersecting = intersection_test(
@@ -177,9 +523,334 @@ impl CollisionProblem {
         !intersecting
     }
 
-    fn random_sample(&self, mut rng: &mut dyn RngCore) -> Vec<f64> {
-        let between = Uniform::new(-2000.0, 2000.0);
-        // let mut rng = rng;
-        vec![between.sample(&mut rng), between.sample(&mut rng)]
+    /// Draw a random sample from the area between the `(min, max)` corners of
+    /// `bounds`, or the goal with probability `goal_bias`
+    fn random_sample_within(
+        &self,
+        (min, max): (Vec2, Vec2),
+        mut rng: &mut dyn RngCore,
+    ) -> Vec<f64> {
+        if self.goal_bias.get() > 0.0 && rng.gen_bool(self.goal_bias.get()) {
+            return vec![f64::from(self.goal.x), f64::from(self.goal.y)];
+        }
+
+        let x = Uniform::new_inclusive(f64::from(min.x), f64::from(max.x));
+        let y = Uniform::new_inclusive(f64::from(min.y), f64::from(max.y));
+        vec![x.sample(&mut rng), y.sample(&mut rng)]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_prng::WyRand;
+    use rand::SeedableRng;
+
+    use super::*;
+
+    #[test]
+    fn straight_line_simplifies_to_endpoints() {
+        let path = Path((0..=10u8).map(|i| Vec2::new(f32::from(i), 0.0)).collect());
+        let simplified = path.simplified(0.1);
+        assert_eq!(simplified.0, vec![Vec2::ZERO, Vec2::new(10.0, 0.0)]);
+    }
+
+    #[test]
+    fn sharp_corner_is_preserved() {
+        let path = Path(vec![
+            Vec2::new(0.0, 0.0),
+            Vec2::new(5.0, 0.05),
+            Vec2::new(10.0, 0.0),
+            Vec2::new(10.0, 5.0),
+            Vec2::new(10.0, 10.0),
+        ]);
+        let simplified = path.simplified(0.1);
+        assert_eq!(simplified.0, vec![
+            Vec2::new(0.0, 0.0),
+            Vec2::new(10.0, 0.0),
+            Vec2::new(10.0, 10.0)
+        ]);
+    }
+
+    #[test]
+    fn samples_are_within_sampling_bounds() {
+        let (min, max) = (Vec2::new(-10.0, 5.0), Vec2::new(20.0, 7.5));
+        let problem = CollisionProblem::new(Colliders::default()).with_sampling_bounds(min, max);
+        let mut rng = WyRand::seed_from_u64(0);
+
+        for _ in 0..1000 {
+            let sample = problem.random_sample_within(problem.sampling_bounds(), &mut rng);
+            #[allow(clippy::cast_possible_truncation)]
+            let sample = Vec2::new(sample[0] as f32, sample[1] as f32);
+            assert!(
+                sample.cmpge(min).all() && sample.cmple(max).all(),
+                "{sample} is outside of the sampling bounds"
+            );
+        }
+    }
+
+    #[test]
+    fn sampling_bounds_default_to_colliders() {
+        let mut colliders = Colliders::default();
+        colliders.push(
+            None,
+            Isometry2::translation(10.0, 0.0),
+            Arc::new(shape::Ball::new(1.0)),
+        );
+        colliders.push(
+            None,
+            Isometry2::translation(-10.0, 5.0),
+            Arc::new(shape::Cuboid::new(Vector2::new(2.0, 1.0))),
+        );
+
+        let problem = CollisionProblem::new(colliders);
+        assert_eq!(
+            problem.sampling_bounds(),
+            (Vec2::new(-12.0, -1.0), Vec2::new(11.0, 6.0))
+        );
+
+        let problem = CollisionProblem::new(Colliders::default());
+        assert_eq!(
+            problem.sampling_bounds(),
+            (Vec2::splat(-2000.0), Vec2::splat(2000.0))
+        );
+    }
+
+    #[test]
+    fn derived_sampling_bounds_include_endpoints() {
+        let mut colliders = Colliders::default();
+        colliders.push(
+            None,
+            Isometry2::identity(),
+            Arc::new(shape::Cuboid::new(Vector2::new(10.0, 10.0))),
+        );
+        let (start, goal) = (Vec2::new(-100.0, 0.0), Vec2::new(0.0, 50.0));
+
+        let problem = CollisionProblem::new(colliders);
+        let (min, max) = problem.sampling_bounds_including(&[start, goal]);
+        // the margin is a tenth of the 110 wide area spanned by the collider
+        // and the endpoints
+        assert!(min.distance(Vec2::new(-111.0, -21.0)) < 1e-4, "min: {min}");
+        assert!(max.distance(Vec2::new(21.0, 61.0)) < 1e-4, "max: {max}");
+
+        let (min, max) = (Vec2::splat(-5.0), Vec2::splat(5.0));
+        let problem = problem.with_sampling_bounds(min, max);
+        assert_eq!(
+            problem.sampling_bounds_including(&[start, goal]),
+            (min, max)
+        );
+    }
+
+    /// Number of samples RRT* draws before reaching the end of a straight
+    /// corridor with the given `goal_bias`
+    fn corridor_samples(goal_bias: f64) -> usize {
+        let mut colliders = Colliders::default();
+        for y in [-60.0, 60.0] {
+            colliders.push(
+                None,
+                Isometry2::translation(0.0, y),
+                Arc::new(shape::Cuboid::new(Vector2::new(1000.0, 50.0))),
+            );
+        }
+        let (start, goal) = (Vec2::new(-900.0, 0.0), Vec2::new(900.0, 0.0));
+        let problem = CollisionProblem::new(colliders)
+            .with_collision_radius(2.0)
+            .with_goal_bias(
+                goal,
+                UnitInterval::new(goal_bias).expect("bias in [0.0, 1.0]"),
+            );
+
+        let mut rng = WyRand::seed_from_u64(42);
+        let mut samples = 0;
+        let tree = rrt::rrtstar::rrtstar(
+            &[f64::from(start.x), f64::from(start.y)],
+            &[f64::from(goal.x), f64::from(goal.y)],
+            |x: &[f64]| problem.is_feasible(x),
+            || {
+                samples += 1;
+                problem.random_sample_within(problem.sampling_bounds(), &mut rng)
+            },
+            20.0,
+            50_000,
+            40.0,
+            true,
+        )
+        .expect("the corridor is traversable");
+        assert!(tree.goal_index.is_some());
+
+        samples
+    }
+
+    #[test]
+    fn goal_bias_speeds_up_corridor_traversal() {
+        let unbiased = corridor_samples(0.0);
+        let biased = corridor_samples(0.1);
+        assert!(
+            biased < unbiased,
+            "biased run drew {biased} samples, unbiased run drew {unbiased}"
+        );
+    }
+
+    #[test]
+    fn full_goal_bias_always_samples_goal() {
+        let goal = Vec2::new(3.0, -4.0);
+        let problem = CollisionProblem::new(Colliders::default())
+            .with_goal_bias(goal, UnitInterval::new(1.0).expect("1.0 in [0.0, 1.0]"));
+        let mut rng = WyRand::seed_from_u64(0);
+
+        for _ in 0..100 {
+            assert_eq!(
+                problem.random_sample_within(problem.sampling_bounds(), &mut rng),
+                vec![3.0, -4.0]
+            );
+        }
+    }
+
+    #[test]
+    fn euclidean_length_of_l_shaped_path() {
+        let path = Path(vec![Vec2::ZERO, Vec2::new(3.0, 0.0), Vec2::new(3.0, 4.0)]);
+        assert!((path.euclidean_length() - 7.0).abs() < f32::EPSILON);
+
+        assert!(Path::default().euclidean_length().abs() < f32::EPSILON);
+        assert!(Path(vec![Vec2::ONE]).euclidean_length().abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn resample_segment_at_even_spacing() {
+        let path = Path(vec![Vec2::ZERO, Vec2::new(10.0, 0.0)]);
+        let resampled = path.resample(2.0);
+        assert_eq!(resampled.len(), 6);
+        for (i, point) in (0..=5u8).zip(resampled.0) {
+            assert!(point.distance(Vec2::new(f32::from(i) * 2.0, 0.0)) < 1e-4);
+        }
+    }
+
+    #[test]
+    fn resample_keeps_endpoints_around_corners() {
+        let path = Path(vec![Vec2::ZERO, Vec2::new(3.0, 0.0), Vec2::new(3.0, 4.0)]);
+        let resampled = path.resample(2.5);
+        assert_eq!(resampled.0, vec![
+            Vec2::ZERO,
+            Vec2::new(2.5, 0.0),
+            Vec2::new(3.0, 2.0),
+            Vec2::new(3.0, 4.0)
+        ]);
+        assert_eq!(Path::default().resample(1.0).len(), 0);
+    }
+
+    #[test]
+    fn candidates_near_agrees_with_brute_force() {
+        let mut colliders = Colliders::default();
+        // 100 colliders scattered over a 200x200 area
+        for i in 0..100u8 {
+            let (x, y) = (f32::from(i % 10) * 20.0, f32::from(i / 10) * 20.0);
+            let shape: Arc<dyn shape::Shape> = if i % 2 == 0 {
+                Arc::new(shape::Ball::new(3.0))
+            } else {
+                Arc::new(shape::Cuboid::new(Vector2::new(4.0, 2.0)))
+            };
+            colliders.push(
+                None,
+                Isometry2::translation(x + 0.5 * f32::from(i % 7), y),
+                shape,
+            );
+        }
+
+        let ball = shape::Ball::new(2.0);
+        let mut rng = WyRand::seed_from_u64(0);
+        let between = Uniform::new(-10.0, 210.0);
+        for _ in 0..500 {
+            let point = Vec2::new(between.sample(&mut rng), between.sample(&mut rng));
+            let position = Isometry2::translation(point.x, point.y);
+            let intersects = |collider: &Collider| {
+                intersection_test(
+                    &position,
+                    &ball,
+                    &collider.isometry,
+                    collider.shape.as_ref(),
+                )
+                .expect("shapes are supported")
+            };
+
+            let brute_force = colliders.iter().any(intersects);
+            let candidates = colliders
+                .candidates_near(point, ball.radius)
+                .collect::<Vec<_>>();
+            assert_eq!(candidates.iter().copied().any(intersects), brute_force);
+            assert!(candidates.len() < colliders.len());
+        }
+    }
+
+    #[test]
+    fn pathfinding_errors_are_human_readable() {
+        assert_eq!(
+            PathfindingError::ReachedMaxIterations.to_string(),
+            "reached the maximum number of iterations without finding a path to the goal"
+        );
+        assert_eq!(
+            PathfindingError::NoPathExists.to_string(),
+            "no path to the goal exists"
+        );
+        assert_eq!(
+            PathfindingError::StartInCollision.to_string(),
+            "the start position is in collision"
+        );
+        assert_eq!(
+            PathfindingError::GoalInCollision.to_string(),
+            "the goal position is in collision"
+        );
+    }
+
+    #[test]
+    fn path_round_trips_through_ron() {
+        let path = Path(vec![
+            Vec2::new(0.0, 0.0),
+            Vec2::new(1.5, -2.25),
+            Vec2::new(100.0, 42.0),
+        ]);
+        let serialized = path.to_ron_string().expect("a path can be serialized");
+        let deserialized = Path::parse_from_ron(&serialized).expect("the string is a valid path");
+        assert_eq!(deserialized, path);
+    }
+
+    fn open_field_tree(max_iterations: usize) -> RRTStarTree {
+        let problem = CollisionProblem::new(Colliders::default())
+            .with_sampling_bounds(Vec2::splat(-100.0), Vec2::splat(100.0));
+        let mut rng = WyRand::seed_from_u64(0);
+        rrt::rrtstar::rrtstar(
+            &[0.0, 0.0],
+            &[50.0, 50.0],
+            |x: &[f64]| problem.is_feasible(x),
+            || problem.random_sample_within(problem.sampling_bounds(), &mut rng),
+            5.0,
+            max_iterations,
+            10.0,
+            false,
+        )
+        .map(RRTStarTree::from)
+        .expect("the open field is traversable")
+    }
+
+    #[test]
+    fn tree_grows_with_more_samples() {
+        let small = open_field_tree(10);
+        let large = open_field_tree(200);
+        assert!(small.node_count() >= 1);
+        assert!(
+            large.node_count() > small.node_count(),
+            "{} nodes after 200 samples, {} after 10",
+            large.node_count(),
+            small.node_count()
+        );
+
+        // every node but the root has exactly one parent
+        assert_eq!(large.edges().count(), large.node_count() - 1);
+        assert_eq!(large.nearest(Vec2::new(0.01, -0.01)), Some(Vec2::ZERO));
+    }
+
+    #[test]
+    fn short_paths_are_unchanged() {
+        let path = Path(vec![Vec2::ZERO, Vec2::ONE]);
+        assert_eq!(path.simplified(100.0).0, path.0);
+        assert_eq!(Path::default().simplified(0.1).len(), 0);
     }
 }
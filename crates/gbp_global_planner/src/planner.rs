@@ -0,0 +1,266 @@
+use std::cell::RefCell;
+
+use bevy::math::Vec2;
+use gbp_config::RRTSection;
+use rand::RngCore;
+
+use crate::{CollisionProblem, Path, PathfindingError};
+
+/// Algorithm used to plan a path with [`plan`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PlannerAlgorithm {
+    /// Bidirectional RRT-Connect
+    /// Stops at the first feasible path found, which is much faster than
+    /// [`PlannerAlgorithm::RrtStar`] in cluttered environments, but the path
+    /// is not optimised
+    Rrt,
+    /// RRT*
+    /// Rewires the tree to find a shorter path
+    RrtStar {
+        /// Stop as soon as the goal is reached, instead of running for the
+        /// configured maximum number of iterations
+        stop_at_first_solution: bool,
+    },
+}
+
+impl Default for PlannerAlgorithm {
+    fn default() -> Self {
+        Self::RrtStar {
+            stop_at_first_solution: false,
+        }
+    }
+}
+
+/// Plan a path from `start` to `end` through `problem` with the given
+/// `algorithm`
+/// - The path is smoothed afterwards if enabled in `config`
+/// - Goal biased sampling is configured on `problem`, see
+///   [`CollisionProblem::with_goal_bias`]
+/// - `rng` is used for all random sampling, so planning is reproducible for a
+///   seeded `rng`
+///
+/// # Errors
+///
+/// - [`PathfindingError::StartInCollision`] or
+///   [`PathfindingError::GoalInCollision`] if `start` or `end` is in collision,
+///   checked before planning
+/// - [`PathfindingError::ReachedMaxIterations`] if no path was found within
+///   `config.max_iterations` iterations
+/// - [`PathfindingError::NoPathExists`] if RRT* terminated without reaching
+///   `end`
+#[allow(clippy::cast_possible_truncation)]
+pub fn plan(
+    problem: &CollisionProblem,
+    start: Vec2,
+    end: Vec2,
+    algorithm: PlannerAlgorithm,
+    config: &RRTSection,
+    rng: &mut dyn RngCore,
+) -> Result<Path, PathfindingError> {
+    let sampling_bounds = problem.sampling_bounds_including(&[start, end]);
+    let start = [f64::from(start.x), f64::from(start.y)];
+    let end = [f64::from(end.x), f64::from(end.y)];
+    let step_size = f64::from(config.step_size.get());
+    problem.check_endpoints(&start, &end)?;
+
+    // the sampler is borrowed by the planner, and the rng is needed again for
+    // smoothing afterwards
+    let rng = RefCell::new(rng);
+    let random_sample = || problem.random_sample_within(sampling_bounds, &mut **rng.borrow_mut());
+    let is_feasible = |x: &[f64]| problem.is_feasible(x);
+
+    let mut waypoints = match algorithm {
+        PlannerAlgorithm::Rrt => rrt::dual_rrt_connect(
+            &start,
+            &end,
+            is_feasible,
+            random_sample,
+            step_size,
+            config.max_iterations.get(),
+        )
+        .map_err(|_| PathfindingError::ReachedMaxIterations)?,
+        PlannerAlgorithm::RrtStar {
+            stop_at_first_solution,
+        } => {
+            let tree = rrt::rrtstar::rrtstar(
+                &start,
+                &end,
+                is_feasible,
+                random_sample,
+                step_size,
+                config.max_iterations.get(),
+                f64::from(config.neighbourhood_radius.get()),
+                stop_at_first_solution,
+            )
+            .map_err(|_| PathfindingError::ReachedMaxIterations)?;
+            let goal_index = tree.goal_index.ok_or(PathfindingError::NoPathExists)?;
+
+            let mut waypoints = std::iter::once(vec![end[0], end[1]])
+                .chain(tree.get_until_root(goal_index))
+                .collect::<Vec<_>>();
+            waypoints.reverse();
+            waypoints
+        }
+    };
+
+    if config.smoothing.enabled {
+        rrt::rrtstar::smooth_path(
+            &mut waypoints,
+            is_feasible,
+            step_size,
+            config.smoothing.max_iterations.get(),
+            &mut **rng.borrow_mut(),
+        );
+    }
+
+    Ok(Path(
+        waypoints
+            .into_iter()
+            .map(|v| Vec2::new(v[0] as f32, v[1] as f32))
+            .collect(),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{num::NonZeroUsize, sync::Arc};
+
+    use bevy_prng::WyRand;
+    use parry2d::{na::Isometry2, shape};
+    use rand::SeedableRng;
+
+    use super::*;
+    use crate::Colliders;
+
+    /// Wrapper around an rng counting the number of random values drawn
+    struct CountingRng<R> {
+        rng:   R,
+        draws: usize,
+    }
+
+    impl<R: RngCore> RngCore for CountingRng<R> {
+        fn next_u32(&mut self) -> u32 {
+            self.draws += 1;
+            self.rng.next_u32()
+        }
+
+        fn next_u64(&mut self) -> u64 {
+            self.draws += 1;
+            self.rng.next_u64()
+        }
+
+        fn fill_bytes(&mut self, dest: &mut [u8]) {
+            self.draws += 1;
+            self.rng.fill_bytes(dest);
+        }
+
+        fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand::Error> {
+            self.draws += 1;
+            self.rng.try_fill_bytes(dest)
+        }
+    }
+
+    fn plan_around_obstacle(algorithm: PlannerAlgorithm) -> (Path, CollisionProblem, usize) {
+        let mut colliders = Colliders::default();
+        colliders.push(
+            None,
+            Isometry2::identity(),
+            Arc::new(shape::Ball::new(200.0)),
+        );
+        let problem = CollisionProblem::new(colliders).with_collision_radius(10.0);
+
+        let mut config = RRTSection {
+            max_iterations: NonZeroUsize::new(5000).expect("5000 > 0"),
+            step_size: 100.0.try_into().expect("100.0 > 0.0"),
+            neighbourhood_radius: 300.0.try_into().expect("300.0 > 0.0"),
+            ..Default::default()
+        };
+        config.smoothing.enabled = false;
+
+        let mut rng = CountingRng {
+            rng:   WyRand::seed_from_u64(0),
+            draws: 0,
+        };
+        let path = plan(
+            &problem,
+            Vec2::new(-1000.0, 0.0),
+            Vec2::new(1000.0, 0.0),
+            algorithm,
+            &config,
+            &mut rng,
+        )
+        .expect("a path around a single obstacle exists");
+
+        (path, problem, rng.draws)
+    }
+
+    #[test]
+    fn goal_in_collider_is_rejected_before_planning() {
+        let mut colliders = Colliders::default();
+        colliders.push(
+            None,
+            Isometry2::translation(100.0, 0.0),
+            Arc::new(shape::Ball::new(20.0)),
+        );
+        let problem = CollisionProblem::new(colliders)
+            .with_sampling_bounds(Vec2::splat(-200.0), Vec2::splat(200.0));
+        let config = RRTSection {
+            step_size: 10.0.try_into().expect("10.0 > 0.0"),
+            ..Default::default()
+        };
+        let mut rng = WyRand::seed_from_u64(0);
+
+        for algorithm in [PlannerAlgorithm::Rrt, PlannerAlgorithm::default()] {
+            let inside = plan(
+                &problem,
+                Vec2::new(-100.0, 0.0),
+                Vec2::new(100.0, 0.0),
+                algorithm,
+                &config,
+                &mut rng,
+            );
+            assert_eq!(inside.err(), Some(PathfindingError::GoalInCollision));
+
+            let inside = plan(
+                &problem,
+                Vec2::new(100.0, 5.0),
+                Vec2::new(-100.0, 0.0),
+                algorithm,
+                &config,
+                &mut rng,
+            );
+            assert_eq!(inside.err(), Some(PathfindingError::StartInCollision));
+        }
+
+        let reachable = plan(
+            &problem,
+            Vec2::new(-100.0, 0.0),
+            Vec2::new(100.0, 100.0),
+            PlannerAlgorithm::Rrt,
+            &config,
+            &mut rng,
+        )
+        .expect("goal outside of the collider is reachable");
+        assert_eq!(reachable.0.last(), Some(&Vec2::new(100.0, 100.0)));
+    }
+
+    #[test]
+    fn both_algorithms_find_collision_free_path_and_rrt_is_faster() {
+        let (rrt_path, problem, rrt_draws) = plan_around_obstacle(PlannerAlgorithm::Rrt);
+        let (rrtstar_path, _, rrtstar_draws) = plan_around_obstacle(PlannerAlgorithm::default());
+
+        for path in [&rrt_path, &rrtstar_path] {
+            assert_eq!(path.0.first(), Some(&Vec2::new(-1000.0, 0.0)));
+            assert_eq!(path.0.last(), Some(&Vec2::new(1000.0, 0.0)));
+            assert!(path
+                .0
+                .iter()
+                .all(|p| problem.is_feasible(&[f64::from(p.x), f64::from(p.y)])));
+        }
+
+        assert!(
+            rrt_draws < rrtstar_draws,
+            "RRT drew {rrt_draws} samples, RRT* drew {rrtstar_draws}"
+        );
+    }
+}
@@ -7,16 +7,61 @@ use bevy_prng::WyRand;
 use gbp_config::RRTSection;
 use rand::{RngCore, SeedableRng};
 
-use crate::{Colliders, CollisionProblem, Path, PathfindingError, PathfindingTask};
+use crate::{
+    plan, Colliders, CollisionProblem, Path, PathfindingError, PathfindingTask, PlannerAlgorithm,
+};
 
-/// Standalone function to spawn an async task for pathfinding
-/// - Used to run path-finding tasks that may take longer than a single frame to
-///   complete
-pub fn spawn_pathfinding_task(
+/// Plan a path from `start` to `goal` through `colliders` with RRT*, blocking
+/// until it is found
+/// - The blocking counterpart of [`spawn_pathfinding_task`], for use outside of
+///   **Bevy**'s async task pools, e.g. in headless experiments
+/// - Random samples are drawn from an entropy seeded rng
+///
+/// # Errors
+///
+/// - [`PathfindingError::StartInCollision`] or
+///   [`PathfindingError::GoalInCollision`] if `start` or `goal` is in collision
+/// - [`PathfindingError::ReachedMaxIterations`] if no path was found within
+///   `config.max_iterations` iterations
+/// - [`PathfindingError::NoPathExists`] if RRT* terminated without reaching
+///   `goal`
+pub fn plan_blocking(
+    colliders: &Colliders,
+    start: Vec2,
+    goal: Vec2,
+    config: &RRTSection,
+) -> Result<Path, PathfindingError> {
+    plan(
+        &collision_problem(colliders.clone(), goal, config),
+        start,
+        goal,
+        FIRST_SOLUTION,
+        config,
+        &mut WyRand::from_entropy(),
+    )
+}
+
+/// RRT*, stopping at the first path found
+const FIRST_SOLUTION: PlannerAlgorithm = PlannerAlgorithm::RrtStar {
+    stop_at_first_solution: true,
+};
+
+/// The [`CollisionProblem`] of planning a path to `goal` through `colliders`,
+/// as configured in `config`
+fn collision_problem(colliders: Colliders, goal: Vec2, config: &RRTSection) -> CollisionProblem {
+    CollisionProblem::new(colliders)
+        .with_collision_radius(config.collision_radius.get())
+        .with_goal_bias(goal, config.goal_bias)
+}
+
+/// Spawn an async task planning a path from `start` to `end` with
+/// `algorithm`, and insert it on `task_target` as a [`PathfindingTask`]
+#[allow(clippy::too_many_arguments)]
+fn spawn_planning_task(
     commands: &mut Commands,
     start: Vec2,
     end: Vec2,
-    // smooth: bool,
+    algorithm: PlannerAlgorithm,
     rrt_params: RRTSection,
     colliders: Colliders,
     task_target: Entity,
@@ -27,56 +72,19 @@ pub fn spawn_pathfinding_task(
         None => Box::new(WyRand::from_entropy()),
     };
 
-    let collision_solver =
-        CollisionProblem::new(colliders).with_collision_radius(rrt_params.collision_radius.get());
+    let collision_solver = collision_problem(colliders, end, &rrt_params);
 
     let task_pool = AsyncComputeTaskPool::get();
 
     let task = task_pool.spawn(async move {
-        let start = [start.x as f64, start.y as f64];
-        let end = [end.x as f64, end.y as f64];
-
-        rrt::rrtstar::rrtstar(
-            &start,
-            &end,
-            |x: &[f64]| collision_solver.is_feasible(x),
-            // || collision_solver.random_sample(&mut *rng_source.lock().unwrap()),
-            || collision_solver.random_sample(&mut *rng_source),
-            rrt_params.step_size.get() as f64,
-            rrt_params.max_iterations.get(),
-            rrt_params.neighbourhood_radius.get() as f64,
-            true,
+        plan(
+            &collision_solver,
+            start,
+            end,
+            algorithm,
+            &rrt_params,
+            &mut *rng_source,
         )
-        .map(|res| {
-            if let Some(goal_index) = res.goal_index {
-                let resulting_path = {
-                    let mut resulting_path = std::iter::once(vec![end[0], end[1]])
-                        .chain(res.get_until_root(goal_index).into_iter())
-                        .collect::<Vec<_>>();
-                    if rrt_params.smoothing.enabled {
-                        rrt::rrtstar::smooth_path(
-                            &mut resulting_path,
-                            |x| collision_solver.is_feasible(x),
-                            rrt_params.step_size.get() as f64,
-                            rrt_params.smoothing.max_iterations.get(),
-                            &mut *rng_source,
-                        );
-                    }
-                    resulting_path
-                };
-
-                Path(
-                    resulting_path
-                        .into_iter()
-                        .rev()
-                        .map(|v| Vec2::new(v[0] as f32, v[1] as f32))
-                        .collect::<Vec<_>>(),
-                )
-            } else {
-                Path(vec![])
-            }
-        })
-        .map_err(|_| PathfindingError::ReachedMaxIterations)
     });
 
     commands.entity(task_target).insert(PathfindingTask(task));
@@ -85,7 +93,9 @@ pub fn spawn_pathfinding_task(
 /// Standalone function to spawn an async task for pathfinding
 /// - Used to run path-finding tasks that may take longer than a single frame to
 ///   complete
-pub fn spawn_pathfinding_task_full_tree(
+/// - Stops at the first path found
+/// - See [`plan_blocking`] for planning without an async task pool
+pub fn spawn_pathfinding_task(
     commands: &mut Commands,
     start: Vec2,
     end: Vec2,
@@ -95,61 +105,63 @@ pub fn spawn_pathfinding_task_full_tree(
     task_target: Entity,
     rng_source: Option<Box<dyn RngCore + Send>>,
 ) {
-    let mut rng_source: Box<dyn RngCore + Send> = match rng_source {
-        Some(rng) => rng,
-        None => Box::new(WyRand::from_entropy()),
-    };
-
-    let collision_solver =
-        CollisionProblem::new(colliders).with_collision_radius(rrt_params.collision_radius.get());
+    spawn_planning_task(
+        commands,
+        start,
+        end,
+        FIRST_SOLUTION,
+        rrt_params,
+        colliders,
+        task_target,
+        rng_source,
+    );
+}
 
-    let task_pool = AsyncComputeTaskPool::get();
+/// Standalone function to spawn an async task for pathfinding
+/// - Used to run path-finding tasks that may take longer than a single frame to
+///   complete
+/// - Runs for the configured maximum number of iterations, to find a shorter
+///   path than [`spawn_pathfinding_task`]
+pub fn spawn_pathfinding_task_full_tree(
+    commands: &mut Commands,
+    start: Vec2,
+    end: Vec2,
+    // smooth: bool,
+    rrt_params: RRTSection,
+    colliders: Colliders,
+    task_target: Entity,
+    rng_source: Option<Box<dyn RngCore + Send>>,
+) {
+    spawn_planning_task(
+        commands,
+        start,
+        end,
+        PlannerAlgorithm::default(),
+        rrt_params,
+        colliders,
+        task_target,
+        rng_source,
+    );
+}
 
-    let task = task_pool.spawn(async move {
-        let start = [start.x as f64, start.y as f64];
-        let end = [end.x as f64, end.y as f64];
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-        rrt::rrtstar::rrtstar(
-            &start,
-            &end,
-            |x: &[f64]| collision_solver.is_feasible(x),
-            // || collision_solver.random_sample(&mut *rng_source.lock().unwrap()),
-            || collision_solver.random_sample(&mut *rng_source),
-            rrt_params.step_size.get() as f64,
-            rrt_params.max_iterations.get(),
-            rrt_params.neighbourhood_radius.get() as f64,
-            true,
-        )
-        .map(|res| {
-            if let Some(goal_index) = res.goal_index {
-                let resulting_path = {
-                    let mut resulting_path = std::iter::once(vec![end[0], end[1]])
-                        .chain(res.get_until_root(goal_index).into_iter())
-                        .collect::<Vec<_>>();
-                    if rrt_params.smoothing.enabled {
-                        rrt::rrtstar::smooth_path(
-                            &mut resulting_path,
-                            |x| collision_solver.is_feasible(x),
-                            rrt_params.step_size.get() as f64,
-                            rrt_params.smoothing.max_iterations.get(),
-                            &mut *rng_source,
-                        );
-                    }
-                    resulting_path
-                };
+    #[test]
+    fn plan_blocking_preserves_endpoints_in_empty_environment() {
+        let (start, goal) = (Vec2::new(-50.0, 20.0), Vec2::new(80.0, -40.0));
+        let config = RRTSection {
+            step_size: 10.0.try_into().expect("10.0 > 0.0"),
+            neighbourhood_radius: 20.0.try_into().expect("20.0 > 0.0"),
+            ..Default::default()
+        };
 
-                Path(
-                    resulting_path
-                        .into_iter()
-                        .map(|v| Vec2::new(v[0] as f32, v[1] as f32))
-                        .collect::<Vec<_>>(),
-                )
-            } else {
-                Path(vec![])
-            }
-        })
-        .map_err(|_| PathfindingError::ReachedMaxIterations)
-    });
+        let path = plan_blocking(&Colliders::default(), start, goal, &config)
+            .expect("nothing blocks the path in an empty environment");
 
-    commands.entity(task_target).insert(PathfindingTask(task));
+        assert!(path.len() >= 2);
+        assert_eq!(path.0.first(), Some(&start));
+        assert_eq!(path.0.last(), Some(&goal));
+    }
 }
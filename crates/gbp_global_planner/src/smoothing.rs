@@ -0,0 +1,110 @@
+use bevy::math::Vec2;
+use parry2d::{
+    na::{Isometry2, Point2},
+    query::intersection_test,
+    shape,
+};
+use rand::{Rng, RngCore};
+
+use crate::{Colliders, Path};
+
+/// Smooth `path` by repeatedly replacing the sub-path between two random
+/// points with a straight line, if that line does not intersect any of the
+/// `colliders`
+/// - `iterations` shortcuts are attempted, or fewer if the path is reduced to a
+///   straight line before that
+/// - The first and last points of the path are always kept
+pub fn shortcut_smooth(
+    path: &Path,
+    colliders: &Colliders,
+    iterations: usize,
+    rng: &mut dyn RngCore,
+) -> Path {
+    let mut points = path.0.clone();
+
+    for _ in 0..iterations {
+        if points.len() < 3 {
+            break;
+        }
+
+        let (a, b) = (
+            rng.gen_range(0..points.len()),
+            rng.gen_range(0..points.len()),
+        );
+        let (start, end) = (a.min(b), a.max(b));
+        if end - start < 2 {
+            continue;
+        }
+
+        if is_segment_free(colliders, points[start], points[end]) {
+            points.drain(start + 1..end);
+        }
+    }
+
+    Path(points)
+}
+
+/// Check if the line segment from `start` to `end` does not intersect any of
+/// the `colliders`
+pub(crate) fn is_segment_free(colliders: &Colliders, start: Vec2, end: Vec2) -> bool {
+    let segment = shape::Segment::new(Point2::new(start.x, start.y), Point2::new(end.x, end.y));
+    colliders.iter().all(|collider| {
+        !intersection_test(
+            &Isometry2::identity(),
+            &segment,
+            &collider.isometry,
+            collider.shape.as_ref(),
+        )
+        .expect("Correct shapes should have been given.")
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_prng::WyRand;
+    use rand::SeedableRng;
+
+    use super::*;
+
+    #[test]
+    fn zig_zag_collapses_in_empty_environment() {
+        let zig_zag = Path(
+            (0..=10u8)
+                .map(|i| Vec2::new(f32::from(i), f32::from(i % 2)))
+                .collect(),
+        );
+        let colliders = Colliders::default();
+        let mut rng = WyRand::seed_from_u64(0);
+
+        let smoothed = shortcut_smooth(&zig_zag, &colliders, 100, &mut rng);
+
+        assert!(smoothed.euclidean_length() < zig_zag.euclidean_length());
+        assert!(smoothed.euclidean_length() < Vec2::ZERO.distance(Vec2::new(10.0, 0.0)) + 0.5);
+        assert_eq!(smoothed.0.first(), zig_zag.0.first());
+        assert_eq!(smoothed.0.last(), zig_zag.0.last());
+        assert!(smoothed
+            .0
+            .windows(2)
+            .all(|segment| is_segment_free(&colliders, segment[0], segment[1])));
+    }
+
+    #[test]
+    fn shortcut_through_obstacle_is_rejected() {
+        let path = Path(vec![
+            Vec2::new(-10.0, 0.0),
+            Vec2::new(0.0, 10.0),
+            Vec2::new(10.0, 0.0),
+        ]);
+        let mut colliders = Colliders::default();
+        colliders.push(
+            None,
+            Isometry2::identity(),
+            std::sync::Arc::new(shape::Ball::new(2.0)),
+        );
+        let mut rng = WyRand::seed_from_u64(0);
+
+        let smoothed = shortcut_smooth(&path, &colliders, 100, &mut rng);
+
+        assert_eq!(smoothed.0, path.0);
+    }
+}
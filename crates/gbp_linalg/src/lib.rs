@@ -8,8 +8,8 @@ pub mod prelude {
     // pub use ndarray::{array, concatenate, s, Axis};
 
     pub use super::{
-        pretty_print::*, Float, GbpFloat, Matrix, MatrixView, NdarrayVectorExt, Vector, VectorNorm,
-        VectorView,
+        dominant_eigenvalue, log_sum_exp, pearson_correlation, pretty_print::*, softmax, Float,
+        GbpFloat, Matrix, MatrixView, NdarrayVectorExt, Vector, VectorNorm, VectorView,
     };
 }
 
@@ -105,6 +105,10 @@ pub trait NdarrayVectorExt: Clone + VectorNorm {
         copy.normalize();
         copy
     }
+    /// Cosine of the angle between the vector and `other`, i.e. their dot
+    /// product divided by the product of their norms.
+    /// Returns 0 if either vector has zero magnitude.
+    fn cosine_similarity(&self, other: &Self) -> <Self as NdarrayVectorExt>::Scalar;
 }
 
 macro_rules! ndarray_vector_ext_trait_impl {
@@ -125,6 +129,14 @@ macro_rules! ndarray_vector_ext_trait_impl {
                 }
                 // self.map_mut(|x| *x = *x / mag);
             }
+
+            fn cosine_similarity(&self, other: &Self) -> $float {
+                let denominator = self.euclidean_norm() * other.euclidean_norm();
+                if denominator == 0.0 {
+                    return 0.0;
+                }
+                self.dot(other) / denominator
+            }
         }
 
         // impl NdarrayVectorExt for VectorView<'_, $float> {
@@ -151,6 +163,88 @@ macro_rules! ndarray_vector_ext_trait_impl {
 ndarray_vector_ext_trait_impl!(f32);
 ndarray_vector_ext_trait_impl!(f64);
 
+/// Numerically stable `log(sum(exp(v)))`
+/// The maximum element is subtracted before exponentiating, so large
+/// magnitude elements do not overflow.
+/// Returns negative infinity for an empty vector.
+pub fn log_sum_exp<T: GbpFloat>(v: &Vector<T>) -> T {
+    let max = v.fold(T::neg_infinity(), |acc, &x| acc.max(x));
+    if !max.is_finite() {
+        return max;
+    }
+    max + v.fold(T::zero(), |acc, &x| acc + (x - max).exp()).ln()
+}
+
+/// Numerically stable softmax of `v`
+/// The maximum element is subtracted before exponentiating, so large
+/// magnitude elements do not overflow.
+/// The elements of the returned vector are in [0, 1] and sum to 1.
+pub fn softmax<T: GbpFloat>(v: &Vector<T>) -> Vector<T> {
+    let max = v.fold(T::neg_infinity(), |acc, &x| acc.max(x));
+    let exps = v.mapv(|x| (x - max).exp());
+    let sum = exps.sum();
+    exps / sum
+}
+
+/// Pearson correlation coefficient between the series `a` and `b`
+/// Returns `None` if the series differ in length, have fewer than two
+/// elements, or if either of them is constant.
+pub fn pearson_correlation(a: &Vector<Float>, b: &Vector<Float>) -> Option<Float> {
+    if a.len() != b.len() || a.len() < 2 {
+        return None;
+    }
+    let a = a - a.mean()?;
+    let b = b - b.mean()?;
+    let denominator = (a.dot(&a) * b.dot(&b)).sqrt();
+    if denominator == 0.0 {
+        return None;
+    }
+    Some(a.dot(&b) / denominator)
+}
+
+/// Estimate the eigenvalue of `m` with the largest magnitude, using power
+/// iteration.
+///
+/// Iteration stops once the estimate changes by at most `tol` between two
+/// iterations.
+/// Returns `None` if `m` is not square, is empty, or the estimate has not
+/// converged after `iterations` iterations.
+pub fn dominant_eigenvalue<T: GbpFloat>(m: &Matrix<T>, iterations: usize, tol: T) -> Option<T> {
+    let (rows, cols) = m.dim();
+    if rows != cols || rows == 0 {
+        return None;
+    }
+
+    // start from a vector that is unlikely to be orthogonal to the dominant
+    // eigenvector, e.g. [1, 1] is for [[1, -2], [-2, 1]]
+    let mut next = T::zero();
+    let mut v: Vector<T> = Vector::from_shape_fn(rows, |_| {
+        next += T::one();
+        next
+    });
+    v /= v.dot(&v).sqrt();
+
+    let mut eigenvalue = v.dot(&m.dot(&v));
+    for _ in 0..iterations {
+        let w = m.dot(&v);
+        let norm = w.dot(&w).sqrt();
+        if norm == T::zero() {
+            // `v` is in the null space of `m`
+            return Some(T::zero());
+        }
+        v = w / norm;
+
+        let previous = eigenvalue;
+        // Rayleigh quotient, `v` is normalized
+        eigenvalue = v.dot(&m.dot(&v));
+        if (eigenvalue - previous).abs() <= tol {
+            return Some(eigenvalue);
+        }
+    }
+
+    None
+}
+
 #[cfg(test)]
 mod tests {
     use approx::assert_relative_eq;
@@ -293,4 +387,90 @@ mod tests {
 
     test_vector_normalize!(vector_normalize_f32: f32);
     test_vector_normalize!(vector_normalize_f64: f64);
+
+    #[test]
+    fn softmax_sums_to_one() {
+        let v: Vector<f64> = array![1.0, 2.0, 3.0, -4.0];
+        let s = softmax(&v);
+        assert_relative_eq!(s.sum(), 1.0, epsilon = 1e-12);
+        assert!(s.iter().all(|&x| (0.0..=1.0).contains(&x)));
+        // softmax is monotonic
+        assert!(s[2] > s[1] && s[1] > s[0] && s[0] > s[3]);
+    }
+
+    #[test]
+    fn softmax_large_magnitude_does_not_overflow() {
+        let v: Vector<f32> = array![1000.0, 1000.0, -1000.0];
+        let s = softmax(&v);
+        assert!(s.iter().all(|x| x.is_finite()));
+        assert_relative_eq!(s[0], 0.5);
+        assert_relative_eq!(s[1], 0.5);
+        assert_relative_eq!(s[2], 0.0);
+    }
+
+    #[test]
+    fn log_sum_exp_matches_naive() {
+        let v: Vector<f64> = array![0.5, -1.0, 2.0, 0.0];
+        let naive = v.mapv(f64::exp).sum().ln();
+        assert_relative_eq!(log_sum_exp(&v), naive, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn log_sum_exp_large_magnitude_does_not_overflow() {
+        let v: Vector<f64> = array![1000.0, 1000.0];
+        assert_relative_eq!(log_sum_exp(&v), 1000.0 + 2.0_f64.ln());
+        assert_eq!(log_sum_exp(&Vector::<f64>::zeros(0)), f64::NEG_INFINITY);
+    }
+
+    #[test]
+    fn cosine_similarity_of_identical_and_orthogonal_vectors() {
+        let v: Vector<f64> = array![1.0, 2.0, 3.0];
+        assert_relative_eq!(v.cosine_similarity(&v), 1.0, epsilon = 1e-12);
+        assert_relative_eq!(v.cosine_similarity(&-&v), -1.0, epsilon = 1e-12);
+
+        let x: Vector<f32> = array![1.0, 0.0];
+        let y: Vector<f32> = array![0.0, 2.0];
+        assert_relative_eq!(x.cosine_similarity(&y), 0.0);
+        assert_relative_eq!(x.cosine_similarity(&Vector::zeros(2)), 0.0);
+    }
+
+    #[test]
+    fn pearson_correlation_of_correlated_series() {
+        let a: Vector<Float> = array![1.0, 2.0, 3.0, 4.0];
+        let b = a.mapv(|x| 2.0 * x + 1.0);
+        assert_relative_eq!(
+            pearson_correlation(&a, &b).expect("series are not constant"),
+            1.0,
+            epsilon = 1e-12
+        );
+        assert_relative_eq!(
+            pearson_correlation(&a, &-&b).expect("series are not constant"),
+            -1.0,
+            epsilon = 1e-12
+        );
+
+        assert_eq!(pearson_correlation(&a, &Vector::ones(4)), None);
+        assert_eq!(pearson_correlation(&a, &Vector::zeros(3)), None);
+    }
+
+    #[test]
+    fn dominant_eigenvalue_of_diagonal_matrix() {
+        let m: Matrix<f64> = Matrix::from_diag(&array![2.0, -7.0, 5.0]);
+        let eigenvalue = dominant_eigenvalue(&m, 1000, 1e-12).expect("converges");
+        assert_relative_eq!(eigenvalue, -7.0, epsilon = 1e-6);
+    }
+
+    #[test]
+    fn dominant_eigenvalue_of_symmetric_2x2() {
+        // eigenvalues are 3 and -1, with eigenvectors [1, -1] and [1, 1]
+        let m: Matrix<f64> = array![[1.0, -2.0], [-2.0, 1.0]];
+        let eigenvalue = dominant_eigenvalue(&m, 1000, 1e-12).expect("converges");
+        assert_relative_eq!(eigenvalue, 3.0, epsilon = 1e-6);
+
+        assert_eq!(dominant_eigenvalue(&m, 0, 1e-12), None);
+        assert_eq!(
+            dominant_eigenvalue(&Matrix::<f64>::zeros((2, 3)), 100, 1e-12),
+            None
+        );
+    }
 }
@@ -0,0 +1,51 @@
+use crate::{GbpScheduleAtIteration, GbpScheduleIterator};
+
+/// Schedule replaying a user supplied pattern of steps
+/// - Unlike the other schedules, the number of internal and external iterations
+///   is given by the pattern itself, so it does not implement
+///   [`crate::GbpSchedule`]
+pub struct CustomIter {
+    steps: std::vec::IntoIter<GbpScheduleAtIteration>,
+}
+
+impl CustomIter {
+    pub fn new(steps: Vec<GbpScheduleAtIteration>) -> Self {
+        Self {
+            steps: steps.into_iter(),
+        }
+    }
+}
+
+impl std::iter::Iterator for CustomIter {
+    type Item = GbpScheduleAtIteration;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.steps.next()
+    }
+}
+
+impl GbpScheduleIterator for CustomIter {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const fn ts(internal: bool, external: bool) -> GbpScheduleAtIteration {
+        GbpScheduleAtIteration { internal, external }
+    }
+
+    #[test]
+    fn replays_pattern() {
+        let mut schedule = CustomIter::new(vec![ts(true, false), ts(false, false), ts(true, true)]);
+        assert_eq!(schedule.next(), Some(ts(true, false)));
+        assert_eq!(schedule.next(), Some(ts(false, false)));
+        assert_eq!(schedule.next(), Some(ts(true, true)));
+        assert_eq!(schedule.next(), None);
+    }
+
+    #[test]
+    fn empty_pattern() {
+        let mut schedule = CustomIter::new(vec![]);
+        assert_eq!(schedule.next(), None);
+    }
+}
@@ -1,4 +1,5 @@
 mod centered;
+mod custom;
 mod half_beginning_half_end;
 mod interleave_evenly;
 mod late_as_possible;
@@ -7,6 +8,7 @@ mod soon_as_possible;
 // use std::num::NonZeroUsize;
 
 pub use centered::*;
+pub use custom::*;
 pub use half_beginning_half_end::*;
 pub use interleave_evenly::*;
 pub use late_as_possible::*;
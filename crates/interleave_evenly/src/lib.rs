@@ -23,16 +23,17 @@
 /// # Panics
 ///
 /// This function will panic if `N` is not greater than 0
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct InterleaveEvenly<const N: usize> {
-    /// The accumulated state of each counter. Starts with `[0.0; N]`
-    state: [f32; N],
-    /// How much to increment the state of each counter whenever it is < self.i
-    increments: [f32; N],
+    /// The accumulated state of each counter, scaled by `times[k]` so it can be
+    /// compared exactly with integers. Starts with `[0; N]`
+    state: [usize; N],
+    /// How many `true`s each counter emits
+    times: [usize; N],
     /// The largest of all the counters
-    max: usize,
+    max:   usize,
     /// The current iteration index, starts at 1
-    i: usize,
+    i:     usize,
 }
 
 impl<const N: usize> InterleaveEvenly<N> {
@@ -43,16 +44,33 @@ impl<const N: usize> InterleaveEvenly<N> {
     /// Panics if `N` == 0
     pub fn new(times: [usize; N]) -> Self {
         let max: usize = times.iter().max().copied().expect("N > 0");
-        #[allow(clippy::cast_precision_loss)]
-        let increments = std::array::from_fn(|i| max as f32 / times[i] as f32);
 
         Self {
-            state: [0.0; N],
-            increments,
+            state: [0; N],
+            times,
             max,
             i: 1,
         }
     }
+
+    /// Collect all remaining steps of the schedule, one row per step
+    /// - Useful to inspect or render a whole schedule at once
+    #[must_use]
+    pub fn to_matrix(self) -> Vec<[bool; N]> {
+        self.collect()
+    }
+
+    /// How many `true`s each counter will emit over the remaining iterations
+    /// For a new iterator this is the total, which equals the `times` it was
+    /// created with
+    pub fn total_trues(&self) -> [usize; N] {
+        self.clone().fold([0; N], |mut trues, ready| {
+            for (count, ready) in trues.iter_mut().zip(ready) {
+                *count += usize::from(ready);
+            }
+            trues
+        })
+    }
 }
 
 impl<const N: usize> std::iter::Iterator for InterleaveEvenly<N> {
@@ -63,10 +81,11 @@ impl<const N: usize> std::iter::Iterator for InterleaveEvenly<N> {
             return None;
         }
 
+        // Bresenham style: counter `k` is ready when `state[k] / times[k] < i`,
+        // compared without division so no rounding errors can accumulate
         let ready = std::array::from_fn(|k| {
-            #[allow(clippy::cast_precision_loss)]
-            if self.state[k] < self.i as f32 {
-                self.state[k] += self.increments[k];
+            if self.state[k] < self.i * self.times[k] {
+                self.state[k] += self.max;
                 true
             } else {
                 false
@@ -79,6 +98,64 @@ impl<const N: usize> std::iter::Iterator for InterleaveEvenly<N> {
     }
 }
 
+/// Iterate over the counters in an evenly interleaved fashion, with each
+/// counter shifted by a phase
+/// - Like [`InterleaveEvenly`], but `phases[k]` delays the first `true` of
+///   counter `k` by that fraction of its period, so counters with equal rates
+///   can be staggered instead of all starting at the first step
+///
+/// # Examples
+///
+/// ```
+/// use interleave_evenly::InterleaveEvenlyPhased;
+/// let mut iter = InterleaveEvenlyPhased::new([4, 2, 2], [0.0, 0.0, 0.5]);
+/// assert_eq!(Some([true, true, false]), iter.next());
+/// assert_eq!(Some([true, false, true]), iter.next());
+/// assert_eq!(Some([true, true, false]), iter.next());
+/// assert_eq!(Some([true, false, true]), iter.next());
+/// assert_eq!(None, iter.next());
+/// ```
+#[derive(Debug)]
+pub struct InterleaveEvenlyPhased<const N: usize>(InterleaveEvenly<N>);
+
+impl<const N: usize> InterleaveEvenlyPhased<N> {
+    /// Create a new `InterleaveEvenlyPhased` iterator
+    /// - `phases[k]` is the fraction of counter `k`'s period its first `true`
+    ///   is delayed by
+    ///
+    /// # Panics
+    ///
+    /// Panics if `N` == 0, or any phase is not in the range [0.0, 1.0)
+    pub fn new(times: [usize; N], phases: [f32; N]) -> Self {
+        assert!(
+            phases.iter().all(|phase| (0.0..1.0).contains(phase)),
+            "all phases must be in the range [0.0, 1.0), got {phases:?}"
+        );
+        let mut inner = InterleaveEvenly::new(times);
+        for (state, phase) in inner.state.iter_mut().zip(phases) {
+            // a full period is `max` in the scaled state
+            #[allow(
+                clippy::cast_precision_loss,
+                clippy::cast_possible_truncation,
+                clippy::cast_sign_loss
+            )]
+            let offset = (phase * inner.max as f32) as usize;
+            *state = offset.min(inner.max.saturating_sub(1));
+        }
+
+        Self(inner)
+    }
+}
+
+impl<const N: usize> std::iter::Iterator for InterleaveEvenlyPhased<N> {
+    type Item = [bool; N];
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use pretty_assertions::assert_eq;
@@ -145,4 +222,83 @@ mod tests {
         assert_eq!(Some([false, true, false]), iter.next());
         assert_eq!(None, iter.next());
     }
+
+    #[test]
+    fn opposite_phases_rarely_coincide() {
+        let (a, b) = (10, 5);
+
+        let aligned = InterleaveEvenlyPhased::new([a, b, b], [0.0, 0.0, 0.0])
+            .filter(|[_, b1, b2]| *b1 && *b2)
+            .count();
+        assert_eq!(aligned, b);
+
+        let steps = InterleaveEvenlyPhased::new([a, b, b], [0.0, 0.0, 0.5]).collect::<Vec<_>>();
+        assert_eq!(steps.len(), a);
+        let coinciding = steps.iter().filter(|[_, b1, b2]| *b1 && *b2).count();
+        assert_eq!(coinciding, 0);
+        // the phase only shifts the trues, it does not change how many there are
+        for k in 0..3 {
+            let trues = steps.iter().filter(|step| step[k]).count();
+            assert_eq!(trues, [a, b, b][k]);
+        }
+    }
+
+    #[test]
+    fn total_trues_equals_times() {
+        for a in 1..=32 {
+            for b in 1..=32 {
+                assert_eq!(
+                    InterleaveEvenly::new([a, b]).total_trues(),
+                    [a, b],
+                    "times: {:?}",
+                    [a, b]
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn exact_counts_for_all_pairs() {
+        for a in 1..=64 {
+            for b in 1..=64 {
+                let steps = InterleaveEvenly::new([a, b]).collect::<Vec<_>>();
+                let max = a.max(b);
+                assert_eq!(steps.len(), max);
+
+                let trues = steps.iter().fold([0, 0], |[x, y], [p, q]| {
+                    [x + usize::from(*p), y + usize::from(*q)]
+                });
+                assert_eq!(trues, [a, b], "times: {:?}", [a, b]);
+
+                let k = usize::from(b > a);
+                assert!(steps[0][k], "first step of {:?}", [a, b]);
+                assert!(steps[max - 1][k], "last step of {:?}", [a, b]);
+            }
+        }
+    }
+
+    #[test]
+    fn zero_times_never_fires() {
+        let steps = InterleaveEvenly::new([3, 0]).collect::<Vec<_>>();
+        assert_eq!(steps, vec![[true, false]; 3]);
+        assert_eq!(InterleaveEvenly::new([0, 0]).next(), None);
+    }
+
+    #[test]
+    fn to_matrix_matches_stepping() {
+        let mut iter = InterleaveEvenly::new([10, 4]);
+        let stepped = std::iter::from_fn(|| iter.next()).collect::<Vec<_>>();
+
+        let matrix = InterleaveEvenly::new([10, 4]).to_matrix();
+        assert_eq!(matrix.len(), 10);
+        assert_eq!(matrix, stepped);
+    }
+
+    #[test]
+    fn total_trues_counts_remaining_iterations() {
+        let mut iter = InterleaveEvenly::new([10, 4]);
+        assert_eq!(iter.total_trues(), [10, 4]);
+        iter.next();
+        assert_eq!(iter.total_trues(), [9, 3]);
+    }
 }
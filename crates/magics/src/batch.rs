@@ -0,0 +1,157 @@
+//! Bevy plugin for running the simulation as a deterministic batch job, that
+//! exits after a fixed number of timesteps or when the scenario has finished
+use std::{num::NonZeroU64, time::Duration};
+
+use bevy::prelude::*;
+
+use crate::planner::{
+    robot::{GbpIterationSchedule, RobotFinishedRoute},
+    spawner::AllFormationsFinished,
+};
+
+/// **Bevy** Plugin that exits the application after `max_steps` fixed
+/// timesteps
+/// A summary of the run is printed to stdout when the application exits
+/// Exiting when all formations of the scenario have finished is left to
+/// `simulation.exit-application-on-scenario-finished`, see
+/// [`SimulationLoaderPlugin::exit_application_on_scenario_finished`](crate::simulation_loader::SimulationLoaderPlugin::exit_application_on_scenario_finished)
+#[derive(Debug, Default)]
+pub struct BatchRunPlugin {
+    /// Exit after this many `FixedUpdate` steps
+    pub max_steps: Option<NonZeroU64>,
+}
+
+impl Plugin for BatchRunPlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(BatchRun {
+            max_steps: self.max_steps,
+            steps: 0,
+            robots_finished: 0,
+            formations_finished: false,
+            first_spawned_at: Vec::new(),
+            exit_reason: None,
+        })
+        .add_systems(
+            FixedUpdate,
+            (count_steps, exit_when_max_steps_reached).chain(),
+        )
+        .add_systems(PostUpdate, record_first_spawn_positions)
+        .add_systems(
+            Update,
+            (
+                count_finished_robots,
+                record_all_formations_finished.run_if(on_event::<AllFormationsFinished>()),
+            ),
+        )
+        .add_systems(Last, print_summary.run_if(on_event::<bevy::app::AppExit>()));
+    }
+}
+
+/// **Bevy** [`Resource`] tracking the progress of a batch run
+#[derive(Debug, Resource)]
+pub struct BatchRun {
+    max_steps: Option<NonZeroU64>,
+    /// Number of `FixedUpdate` steps run so far
+    steps: u64,
+    /// Number of robots that have finished their route so far
+    robots_finished: usize,
+    /// Whether all formations of the scenario have finished
+    formations_finished: bool,
+    /// Initial positions of the first robots spawned, sorted by x then y
+    first_spawned_at: Vec<Vec2>,
+    /// Why the application is exiting, if this plugin made it exit
+    exit_reason: Option<&'static str>,
+}
+
+impl BatchRun {
+    /// Number of `FixedUpdate` steps run so far
+    #[inline]
+    pub const fn steps(&self) -> u64 {
+        self.steps
+    }
+
+    /// Number of robots that have finished their route so far
+    #[inline]
+    pub const fn robots_finished(&self) -> usize {
+        self.robots_finished
+    }
+}
+
+fn count_steps(mut batch_run: ResMut<BatchRun>) {
+    if batch_run.exit_reason.is_none() {
+        batch_run.steps += 1;
+    }
+}
+
+/// Runs right after [`count_steps`], as several fixed steps can run in a
+/// single frame, and the application only exits at the end of the frame
+fn exit_when_max_steps_reached(
+    mut batch_run: ResMut<BatchRun>,
+    mut time_fixed: ResMut<Time<Fixed>>,
+    mut time_virtual: ResMut<Time<Virtual>>,
+    mut evw_app_exit: EventWriter<bevy::app::AppExit>,
+) {
+    let Some(max_steps) = batch_run.max_steps else {
+        return;
+    };
+    if batch_run.exit_reason.is_some() || batch_run.steps < max_steps.get() {
+        return;
+    }
+
+    batch_run.exit_reason = Some("reached the maximum number of steps");
+    // skip the remaining fixed steps of this frame
+    time_fixed.discard_overstep(Duration::MAX);
+    time_virtual.pause();
+    evw_app_exit.send(bevy::app::AppExit);
+}
+
+/// Robots are spawned with commands in `Update`, so in `PostUpdate` their
+/// transform is still the initial one
+fn record_first_spawn_positions(
+    mut batch_run: ResMut<BatchRun>,
+    q_spawned_robots: Query<&Transform, Added<GbpIterationSchedule>>,
+) {
+    if !batch_run.first_spawned_at.is_empty() {
+        return;
+    }
+
+    batch_run.first_spawned_at = q_spawned_robots
+        .iter()
+        .map(|transform| transform.translation.xz())
+        .collect();
+    batch_run
+        .first_spawned_at
+        .sort_by(|a, b| a.x.total_cmp(&b.x).then(a.y.total_cmp(&b.y)));
+}
+
+fn count_finished_robots(
+    mut batch_run: ResMut<BatchRun>,
+    mut evr_robot_finished_route: EventReader<RobotFinishedRoute>,
+) {
+    batch_run.robots_finished += evr_robot_finished_route.read().count();
+}
+
+fn record_all_formations_finished(mut batch_run: ResMut<BatchRun>) {
+    batch_run.formations_finished = true;
+}
+
+fn print_summary(batch_run: Res<BatchRun>, mut printed: Local<bool>) {
+    if *printed {
+        return;
+    }
+    *printed = true;
+
+    let reason = batch_run
+        .exit_reason
+        .unwrap_or(if batch_run.formations_finished {
+            "all formations finished"
+        } else {
+            "the application exited"
+        });
+    println!("ending batch run, reason: {reason}");
+    println!("steps run:       {}", batch_run.steps);
+    println!("robots finished: {}", batch_run.robots_finished);
+    if !batch_run.first_spawned_at.is_empty() {
+        println!("first robots spawned at: {:?}", batch_run.first_spawned_at);
+    }
+}
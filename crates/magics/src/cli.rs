@@ -39,6 +39,22 @@ pub struct Cli {
     #[arg(long, value_name = "ENVIRONMENT_TYPE", group = "dump")]
     pub dump_environment: Option<EnvironmentType>,
 
+    /// Write every [`EnvironmentType`] to `<DIR>/<name>.yaml`
+    #[arg(long, value_name = "DIR", group = "dump")]
+    pub dump_all_environments: Option<std::path::PathBuf>,
+
+    /// Check that the config file at `PATH` is valid, and exit
+    #[arg(long, value_name = "PATH", group = "validate")]
+    pub validate_config: Option<std::path::PathBuf>,
+
+    /// Check that the environment file at `PATH` is valid, and exit
+    #[arg(long, value_name = "PATH", group = "validate")]
+    pub validate_environment: Option<std::path::PathBuf>,
+
+    /// Check that the formation file at `PATH` is valid, and exit
+    #[arg(long, value_name = "PATH", group = "validate")]
+    pub validate_formation: Option<std::path::PathBuf>,
+
     // #[arg(short, long, value_name = "DIR")]
     /// Path to directory with simuliations to load. [default:
     /// ./config/scenarios]
@@ -61,6 +77,8 @@ pub struct Cli {
     pub initial_scenario: Option<String>,
 
     /// Run the app without a window for rendering the environment
+    /// Can also be enabled by setting the environment variable
+    /// `MAGICS_HEADLESS=1`
     #[arg(long, group = "display")]
     pub headless:   bool,
     /// Start the app in fullscreen mode
@@ -95,6 +113,41 @@ pub struct Cli {
     #[arg(long)]
     pub height: Option<u32>,
 
+    /// Seed for the pseudo random number generator, overrides
+    /// `simulation.prng-seed` in the config of every scenario
+    #[arg(long, value_name = "SEED")]
+    pub seed: Option<u64>,
+
+    /// Number of internal GBP iterations per timestep, overrides
+    /// `gbp.iteration-schedule.internal` in the config of every scenario
+    #[arg(long, value_name = "N")]
+    pub gbp_internal: Option<usize>,
+
+    /// Number of external GBP iterations per timestep, overrides
+    /// `gbp.iteration-schedule.external` in the config of every scenario
+    #[arg(long, value_name = "N")]
+    pub gbp_external: Option<usize>,
+
+    /// GBP iteration schedule, e.g. `interleave-evenly`, overrides
+    /// `gbp.iteration-schedule.schedule` in the config of every scenario
+    #[arg(long, value_name = "KIND", value_parser = gbp_config::GbpIterationScheduleKind::parse_cli)]
+    pub gbp_schedule: Option<gbp_config::GbpIterationScheduleKind>,
+
+    /// Exit after running this many fixed timesteps, and print a summary of
+    /// the run
+    #[arg(long, value_name = "N")]
+    pub max_steps: Option<std::num::NonZeroU64>,
+
+    /// Exit when all formations of the scenario have finished, and print a
+    /// summary of the run
+    #[arg(long)]
+    pub until_finished: bool,
+
+    /// Write aggregate metrics of the run as JSON to this file, when all
+    /// formations of the scenario have finished or the application exits
+    #[arg(long, value_name = "PATH")]
+    pub export_metrics: Option<std::path::PathBuf>,
+
     /// Record image sequences of the running game, that later can be
     /// concatenated into a video with `ffmpeg`
     #[arg(long)]
@@ -128,11 +181,17 @@ impl Cli {
     }
 }
 
+/// Environment variable that enables headless mode when set to `1`, as an
+/// alternative to `--headless`
+pub const HEADLESS_ENV_VAR: &str = "MAGICS_HEADLESS";
+
 /// Parse arguments from `std::env::args`
 #[cfg(not(target_arch = "wasm32"))]
 #[must_use]
 pub fn parse_arguments() -> Cli {
-    Cli::parse()
+    let mut cli = Cli::parse();
+    cli.headless |= std::env::var(HEADLESS_ENV_VAR).is_ok_and(|value| value == "1");
+    cli
 }
 
 #[must_use]
@@ -74,7 +74,10 @@ fn cursor_to_ground_plane(
     let ground_transform = q_plane.single();
 
     // There is only one primary window, so we can similarly get it from the query:
-    let window = q_window.single();
+    // there is no primary window when running headless
+    let Ok(window) = q_window.get_single() else {
+        return;
+    };
 
     // Check if the cursor is inside the window and get its position
     let Some(cursor_position) = window.cursor_position() else {
@@ -8,7 +8,7 @@ use bevy::{
 };
 use bevy_infinite_grid::{InfiniteGridBundle, InfiniteGridPlugin, InfiniteGridSettings};
 use catppuccin::Flavour;
-use gbp_config::{self, Config};
+use gbp_config::{self, Config, DrawSetting};
 use gbp_environment::Environment;
 
 use crate::{
@@ -144,7 +144,7 @@ fn spawn_sdf_map_representation(
         ..default()
     });
 
-    let visibility = if config.visualisation.draw.sdf {
+    let visibility = if config.visualisation.draw.get(DrawSetting::Sdf) {
         Visibility::Visible
     } else {
         Visibility::Hidden
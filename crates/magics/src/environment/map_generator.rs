@@ -319,13 +319,7 @@ fn build_obstacles(
                 //             _ => std::f32::consts::FRAC_PI_2,
                 //         };
 
-                use std::f32::consts::{FRAC_PI_2, PI};
-                let rotation_offset = PI
-                    + match polygon.sides {
-                        4 => 0.0,
-                        n if n % 2 != 0 => FRAC_PI_2,
-                        _ => -FRAC_PI_2,
-                    };
+                let rotation_offset = polygon.collider_rotation_offset();
                 // let rotation_offset = PI
                 //     + match polygon.sides { n if n % 2 != 0 => PI / n as f32, _ => 0.0, // n
                 //       => FRAC_PI_2 / n as f32,
@@ -487,7 +481,7 @@ fn build_obstacles(
                     mesh,
                     material: materials.obstacle.clone(),
                     transform,
-                    visibility: if config.visualisation.draw.generated_map {
+                    visibility: if config.visualisation.draw.get(DrawSetting::GeneratedMap) {
                         Visibility::Visible
                     } else {
                         Visibility::Hidden
@@ -1262,7 +1256,11 @@ fn build_tile_grid(
                                 mesh: meshes.add(*cuboid),
                                 transform: *transform,
                                 material: materials.obstacle.clone(),
-                                visibility: if config.visualisation.draw.generated_map {
+                                visibility: if config
+                                    .visualisation
+                                    .draw
+                                    .get(DrawSetting::GeneratedMap)
+                                {
                                     Visibility::Visible
                                 } else {
                                     Visibility::Hidden
@@ -494,8 +494,8 @@ fn export(
 
         let gbp = GbpData {
             iterations: GbpIterationData {
-                internal: config.gbp.iteration_schedule.internal,
-                external: config.gbp.iteration_schedule.external,
+                internal: config.gbp.iteration_schedule.internal_iterations(),
+                external: config.gbp.iteration_schedule.external_iterations(),
             },
         };
 
@@ -126,6 +126,36 @@ pub trait Factor: std::fmt::Display {
 
         jacobian
     }
+
+    /// Numerical jacobian of the measurement function at
+    /// `linearisation_point`, computed with central finite differences using a
+    /// step size of `eps`.
+    /// Used to validate the analytic jacobian returned by
+    /// [`Factor::jacobian`].
+    #[must_use]
+    fn jacobian_numeric(
+        &self,
+        state: &FactorState,
+        linearisation_point: &Vector<Float>,
+        eps: Float,
+    ) -> Matrix<Float> {
+        let mut x = linearisation_point.clone();
+        let h0 = self.measure(state, &x).value;
+        let mut jacobian = Matrix::<Float>::zeros((h0.len(), x.len()));
+
+        for i in 0..x.len() {
+            x[i] += eps;
+            let h_plus = self.measure(state, &x).value;
+            x[i] -= 2.0 * eps;
+            let h_minus = self.measure(state, &x).value;
+            x[i] += eps; // reset the perturbation
+
+            let derivatives = (&h_plus - &h_minus) / (2.0 * eps);
+            jacobian.column_mut(i).assign(&derivatives);
+        }
+
+        jacobian
+    }
 }
 
 /// Factor node in the factorgraph
@@ -739,3 +769,102 @@ impl FactorGraphNode for FactorNode {
 //         write!(f, "node_index: {:?}", self.node_index)?;
 //     }
 // }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Assert that the analytic jacobian of `factor` at `linearisation_point`
+    /// matches the numerical one within `tolerance`
+    fn assert_jacobian_matches(
+        factor: &impl Factor,
+        state: &FactorState,
+        linearisation_point: &Vector<Float>,
+        eps: Float,
+        tolerance: Float,
+    ) {
+        let analytic = factor.jacobian(state, linearisation_point);
+        let numeric = factor.jacobian_numeric(state, linearisation_point, eps);
+
+        assert_eq!(analytic.shape(), numeric.shape());
+        assert!(
+            analytic
+                .iter()
+                .zip(numeric.iter())
+                .all(|(a, n)| (a - n).abs() <= tolerance),
+            "jacobian of {} does not match\nanalytic:\n{}\nnumeric:\n{}",
+            factor.name(),
+            analytic,
+            numeric
+        );
+    }
+
+    #[test]
+    fn dynamic_factor_jacobian_matches_numeric() {
+        let mut state =
+            FactorState::new(Vector::<Float>::zeros(DOFS), 0.1, DynamicFactor::NEIGHBORS);
+        let factor = DynamicFactor::new(&mut state, 0.25);
+        let linearisation_point = array![1.0, -2.0, 0.5, 0.25, 1.2, -1.9, 0.4, 0.3];
+
+        assert_jacobian_matches(&factor, &state, &linearisation_point, 1e-4, 1e-6);
+    }
+
+    #[test]
+    fn obstacle_factor_jacobian_matches_numeric() {
+        // sdf that gets darker from left to right, with one pixel per world unit
+        #[allow(clippy::cast_possible_truncation)]
+        let sdf = SdfImage::from_fn(256, 256, |x, _| image::Rgb([x as u8; 3]));
+        let world_size = obstacle::WorldSize {
+            width:  256.0,
+            height: 256.0,
+        };
+        let factor = ObstacleFactor::new(sdf, world_size);
+        let state = FactorState::new(array![0.0], 0.01, ObstacleFactor::NEIGHBORS);
+        let linearisation_point = array![0.5, 0.0, 0.0, 0.0];
+
+        // a step of one pixel, as the sdf is piecewise constant within a pixel
+        let eps = factor.jacobian_delta();
+        assert_jacobian_matches(&factor, &state, &linearisation_point, eps, 1e-9);
+    }
+
+    #[test]
+    fn interrobot_factor_jacobian_matches_numeric() {
+        let factor = InterRobotFactor::new(
+            StrictlyPositiveFinite::<Float>::new(1.0).expect("1.0 is positive and finite"),
+            ExternalVariableId::new(
+                FactorGraphId::PLACEHOLDER,
+                crate::factorgraph::factorgraph::VariableIndex(NodeIndex::new(0)),
+            ),
+            None,
+            NonZeroUsize::MIN,
+        );
+        let state = FactorState::new(array![0.0], 0.01, InterRobotFactor::NEIGHBORS);
+
+        // within the safety distance of 2.2
+        let linearisation_point = array![0.5, 0.3, 1.0, 0.0, -0.2, 0.1, -1.0, 0.5];
+        assert_jacobian_matches(&factor, &state, &linearisation_point, 1e-6, 1e-6);
+
+        // beyond the safety distance the factor has no effect
+        let linearisation_point = array![3.0, 0.3, 1.0, 0.0, -0.2, 0.1, -1.0, 0.5];
+        assert_jacobian_matches(&factor, &state, &linearisation_point, 1e-6, 1e-6);
+    }
+
+    #[test]
+    fn tracking_factor_jacobian_matches_numeric() {
+        let factor = TrackingFactor::new(Some(
+            min_len_vec::TwoOrMore::new(vec![Vec2::ZERO, Vec2::new(10.0, 0.0)])
+                .expect("two waypoints"),
+        ));
+        let state = FactorState::new(array![0.0], 0.01, TrackingFactor::NEIGHBORS);
+
+        // standing still beside the path, and within the attraction distance of 2.0
+        let linearisation_point = array![3.0, 0.5, 0.0, 0.0];
+        let _ = factor.measure(&state, &linearisation_point);
+        assert_jacobian_matches(&factor, &state, &linearisation_point, 1e-6, 1e-6);
+
+        // beyond the attraction distance the measurement is saturated
+        let linearisation_point = array![3.0, 2.5, 0.0, 0.0];
+        let _ = factor.measure(&state, &linearisation_point);
+        assert_jacobian_matches(&factor, &state, &linearisation_point, 1e-6, 1e-6);
+    }
+}
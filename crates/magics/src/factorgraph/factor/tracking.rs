@@ -174,7 +174,7 @@ impl Factor for TrackingFactor {
         linearisation_point: &Vector<Float>,
     ) -> Cow<'_, Matrix<Float>> {
         let last_measurement = self.last_measurement.lock().unwrap().get();
-        let h0 = array![last_measurement.value];
+        let h0 = last_measurement.value;
 
         let m = last_measurement.pos;
         let pos = linearisation_point.slice(s![..DOFS / 2]).to_owned();
@@ -183,10 +183,15 @@ impl Factor for TrackingFactor {
         let temp = array![m.x as Float, m.y as Float];
         let x_diff = pos - temp;
 
-        let mut jacobian = Matrix::<Float>::zeros((h0.len(), DOFS));
-        jacobian
-            .slice_mut(s![0, ..DOFS / 2])
-            .assign(&(1.0 / h0 * &x_diff));
+        let mut jacobian = Matrix::<Float>::zeros((1, DOFS));
+        // The measurement is the distance to the measurement point divided by the
+        // attraction distance, and saturates at 1.0 beyond it
+        if h0 < 1.0 {
+            let attraction_distance = Float::from(self.tracking.config.attraction_distance);
+            jacobian
+                .slice_mut(s![0, ..DOFS / 2])
+                .assign(&(1.0 / (h0 * attraction_distance.powi(2)) * &x_diff));
+        }
 
         // pretty_print_matrix!(&jacobian);
 
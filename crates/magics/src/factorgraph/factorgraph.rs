@@ -1525,6 +1525,76 @@ impl graphviz::ExportGraph for FactorGraph {
     }
 }
 
+impl FactorGraph {
+    /// Export the factorgraph in the Graphviz DOT format.
+    /// Variables and factors are emitted as nodes, and the connections between
+    /// them as edges. Edges from interrobot factors to the variable they are
+    /// connected to in another factorgraph, are styled with the
+    /// active/inactive interrobot edge attributes of `attrs`.
+    #[must_use]
+    pub fn to_dot(&self, attrs: &gbp_config::GraphvizSection) -> String {
+        use graphviz::ExportGraph;
+
+        let (nodes, edges) = self.export_graph();
+        let id = self.id;
+
+        let mut dot = String::with_capacity(1024);
+        let mut append_line = |line: &str| {
+            dot.push_str(line);
+            dot.push('\n');
+        };
+
+        append_line(&format!(r#"graph "{id:?}" {{"#));
+        append_line("  node [style=filled];");
+
+        for node in &nodes {
+            append_line(&format!(
+                r#"  "{id:?}_{}" [label="{}", fillcolor="{}", shape={}, width="{}"];"#,
+                node.index,
+                node.label(),
+                node.color(),
+                node.shape(),
+                node.width()
+            ));
+        }
+
+        for edge in &edges {
+            append_line(&format!(
+                r#"  "{id:?}_{}" -- "{id:?}_{}";"#,
+                edge.from, edge.to
+            ));
+        }
+
+        for node in &nodes {
+            let graphviz::NodeKind::InterRobotFactor {
+                active,
+                external_variable_id,
+            } = node.kind
+            else {
+                continue;
+            };
+
+            let edge_attrs = if active {
+                &attrs.interrobot.active
+            } else {
+                &attrs.interrobot.inactive
+            };
+            append_line(&format!(
+                r#"  "{id:?}_{}" -- "{:?}_{}" [len={}, style={}, color="{}"];"#,
+                node.index,
+                external_variable_id.factorgraph_id,
+                external_variable_id.variable_index.index(),
+                edge_attrs.len,
+                edge_attrs.style,
+                edge_attrs.color
+            ));
+        }
+
+        append_line("}");
+        dot
+    }
+}
+
 impl FactorGraph {
     pub fn change_factor_enabled(&mut self, settings: gbp_config::FactorsEnabledSection) {
         for &ix in self.factor_indices.iter() {
@@ -1608,3 +1678,45 @@ impl FactorGraph {
     //    }
     //}
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::factorgraph::DOFS;
+
+    #[test]
+    fn to_dot_contains_nodes_and_edges() {
+        let id = Entity::from_raw(7);
+        let mut factorgraph = FactorGraph::new(id);
+
+        let variable = || {
+            VariableNode::new(
+                id,
+                Vector::<Float>::zeros(DOFS),
+                Matrix::<Float>::eye(DOFS),
+                DOFS,
+            )
+        };
+        let v0 = factorgraph.add_variable(variable());
+        let v1 = factorgraph.add_variable(variable());
+        let f = factorgraph.add_factor(FactorNode::new_dynamic_factor(
+            id,
+            0.1,
+            Vector::<Float>::zeros(DOFS),
+            0.25,
+            true,
+        ));
+        factorgraph.add_internal_edge(VariableId::new(id, v0), FactorId::new(id, f));
+        factorgraph.add_internal_edge(VariableId::new(id, v1), FactorId::new(id, f));
+
+        let dot = factorgraph.to_dot(&gbp_config::GraphvizSection::default());
+
+        let [v0, v1, f] = [v0.0.index(), v1.0.index(), f.0.index()];
+        assert!(dot.starts_with(&format!(r#"graph "{id:?}" {{"#)));
+        assert!(dot.contains(&format!(r#""{id:?}_{v0}" [label="v{v0}""#)));
+        assert!(dot.contains(&format!(r#""{id:?}_{v1}" [label="v{v1}""#)));
+        assert!(dot.contains(&format!(r#""{id:?}_{f}" [label="fd""#)));
+        assert!(dot.contains(&format!(r#""{id:?}_{v0}" -- "{id:?}_{f}";"#)));
+        assert!(dot.trim_end().ends_with('}'));
+    }
+}
@@ -23,6 +23,17 @@ impl Node {
     pub const fn width(&self) -> f64 {
         self.kind.width()
     }
+
+    /// Returns the label of the node
+    pub fn label(&self) -> String {
+        match self.kind {
+            NodeKind::Variable { .. } => format!("v{}", self.index),
+            NodeKind::InterRobotFactor { .. } => "fr".to_string(),
+            NodeKind::DynamicFactor => "fd".to_string(),
+            NodeKind::ObstacleFactor => "fo".to_string(),
+            NodeKind::TrackingFactor => "ft".to_string(),
+        }
+    }
 }
 
 pub enum NodeKind {
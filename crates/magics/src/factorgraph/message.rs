@@ -84,6 +84,25 @@ impl Message {
         self.payload.is_none()
     }
 
+    /// Returns the degrees of freedom of the message i.e. the length of its
+    /// information vector, or `0` if the message is empty.
+    #[inline]
+    pub fn dofs(&self) -> usize {
+        self.payload
+            .as_ref()
+            .map_or(0, |payload| payload.information_vector.len())
+    }
+
+    /// Returns `true` if every entry of the information vector and the
+    /// precision matrix is zero, i.e. the message carries no information
+    /// and can be skipped. An empty message is not zero.
+    pub fn is_zero(&self) -> bool {
+        self.payload.as_ref().is_some_and(|payload| {
+            payload.information_vector.iter().all(|&x| x == 0.0)
+                && payload.precision_matrix.iter().all(|&x| x == 0.0)
+        })
+    }
+
     /// Take the inner `MultivariateNormal` from the message.
     /// Leaving the message in an empty state.
     #[inline]
@@ -215,3 +234,35 @@ pub type MessagesToFactors = BTreeMap<FactorId, Message>;
 /// stored in a consistent order This is necessary for the **gbpplanner**
 /// algorithm to work correctly.
 pub type MessagesToVariables = BTreeMap<VariableId, Message>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn zeroed_message_is_zero() {
+        let message = Message::zero();
+        assert!(message.is_zero());
+        assert_eq!(message.dofs(), DOFS);
+    }
+
+    #[test]
+    fn message_with_information_is_not_zero() {
+        let mut information_vector = Vector::<Float>::zeros(DOFS);
+        information_vector[0] = 1.0;
+        let message = Message::new(
+            InformationVec(information_vector),
+            PrecisionMatrix(Matrix::<Float>::eye(DOFS)),
+            Mean(Vector::<Float>::zeros(DOFS)),
+        );
+        assert!(!message.is_zero());
+        assert_eq!(message.dofs(), DOFS);
+    }
+
+    #[test]
+    fn empty_message_has_no_dofs_and_is_not_zero() {
+        let message = Message::empty();
+        assert!(!message.is_zero());
+        assert_eq!(message.dofs(), 0);
+    }
+}
@@ -236,13 +236,7 @@ fn export_factorgraphs_as_graphviz(
                 _ => None,
             };
 
-            let label = match node.kind {
-                NodeKind::Variable { .. } => format!("v{}", node.index),
-                NodeKind::InterRobotFactor { .. } => "fr".to_string(),
-                NodeKind::DynamicFactor => "fd".to_string(),
-                NodeKind::ObstacleFactor => "fo".to_string(),
-                NodeKind::TrackingFactor => "ft".to_string(),
-            };
+            let label = node.label();
 
             let line = {
                 let mut line = String::with_capacity(32);
@@ -565,12 +559,16 @@ fn general_actions_system(
     if action_state.just_pressed(&GeneralAction::SaveSettings) {
         evw_save_settings.send(SaveSettings);
         let toast = ToastEvent {
+            id:      ToastId::new(),
             caption: "saved settings to config.toml".to_string(),
             options: ToastOptions {
                 duration: Some(std::time::Duration::from_millis(500)),
                 level: ToastLevel::Success,
                 show_progress_bar: false,
                 closable: false,
+                dedup_key: None,
+                anchor: None,
+                progress: None,
             },
         };
         evw_toast.send(toast);
@@ -1,6 +1,7 @@
 #![feature(iter_repeat_n)]
 //! The main entry point of the simulation.
 pub(crate) mod asset_loader;
+mod batch;
 mod bevy_utils;
 pub mod cli;
 pub mod despawn_entity_after;
@@ -9,6 +10,7 @@ mod environment;
 mod factorgraph;
 pub mod goal_area;
 mod input;
+mod metrics;
 mod moveable_object;
 mod movement;
 pub(crate) mod pause_play;
@@ -36,7 +38,9 @@ static ALLOC: mimalloc::MiMalloc = mimalloc::MiMalloc;
 
 use std::{path::Path, time::Duration};
 
+use anyhow::Context;
 use bevy::{
+    app::ScheduleRunnerPlugin,
     asset::AssetMetaCheck,
     input::common_conditions::input_just_pressed,
     prelude::*,
@@ -45,10 +49,12 @@ use bevy::{
         render_resource::{
             Extent3d, TextureDescriptor, TextureDimension, TextureFormat, TextureUsages,
         },
+        settings::WgpuSettings,
         RenderPlugin,
     },
     time::common_conditions::once_after_real_delay,
-    window::{PrimaryWindow, WindowMode, WindowResolution},
+    window::{ExitCondition, PrimaryWindow, WindowMode, WindowResolution},
+    winit::WinitPlugin,
 };
 use bevy_image_export::{
     ImageExportBundle, ImageExportPlugin, ImageExportSettings, ImageExportSource,
@@ -63,6 +69,7 @@ use gbp_config::{read_config, Config, FormationGroup};
 // use config::{environment::EnvironmentType, Environment};
 use gbp_environment::{Environment, EnvironmentType};
 use magics::AppState;
+use strum::IntoEnumIterator;
 
 use crate::cli::DumpDefault;
 
@@ -183,14 +190,7 @@ fn main() -> anyhow::Result<()> {
     }
     // dump_environment
     if let Some(dump_environment) = cli.dump_environment {
-        let env = match dump_environment {
-            EnvironmentType::Intersection => Environment::intersection(),
-            EnvironmentType::Circle => Environment::circle(),
-            EnvironmentType::Intermediate => Environment::intermediate(),
-            EnvironmentType::Complex => Environment::complex(),
-            EnvironmentType::Maze => Environment::maze(),
-            EnvironmentType::Test => Environment::test(),
-        };
+        let env = dump_environment.environment();
 
         let yaml = serde_yaml::to_string(&env)?;
         let stdout_is_a_terminal = atty::is(atty::Stream::Stdout);
@@ -208,6 +208,43 @@ fn main() -> anyhow::Result<()> {
         return Ok(());
     }
 
+    if let Some(path) = cli.validate_config {
+        let contents = std::fs::read_to_string(&path)
+            .with_context(|| format!("failed to read {}", path.display()))?;
+        let (_, warnings) = Config::parse_with_warnings(&contents)
+            .with_context(|| format!("{} is not a valid config", path.display()))?;
+        for warning in warnings {
+            eprintln!("{}: {warning}", "warning".yellow().bold());
+        }
+        println!("{}: {}", path.display(), "OK".green().bold());
+        return Ok(());
+    }
+
+    if let Some(path) = cli.validate_environment {
+        Environment::from_file(&path)
+            .with_context(|| format!("{} is not a valid environment", path.display()))?;
+        println!("{}: {}", path.display(), "OK".green().bold());
+        return Ok(());
+    }
+
+    if let Some(path) = cli.validate_formation {
+        FormationGroup::from_yaml_file(&path)
+            .with_context(|| format!("{} is not a valid formation", path.display()))?;
+        println!("{}: {}", path.display(), "OK".green().bold());
+        return Ok(());
+    }
+
+    if let Some(dir) = cli.dump_all_environments {
+        std::fs::create_dir_all(&dir)?;
+        for env_type in EnvironmentType::iter() {
+            let path = dir.join(format!("{}.yaml", env_type.as_ref()));
+            std::fs::write(&path, serde_yaml::to_string(&env_type.environment())?)?;
+            eprintln!("wrote {}", path.display());
+        }
+
+        return Ok(());
+    }
+
     if cli.list_scenarios {
         let scenario_dir = Path::new("./config/scenarios");
         assert!(scenario_dir.exists());
@@ -388,43 +425,118 @@ fn main() -> anyhow::Result<()> {
     let export_plugin = ImageExportPlugin::default();
     let export_threads = export_plugin.threads.clone();
 
-    app
-        //.add_plugins(default_plugins)
-        // bevy builtin plugins
-        .add_plugins(DefaultPlugins
-            .set(window_plugin)
-            .set(image_plugin)
-            .set(RenderPlugin {
-                                    synchronous_pipeline_compilation: true,
-                                    ..default()
-            })
+    if cli.headless {
+        info!("running headless");
+        // Keep the window and render plugins, so assets like meshes and
+        // materials can still be created, but without a window, a winit event
+        // loop or a gpu backend
+        app.add_plugins(
+            DefaultPlugins
+                .set(WindowPlugin {
+                    primary_window: None,
+                    exit_condition: ExitCondition::DontExit,
+                    close_when_requested: false,
+                })
+                .set(image_plugin)
+                .set(RenderPlugin {
+                    render_creation: WgpuSettings {
+                        backends: None,
+                        ..default()
+                    }
+                    .into(),
+                    synchronous_pipeline_compilation: true,
+                })
+                .disable::<WinitPlugin>(),
         )
-        // third-party plugins
-        .add_plugins((
-            bevy_egui::EguiPlugin,
-            bevy_mod_picking::DefaultPickingPlugins,
-        ))
-
-        // our plugins
-        .add_plugins((
-            // simulation_loader::SimulationLoaderPlugin::default(),
-            despawn_entity_after::DespawnEntityAfterPlugin,
-            simulation_loader::SimulationLoaderPlugin::new(true, cli.initial_scenario.clone()),
-            pause_play::PausePlayPlugin::default(),
-            theme::ThemePlugin,
-            asset_loader::AssetLoaderPlugin,
-            environment::EnvironmentPlugin,
-            movement::MovementPlugin,
-            input::InputPlugin,
-            ui::EguiInterfacePlugin,
-            planner::PlannerPlugin,
-            bevy_notify::NotifyPlugin::default(),
-            export::ExportPlugin::default(),
-            bevy_fullscreen::ToggleFullscreenPlugin::default(),
-            goal_area::GoalAreaPlugin,
-        ))
-        .add_systems(Update, draw_coordinate_system.run_if(input_just_pressed(KeyCode::F1)))
-        .add_systems(PostUpdate, end_simulation.run_if(virtual_time_exceeds_max_time));
+        .add_plugins(ScheduleRunnerPlugin::run_loop(Duration::from_secs_f64(
+            1.0 / 60.0,
+        )))
+        // sent by our plugins, but normally registered by the skipped
+        // `NotifyPlugin`
+        .add_event::<bevy_notify::ToastEvent>()
+        .add_event::<bevy_notify::DismissAllToasts>()
+        // read by our plugins, but normally registered by the skipped
+        // `InputPlugin`
+        .add_event::<input::DrawSettingsEvent>();
+    } else {
+        app
+            // bevy builtin plugins
+            .add_plugins(DefaultPlugins
+                .set(window_plugin)
+                .set(image_plugin)
+                .set(RenderPlugin {
+                                        synchronous_pipeline_compilation: true,
+                                        ..default()
+                })
+            )
+            // third-party plugins
+            .add_plugins((
+                bevy_egui::EguiPlugin,
+                bevy_mod_picking::DefaultPickingPlugins,
+            ));
+    }
+
+    let mut simulation_loader_plugin =
+        simulation_loader::SimulationLoaderPlugin::new(true, cli.initial_scenario.clone());
+    if let Some(seed) = cli.seed {
+        simulation_loader_plugin = simulation_loader_plugin.prng_seed(seed);
+    }
+    if cli.until_finished {
+        simulation_loader_plugin =
+            simulation_loader_plugin.exit_application_on_scenario_finished(true);
+    }
+    let gbp_iteration_schedule_overrides = gbp_config::GbpIterationScheduleOverrides {
+        internal: cli.gbp_internal,
+        external: cli.gbp_external,
+        schedule: cli.gbp_schedule.clone(),
+    };
+    if !gbp_iteration_schedule_overrides.is_empty() {
+        simulation_loader_plugin =
+            simulation_loader_plugin.gbp_iteration_schedule(gbp_iteration_schedule_overrides);
+    }
+
+    // our plugins
+    app.add_plugins((
+        // simulation_loader::SimulationLoaderPlugin::default(),
+        despawn_entity_after::DespawnEntityAfterPlugin,
+        simulation_loader_plugin,
+        pause_play::PausePlayPlugin::default(),
+        theme::ThemePlugin,
+        asset_loader::AssetLoaderPlugin,
+        environment::EnvironmentPlugin,
+        movement::MovementPlugin,
+    ));
+    // interactive plugins are skipped when running headless, as there is no
+    // window to interact with
+    if !cli.headless {
+        app.add_plugins((input::InputPlugin, ui::EguiInterfacePlugin));
+    }
+    app.add_plugins(planner::PlannerPlugin);
+    if !cli.headless {
+        app.add_plugins(bevy_notify::NotifyPlugin::default());
+    }
+    app.add_plugins(export::ExportPlugin::default());
+    if !cli.headless {
+        app.add_plugins(bevy_fullscreen::ToggleFullscreenPlugin::default())
+            .add_systems(
+                Update,
+                draw_coordinate_system.run_if(input_just_pressed(KeyCode::F1)),
+            );
+    }
+    app.add_plugins(goal_area::GoalAreaPlugin).add_systems(
+        PostUpdate,
+        end_simulation.run_if(virtual_time_exceeds_max_time),
+    );
+
+    if cli.max_steps.is_some() || cli.until_finished {
+        app.add_plugins(batch::BatchRunPlugin {
+            max_steps: cli.max_steps,
+        });
+    }
+
+    if let Some(path) = cli.export_metrics.clone() {
+        app.add_plugins(metrics::MetricsExportPlugin { path });
+    }
 
     if let Some(schedule) = cli.schedule_graph {
         match schedule {
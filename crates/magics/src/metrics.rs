@@ -0,0 +1,147 @@
+//! Bevy plugin for exporting aggregate metrics of a simulation run as JSON,
+//! when all formations of the scenario have finished
+use std::collections::HashMap;
+
+use bevy::prelude::*;
+
+use crate::planner::{
+    collisions::resources::CollisionStats,
+    robot::{RobotFinishedRoute, RobotId},
+    spawner::AllFormationsFinished,
+    tracking::PositionTracker,
+};
+
+/// **Bevy** Plugin that writes the [`Metrics`] of the simulation to `path`
+/// when all formations have finished
+/// If the application exits before that, e.g. at the end of a batch run, the
+/// metrics gathered so far are written instead
+#[derive(Debug)]
+pub struct MetricsExportPlugin {
+    /// File the metrics are written to
+    pub path: std::path::PathBuf,
+}
+
+impl Plugin for MetricsExportPlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(MetricsExport {
+            path: self.path.clone(),
+            path_lengths: HashMap::new(),
+            written: false,
+        })
+        .add_systems(
+            Update,
+            (
+                record_path_lengths_of_finished_robots,
+                export_metrics.run_if(on_event::<AllFormationsFinished>()),
+            )
+                .chain(),
+        )
+        .add_systems(
+            Last,
+            export_metrics.run_if(on_event::<bevy::app::AppExit>()),
+        );
+    }
+}
+
+/// Aggregate metrics of a simulation run
+#[derive(Debug, serde::Serialize)]
+pub struct Metrics {
+    /// Elapsed virtual time in seconds
+    pub makespan: f64,
+    /// Number of robots that have finished their route
+    pub robots_finished: usize,
+    /// Number of collisions between robots
+    pub robot_robot_collisions: usize,
+    /// Number of collisions between robots and the environment
+    pub robot_environment_collisions: usize,
+    /// Sum of the tracked path lengths of all robots
+    pub total_path_length: f32,
+    /// Mean of the tracked path lengths of all robots
+    pub mean_path_length: f32,
+}
+
+#[derive(Debug, Resource)]
+struct MetricsExport {
+    path: std::path::PathBuf,
+    /// Tracked path lengths of the robots that have finished their route, as
+    /// they are despawned afterwards
+    path_lengths: HashMap<RobotId, f32>,
+    /// Whether the metrics have been written, so they are only written once
+    written: bool,
+}
+
+/// Length of the polyline through the positions tracked by `tracker`
+fn path_length(tracker: &PositionTracker) -> f32 {
+    tracker
+        .positions()
+        .zip(tracker.positions().skip(1))
+        .map(|(from, to)| from.distance(to))
+        .sum()
+}
+
+fn record_path_lengths_of_finished_robots(
+    mut metrics_export: ResMut<MetricsExport>,
+    mut evr_robot_finished_route: EventReader<RobotFinishedRoute>,
+    q_position_trackers: Query<&PositionTracker>,
+) {
+    for RobotFinishedRoute(robot_id) in evr_robot_finished_route.read() {
+        if let Ok(tracker) = q_position_trackers.get(*robot_id) {
+            metrics_export
+                .path_lengths
+                .insert(*robot_id, path_length(tracker));
+        }
+    }
+}
+
+#[allow(clippy::cast_precision_loss)]
+fn export_metrics(
+    mut metrics_export: ResMut<MetricsExport>,
+    time: Res<Time<Virtual>>,
+    collision_stats: Option<Res<CollisionStats>>,
+    q_position_trackers: Query<(Entity, &PositionTracker)>,
+) {
+    if metrics_export.written {
+        return;
+    }
+    metrics_export.written = true;
+
+    let mut path_lengths = metrics_export.path_lengths.clone();
+    for (robot_id, tracker) in &q_position_trackers {
+        path_lengths.insert(robot_id, path_length(tracker));
+    }
+    let total_path_length: f32 = path_lengths.values().sum();
+    let mean_path_length = if path_lengths.is_empty() {
+        0.0
+    } else {
+        total_path_length / path_lengths.len() as f32
+    };
+
+    let metrics = Metrics {
+        makespan: time.elapsed_seconds_f64(),
+        robots_finished: metrics_export.path_lengths.len(),
+        robot_robot_collisions: collision_stats
+            .as_ref()
+            .map_or(0, |stats| stats.robot_robot()),
+        robot_environment_collisions: collision_stats
+            .as_ref()
+            .map_or(0, |stats| stats.robot_environment()),
+        total_path_length,
+        mean_path_length,
+    };
+
+    let json = match serde_json::to_string_pretty(&metrics) {
+        Ok(json) => json,
+        Err(err) => {
+            error!("failed to serialize metrics: {err}");
+            return;
+        }
+    };
+
+    match std::fs::write(&metrics_export.path, json) {
+        Ok(()) => info!("exported metrics to {}", metrics_export.path.display()),
+        Err(err) => error!(
+            "failed to export metrics to {}: {err}",
+            metrics_export.path.display()
+        ),
+    }
+}
@@ -24,7 +24,8 @@ impl RobotCollisionsPlugin {
 
 impl Plugin for RobotCollisionsPlugin {
     fn build(&self, app: &mut App) {
-        app.init_resource::<resources::RobotRobotCollisions>()
+        app.init_resource::<resources::CollisionStats>()
+            .init_resource::<resources::RobotRobotCollisions>()
             .init_resource::<resources::RobotEnvironmentCollisions>()
             .add_event::<events::RobotRobotCollision>()
             .add_event::<events::RobotEnvironmentCollision>()
@@ -65,12 +66,17 @@ impl Plugin for RobotCollisionsPlugin {
     }
 }
 
-fn clear_robot_robot_collisions(mut robot_collisions: ResMut<resources::RobotRobotCollisions>) {
+fn clear_robot_robot_collisions(
+    mut robot_collisions: ResMut<resources::RobotRobotCollisions>,
+    mut collision_stats: ResMut<resources::CollisionStats>,
+) {
     robot_collisions.clear();
+    collision_stats.clear();
 }
 
 fn update_robot_robot_collisions(
     mut robot_collisions: ResMut<resources::RobotRobotCollisions>,
+    mut collision_stats: ResMut<resources::CollisionStats>,
     robots: Query<(Entity, &Transform, &Ball), With<RobotConnections>>,
     // PERF: store bounding spheres in a Local<> vec to reuse the allocation between system calls
     mut aabbs: Local<
@@ -109,6 +115,7 @@ fn update_robot_robot_collisions(
 
         match collision_status {
             CollisionStatus::Hit => {
+                collision_stats.robot_robot += 1;
                 // ehh...
                 let r_ball = robots.get(aabbs[r].0).unwrap().2;
                 let c_ball = robots.get(aabbs[c].0).unwrap().2;
@@ -143,6 +150,29 @@ pub mod resources {
     // use bevy::prelude::*;
     use super::*;
 
+    /// Number of collisions detected since the simulation was (re)loaded
+    #[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Resource)]
+    pub struct CollisionStats {
+        pub(super) robot_robot:       usize,
+        pub(super) robot_environment: usize,
+    }
+
+    impl CollisionStats {
+        /// Number of collisions between two robots
+        pub const fn robot_robot(&self) -> usize {
+            self.robot_robot
+        }
+
+        /// Number of collisions between a robot and the environment
+        pub const fn robot_environment(&self) -> usize {
+            self.robot_environment
+        }
+
+        pub(super) fn clear(&mut self) {
+            *self = Self::default();
+        }
+    }
+
     #[derive(Resource)]
     pub struct RobotRobotCollisions {
         inner:      HashMap<(Entity, Entity), CollisionHistory>,
@@ -369,6 +399,7 @@ fn update_robot_environment_collisions(
     env_colliders: Res<Colliders>,
     robots: Query<(Entity, &Transform, &Ball), With<RobotConnections>>,
     mut robot_environment_collisions: ResMut<resources::RobotEnvironmentCollisions>,
+    mut collision_stats: ResMut<resources::CollisionStats>,
     mut aabbs: Local<
         Vec<(
             Entity,
@@ -416,6 +447,7 @@ fn update_robot_environment_collisions(
 
             match collision_status {
                 CollisionStatus::Hit => {
+                    collision_stats.robot_environment += 1;
                     let robot_aabb = ball.aabb(&robot_pos);
                     let env_aabb = env_collider.aabb();
                     let intersection = robot_aabb.intersection(&env_aabb).unwrap();
@@ -526,7 +558,11 @@ fn render_robot_robot_collisions(
             aabb.maxs.y - aabb.mins.y,
         ));
 
-        let initial_visibility = if config.visualisation.draw.robot_robot_collisions {
+        let initial_visibility = if config
+            .visualisation
+            .draw
+            .get(gbp_config::DrawSetting::RobotRobotCollisions)
+        {
             Visibility::Visible
         } else {
             Visibility::Hidden
@@ -623,7 +659,11 @@ fn render_robot_environment_collisions(
             ) * 1.05,
         );
 
-        let initial_visibility = if config.visualisation.draw.robot_environment_collisions {
+        let initial_visibility = if config
+            .visualisation
+            .draw
+            .get(gbp_config::DrawSetting::RobotEnvironmentCollisions)
+        {
             Visibility::Visible
         } else {
             Visibility::Hidden
@@ -714,3 +754,36 @@ fn record_aabb_when_robot_collides_with_environment(
         robot_collisions.record_collision(event);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn overlapping_robots_increment_robot_robot_collisions() {
+        let mut app = App::new();
+        app.init_resource::<Time<Virtual>>()
+            .init_resource::<resources::CollisionStats>()
+            .init_resource::<resources::RobotRobotCollisions>()
+            .add_event::<events::RobotRobotCollision>()
+            .add_systems(Update, update_robot_robot_collisions);
+
+        for x in [0.0, 1.0] {
+            app.world.spawn((
+                Transform::from_xyz(x, 0.0, 0.0),
+                Ball::new(1.0),
+                RobotConnections::new(),
+            ));
+        }
+        app.update();
+
+        let collision_stats = app.world.resource::<resources::CollisionStats>();
+        assert_eq!(collision_stats.robot_robot(), 1);
+        assert_eq!(collision_stats.robot_environment(), 0);
+
+        // robots still overlapping is the same collision
+        app.update();
+        let collision_stats = app.world.resource::<resources::CollisionStats>();
+        assert_eq!(collision_stats.robot_robot(), 1);
+    }
+}
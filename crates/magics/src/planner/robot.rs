@@ -56,6 +56,7 @@ impl Plugin for RobotPlugin {
             .add_event::<RobotSpawned>()
             .add_event::<RobotDespawned>()
             .add_event::<RobotFinishedRoute>()
+            .add_event::<SetDespawnOnArrival>()
             .add_event::<RobotReachedWaypoint>()
             .add_event::<GbpScheduleChanged>()
             .add_systems(PreUpdate, start_manual_step.run_if(virtual_time_is_paused))
@@ -69,7 +70,11 @@ impl Plugin for RobotPlugin {
                 (
                     on_robot_clicked,
                     on_gbp_schedule_changed,
-                    attach_despawn_timer_when_robot_finishes_route,
+                    (
+                        set_despawn_on_arrival,
+                        attach_despawn_timer_when_robot_finishes_route,
+                    )
+                        .chain(),
                     request_snapshot_of_robot_when_it_finishes_its_route,
                     progress_missions.run_if(resource_exists::<gbp_global_planner::Colliders>),
                 ),
@@ -164,6 +169,22 @@ pub struct RobotDespawned(pub RobotId);
 #[derive(Debug, Event)]
 pub struct RobotFinishedRoute(pub RobotId);
 
+/// Event that changes whether robots are despawned when they reach their final
+/// waypoint, i.e. `simulation.despawn-robot-when-final-waypoint-reached`,
+/// while the simulation is running
+#[derive(Debug, Clone, Copy, Event)]
+pub struct SetDespawnOnArrival(pub bool);
+
+fn set_despawn_on_arrival(
+    mut evr_set_despawn_on_arrival: EventReader<SetDespawnOnArrival>,
+    mut config: ResMut<Config>,
+) {
+    if let Some(SetDespawnOnArrival(despawn)) = evr_set_despawn_on_arrival.read().last() {
+        info!("setting despawn robot when final waypoint reached to {despawn}");
+        config.simulation.despawn_robot_when_final_waypoint_reached = *despawn;
+    }
+}
+
 fn attach_despawn_timer_when_robot_finishes_route(
     mut commands: Commands,
     mut evr_robot_finished_route: EventReader<RobotFinishedRoute>,
@@ -536,7 +557,15 @@ impl RobotConnections {
 #[derive(Debug, Component, Deref)]
 pub struct Ball(parry2d::shape::Ball);
 
-#[derive(Clone, Copy, Debug, Component, Resource, derive_more::Into, derive_more::From)]
+impl Ball {
+    /// Create a new `Ball` with the given radius
+    #[must_use]
+    pub fn new(radius: f32) -> Self {
+        Self(parry2d::shape::Ball::new(radius))
+    }
+}
+
+#[derive(Clone, Debug, Component, Resource, derive_more::Into, derive_more::From)]
 pub struct GbpIterationSchedule(pub gbp_config::GbpIterationSchedule);
 
 impl GbpIterationSchedule {
@@ -552,7 +581,7 @@ impl GbpIterationSchedule {
 impl FromWorld for GbpIterationSchedule {
     fn from_world(world: &mut World) -> Self {
         if let Some(config) = world.get_resource::<Config>() {
-            Self(config.gbp.iteration_schedule)
+            Self(config.gbp.iteration_schedule.clone())
         } else {
             Self(gbp_config::GbpIterationSchedule::default())
         }
@@ -1337,14 +1366,14 @@ impl RobotBundle {
         Self {
             factorgraph,
             radius: Radius(radius),
-            ball: Ball(parry2d::shape::Ball::new(radius)),
+            ball: Ball::new(radius),
             antenna: RadioAntenna::new(config.robot.communication.radius.get(), true),
             connections: RobotConnections::new(),
             // route,
             // initial_state,
             finished_path: FinishedPath::default(),
             t0: T0(t0),
-            gbp_iteration_schedule: GbpIterationSchedule(config.gbp.iteration_schedule),
+            gbp_iteration_schedule: GbpIterationSchedule(config.gbp.iteration_schedule.clone()),
             // task_state:
             // mission: RobotMission::local(waypoints.try_into().unwrap(), started_at),
             mission,
@@ -1605,7 +1634,7 @@ fn iterate_gbp_internal(
     config: Res<Config>,
 ) {
     query.par_iter_mut().for_each(|mut factorgraph| {
-        for _ in 0..config.gbp.iteration_schedule.internal {
+        for _ in 0..config.gbp.iteration_schedule.internal_iterations() {
             factorgraph.internal_factor_iteration();
             factorgraph.internal_variable_iteration();
         }
@@ -1617,7 +1646,7 @@ fn iterate_gbp_internal_sync(
     config: Res<Config>,
 ) {
     for mut factorgraph in &mut query {
-        for _ in 0..config.gbp.iteration_schedule.internal {
+        for _ in 0..config.gbp.iteration_schedule.internal_iterations() {
             factorgraph.internal_factor_iteration();
             factorgraph.internal_variable_iteration();
         }
@@ -1633,7 +1662,7 @@ fn iterate_gbp_external(
         Default::default();
     let messages_to_external_factors: Arc<Mutex<Vec<VariableToFactorMessage>>> = Default::default();
 
-    for _ in 0..config.gbp.iteration_schedule.external {
+    for _ in 0..config.gbp.iteration_schedule.external_iterations() {
         query
             .par_iter_mut()
             .for_each(|(_, mut factorgraph, state, antenna)| {
@@ -1702,7 +1731,7 @@ fn iterate_gbp_external_sync(
     let mut messages_to_external_variables: Vec<FactorToVariableMessage> = Default::default();
     let mut messages_to_external_factors: Vec<VariableToFactorMessage> = Default::default();
 
-    for _ in 0..config.gbp.iteration_schedule.external {
+    for _ in 0..config.gbp.iteration_schedule.external_iterations() {
         for (_, mut factorgraph, state, antenna) in &mut query {
             // if !state.interrobot_comms_active {
             if !antenna.active {
@@ -1866,7 +1895,7 @@ fn iterate_gbp(
 ) {
     // let mut  messages_to_external_variables = vec![];
 
-    for _ in 0..config.gbp.iteration_schedule.internal {
+    for _ in 0..config.gbp.iteration_schedule.internal_iterations() {
         // pretty_print_title!(format!("GBP iteration: {}", i + 1));
         // ╭────────────────────────────────────────────────────────────────────────────────────────
         // │ Factor iteration
@@ -2228,7 +2257,7 @@ fn update_prior_of_horizon_state(
             continue;
         };
 
-        if config.gbp.iteration_schedule.internal == 0 {
+        if config.gbp.iteration_schedule.internal_iterations() == 0 {
             continue;
         }
 
@@ -2698,7 +2727,7 @@ fn on_gbp_schedule_changed(
 ) {
     for GbpScheduleChanged(new_schedule) in evr_gbp_schedule_changed.read() {
         for (entity, mut schedule) in q_robots.iter_mut() {
-            *schedule = *new_schedule;
+            *schedule = new_schedule.clone();
             info!(
                 "changed gbp-schedule to: {:?} of entity {:?}",
                 new_schedule, entity
@@ -2707,3 +2736,48 @@ fn on_gbp_schedule_changed(
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::despawn_entity_after::components::DespawnEntityAfter;
+
+    /// Let a robot finish its route after `SetDespawnOnArrival(despawn)`, and
+    /// return whether a despawn timer was attached to it
+    fn despawn_timer_attached_on_arrival(despawn: bool) -> bool {
+        let mut app = App::new();
+        app.insert_resource(Config::default())
+            .add_event::<SetDespawnOnArrival>()
+            .add_event::<RobotFinishedRoute>()
+            .add_systems(
+                Update,
+                (
+                    set_despawn_on_arrival,
+                    attach_despawn_timer_when_robot_finishes_route,
+                )
+                    .chain(),
+            );
+
+        let robot_id = app.world.spawn_empty().id();
+        app.world.send_event(SetDespawnOnArrival(despawn));
+        app.world.send_event(RobotFinishedRoute(robot_id));
+        app.update();
+
+        assert!(app.world.get_entity(robot_id).is_some());
+        app.world
+            .query::<&DespawnEntityAfter<Virtual>>()
+            .iter(&app.world)
+            .any(|despawn_after| despawn_after.entity_to_despawn == robot_id)
+    }
+
+    #[test]
+    fn robot_persists_when_despawn_on_arrival_is_disabled() {
+        assert!(
+            Config::default()
+                .simulation
+                .despawn_robot_when_final_waypoint_reached
+        );
+        assert!(!despawn_timer_attached_on_arrival(false));
+        assert!(despawn_timer_attached_on_arrival(true));
+    }
+}
@@ -1,20 +1,20 @@
 use std::{num::NonZeroUsize, ops::DerefMut, time::Duration};
 
-use bevy::prelude::*;
+use bevy::{ecs::system::SystemParam, prelude::*};
 use bevy_mod_picking::prelude::*;
 use bevy_notify::ToastEvent;
 use bevy_rand::prelude::{ForkableRng, GlobalEntropy};
 use gbp_config::{
-    formation::{PlanningStrategy, RepeatTimes, WorldDimensions},
-    Config,
+    formation::{PlanningStrategy, ReachedWhen, RepeatTimes, WorldDimensions},
+    Config, DrawSetting,
 };
 use itertools::Itertools;
-use rand::{seq::IteratorRandom, Rng};
+use rand::seq::IteratorRandom;
 use strum::IntoEnumIterator;
 
 use super::{
-    robot::{RobotFinishedRoute, RobotSpawned},
-    RobotId,
+    robot::{RobotDespawned, RobotFinishedRoute, RobotSpawned},
+    RobotConnections, RobotId,
 };
 use crate::{
     // asset_loader::SceneAssets,
@@ -38,6 +38,8 @@ impl Plugin for RobotSpawnerPlugin {
             .add_event::<WaypointCreated>()
             // .add_event::<RobotReachedWaypoint>()
             .add_event::<AllFormationsFinished>()
+            .add_event::<SpawnRobot>()
+            .add_event::<DespawnRobot>()
             .add_systems(
                 Update,
                 (
@@ -57,6 +59,8 @@ impl Plugin for RobotSpawnerPlugin {
                 Update,
                 (
                     spawn_formation,
+                    spawn_requested_robots,
+                    despawn_requested_robots,
                     advance_time.run_if(not(virtual_time_is_paused)),
                     exit_application_on_scenario_finished,
                     // exit_application_on_scenario_finished.run_if(on_event::<AllFormationsFinished>())
@@ -380,6 +384,24 @@ pub struct RobotFormationSpawned {
     pub formation_group_index: usize,
 }
 
+/// Event requesting a single robot to be spawned, in addition to the robots
+/// spawned by the formations of the scenario. Used by e.g. headless experiment
+/// drivers to inject robots programmatically.
+#[derive(Debug, Clone, Event)]
+pub struct SpawnRobot {
+    /// Initial position of the robot
+    pub position:  Vec2,
+    /// Waypoints the robot visits in order, the last one being its goal.
+    /// Must not be empty.
+    pub waypoints: Vec<Vec2>,
+    /// Radius of the robot
+    pub radius:    f32,
+}
+
+/// Event requesting the robot with the given id to be despawned
+#[derive(Debug, Clone, Copy, Event)]
+pub struct DespawnRobot(pub RobotId);
+
 /// Advance time for each `FormationSpawnerCountdown` entity with
 /// `Time::delta()`. If the timer has just finished, send a
 /// `FormationSpawnEvent`.
@@ -411,22 +433,157 @@ fn advance_time(
     }
 }
 
-#[allow(clippy::too_many_arguments, clippy::too_many_lines)]
+/// Pose at `from`, with a velocity of `speed` in the direction of `to`
+fn pose_towards(from: Vec2, to: Vec2, speed: f32) -> Vec4 {
+    let v = (to - from).normalize_or_zero() * speed;
+    Vec4::new(from.x, from.y, v.x, v.y)
+}
+
+/// Description of a single robot to spawn
+struct RobotSpawnSpec {
+    initial_pose: Vec4,
+    /// Poses of the waypoints, excluding the initial pose
+    waypoints: Vec<Vec4>,
+    radius: f32,
+    planning_strategy: PlanningStrategy,
+    waypoint_reached_when_intersects: ReachedWhen,
+    finished_when_intersects: ReachedWhen,
+}
+
+/// Resources needed to spawn a robot, shared by the formation driven and the
+/// event driven spawning of robots
+#[derive(SystemParam)]
+struct RobotSpawnParams<'w, 's> {
+    commands: Commands<'w, 's>,
+    evw_robot_spawned: EventWriter<'w, RobotSpawned>,
+    evw_waypoint_created: EventWriter<'w, WaypointCreated>,
+    materials: ResMut<'w, Assets<StandardMaterial>>,
+    mesh_assets: ResMut<'w, Assets<Mesh>>,
+    config: Res<'w, Config>,
+    env_config: Res<'w, gbp_environment::Environment>,
+    theme: Res<'w, CatppuccinTheme>,
+    sdf: Res<'w, Sdf>,
+    prng: ResMut<'w, GlobalEntropy<bevy_prng::WyRand>>,
+    time_fixed: Res<'w, Time<Fixed>>,
+}
+
+impl RobotSpawnParams<'_, '_> {
+    /// Spawn the robot described by `spec`, and return its id
+    fn spawn_robot(&mut self, spec: RobotSpawnSpec) -> RobotId {
+        let RobotSpawnSpec {
+            initial_pose,
+            waypoints,
+            radius,
+            planning_strategy,
+            waypoint_reached_when_intersects,
+            finished_when_intersects,
+        } = spec;
+
+        trace!(
+            "initial pose: {:?}, waypoints: {:?}",
+            initial_pose,
+            waypoints
+        );
+
+        let initial_direction = initial_pose.yz().extend(0.0);
+        let initial_translation = Vec3::new(initial_pose.x, -1.5, initial_pose.y);
+
+        let robot_entity = self.commands.spawn_empty().id();
+        self.evw_waypoint_created
+            .send_batch(waypoints.iter().map(|pose| WaypointCreated {
+                for_robot: robot_entity,
+                position:  pose.xy(),
+            }));
+
+        let mut waypoints = std::iter::once(initial_pose)
+            .chain(waypoints)
+            .map_into::<StateVector>()
+            .collect::<Vec<_>>();
+
+        if let [.., second_last, last] = waypoints.as_mut_slice() {
+            last.update_velocity(second_last.velocity());
+        }
+
+        let lookahead_horizon: u32 =
+            (self.config.robot.target_speed * self.config.robot.planning_horizon).get() as u32;
+        let lookahead_multiple = self.config.gbp.lookahead_multiple as u32;
+        let variable_timesteps = get_variable_timesteps(lookahead_horizon, lookahead_multiple);
+
+        let robotbundle = RobotBundle::new(
+            robot_entity,
+            StateVector::new(initial_pose),
+            variable_timesteps.as_slice(),
+            &self.config,
+            &self.env_config,
+            radius,
+            &self.sdf.0,
+            self.time_fixed.elapsed().as_secs_f64(),
+            waypoints
+                .try_into()
+                .expect("there is at least one waypoint besides the initial pose"),
+            planning_strategy,
+            waypoint_reached_when_intersects,
+            finished_when_intersects,
+        );
+
+        let initial_visibility = if self.config.visualisation.draw.get(DrawSetting::Robots) {
+            Visibility::Visible
+        } else {
+            Visibility::Hidden
+        };
+
+        let random_color = DisplayColour::iter()
+            .choose(self.prng.deref_mut())
+            .expect("there is more than 0 colors");
+
+        let material = self.materials.add(StandardMaterial {
+            base_color: Color::from_catppuccin_colour(self.theme.get_display_colour(&random_color)),
+            ..Default::default()
+        });
+
+        let mesh = self.mesh_assets.add(
+            Sphere::new(radius)
+                .mesh()
+                .ico(2)
+                .expect("4 subdivisions is less than the maximum allowed of 80"),
+        );
+
+        let pbrbundle = PbrBundle {
+            mesh,
+            material,
+            transform: Transform::from_translation(initial_translation),
+            visibility: initial_visibility,
+            ..Default::default()
+        };
+
+        let prng = self.prng.fork_rng();
+        self.commands.entity(robot_entity).insert((
+            robotbundle,
+            pbrbundle,
+            prng,
+            simulation_loader::Reloadable,
+            super::tracking::PositionTracker::new(10000, Duration::from_millis(100)),
+            super::tracking::VelocityTracker::new(10000, Duration::from_millis(100)),
+            PickableBundle::default(),
+            On::<Pointer<Click>>::send_event::<RobotClickedOn>(),
+            ColorAssociation { name: random_color },
+            FollowCameraMe::new(0.0, 30.0, 0.0)
+                // `initial_direction` is zero when e.g. the robot starts at y = 0 and
+                // moves straight along the y-axis
+                .with_up_direction(Direction3d::new(initial_direction).unwrap_or(Direction3d::Z))
+                .with_attached(true),
+            crate::goal_area::components::Collider(Box::new(parry2d::shape::Ball::new(radius))),
+        ));
+
+        self.evw_robot_spawned.send(RobotSpawned(robot_entity));
+        robot_entity
+    }
+}
+
 fn spawn_formation(
-    mut commands: Commands,
     mut evr_robot_formation_spawned: EventReader<RobotFormationSpawned>,
-    mut evw_robot_spawned: EventWriter<RobotSpawned>,
-    mut evw_waypoint_created: EventWriter<WaypointCreated>,
-    mut materials: ResMut<Assets<StandardMaterial>>,
-    config: Res<Config>,
-    env_config: Res<gbp_environment::Environment>,
-    theme: Res<CatppuccinTheme>,
     simulation_manager: Res<SimulationManager>,
-    sdf: Res<Sdf>,
-    mut prng: ResMut<GlobalEntropy<bevy_prng::WyRand>>,
-    mut mesh_assets: ResMut<Assets<Mesh>>,
-    // time_virtual: Res<Time<Virtual>>,
-    time_fixed: Res<Time<Fixed>>,
+    mut spawn_params: RobotSpawnParams,
 ) {
     for event in evr_robot_formation_spawned.read() {
         let formation_group = simulation_manager
@@ -437,27 +594,27 @@ fn spawn_formation(
         // TODO: check this gets reloaded correctly
 
         let world_dims = {
-            let tile_size = env_config.tiles.settings.tile_size as f64;
-            let width = tile_size * env_config.tiles.grid.ncols() as f64;
-            let height = tile_size * env_config.tiles.grid.nrows() as f64;
+            let tiles = &spawn_params.env_config.tiles;
+            let tile_size = tiles.settings.tile_size as f64;
+            let width = tile_size * tiles.grid.ncols() as f64;
+            let height = tile_size * tiles.grid.nrows() as f64;
             WorldDimensions::new(width, height)
         };
 
         let max_placement_attempts = NonZeroUsize::new(1000).expect("1000 is not zero");
 
         let radii = (0..formation.robots)
-            .map(|_| prng.gen_range(config.robot.radius.range()))
+            .map(|_| {
+                spawn_params
+                    .config
+                    .robot
+                    .radius
+                    .sample(spawn_params.prng.deref_mut())
+            })
             .collect::<Vec<_>>();
 
-        let Some((initial_position_for_each_robot, waypoint_positions_for_each_robot)) = formation
-            .as_positions(
-                world_dims,
-                &radii, /* config.robot.radius,
-                         * max_placement_attempts,
-                         * &mut prng.rng as &mut dyn Rng,
-                         * prng as &mut dyn Rng, */
-                prng.deref_mut(),
-            )
+        let Some((initial_position_for_each_robot, waypoint_positions_for_each_robot)) =
+            formation.as_positions(world_dims, &radii, spawn_params.prng.deref_mut())
         else {
             error!(
                 "failed to spawn formation {}, reason: was not able to place robots along line \
@@ -468,6 +625,8 @@ fn spawn_formation(
             return;
         };
 
+        let target_speed = spawn_params.config.robot.target_speed.get();
+
         let initial_pose_for_each_robot: Vec<Vec4> = initial_position_for_each_robot
             .iter()
             .zip(
@@ -475,11 +634,7 @@ fn spawn_formation(
                     .first()
                     .expect("there is at least one waypoint"),
             )
-            .map(|(from, to)| {
-                let d = *to - *from;
-                let v = d.normalize_or_zero() * config.robot.target_speed.get();
-                Vec4::new(from.x, from.y, v.x, v.y)
-            })
+            .map(|(from, to)| pose_towards(*from, *to, target_speed))
             .collect();
 
         let waypoint_poses_for_each_robot: Vec<Vec<Vec4>> = waypoint_positions_for_each_robot
@@ -489,159 +644,80 @@ fn spawn_formation(
             .map(|(a, b)| {
                 a.iter()
                     .zip(b.iter())
-                    .map(|(from, to)| {
-                        let d = *to - *from;
-                        let v = d.normalize_or_zero() * config.robot.target_speed.get();
-                        Vec4::new(from.x, from.y, v.x, v.y)
-                    })
+                    .map(|(from, to)| pose_towards(*from, *to, target_speed))
                     .collect::<Vec<_>>()
             })
             .collect();
 
-        #[rustfmt::skip]
-        let Some(min_radius) = radii.iter().copied().map(ordered_float::OrderedFloat).min() else {
-            return;
-        };
-        #[rustfmt::skip]
-        let Some(max_radius) = radii.iter().copied().map(ordered_float::OrderedFloat).max() else {
-            return;
-        };
-
         for (i, initial_pose) in initial_pose_for_each_robot.iter().enumerate() {
-            let mut waypoints: Vec<Vec4> = waypoint_poses_for_each_robot
-                .iter()
-                .map(|wps| wps[i])
-                .collect();
-            trace!(
-                "initial pose: {:?}, waypoints: {:?}",
-                initial_pose,
-                waypoints
-            );
-
-            let initial_direction = initial_pose.yz().extend(0.0);
-            let initial_translation = Vec3::new(initial_pose.x, -1.5, initial_pose.y);
-            // let initial_translation = Vec3::new(initial_pose.x, -5.5, initial_pose.y);
-
-            let mut entity = commands.spawn_empty();
-            let robot_entity = entity.id();
-            evw_waypoint_created.send_batch(waypoints.iter().map(|pose| WaypointCreated {
-                for_robot: robot_entity,
-                position:  pose.xy(),
-            }));
-
-            // let second_last = waypoints.get(waypoints.len() - 2).copied().unwrap();
-            // let last = waypoints.last_mut().unwrap();
-            // last.z = second_last.z;
-            // last.w = second_last.w;
-
-            // let mu
-            let mut waypoints = std::iter::once(initial_pose)
-                .chain(waypoints.iter())
-                .copied()
-                .map_into::<StateVector>()
-                .collect::<Vec<_>>();
-
-            let second_last = waypoints.get(waypoints.len() - 2).copied().unwrap();
-            let last = waypoints.last_mut().unwrap();
-            last.update_velocity(second_last.velocity());
-            // last.z = second_last.z;
-            // last.w = second_last.w;
-            //
-
-            // let lookahead_horizon = (5.0 / 0.25) as u32;
-            // let lookahead_multiple = 3;
-
-            //     globals.T_HORIZON / globals.T0, globals.LOOKAHEAD_MULTIPLE);
-            // num_variables_ = variable_timesteps.size();
-            // let t0: f32 = radii[i] / 2.0 / config.robot.max_speed.get();
-
-            // let divisor: f32 = (min_radius / 2.0 / config.robot.max_speed.get()).into();
-            let divisor: f32 = (max_radius / 2.0 / config.robot.target_speed.get()).into();
-
-            let lookahead_horizon: u32 = (config.robot.planning_horizon.get() / divisor) as u32;
-            let lookahead_horizon: u32 = config.robot.planning_horizon.get() as u32;
-            let lookahead_horizon: u32 =
-                (config.robot.target_speed * config.robot.planning_horizon).get() as u32;
-            // let lookahead_horizon: u32 = (config.robot.planning_horizon.get()
-            //     / radii.iter().map(ordered_float::OrderedFloat).min().unwrap())
-            //     as u32;
-            let lookahead_multiple = config.gbp.lookahead_multiple as u32;
-            let variable_timesteps = get_variable_timesteps(lookahead_horizon, lookahead_multiple);
-
-            let robotbundle = RobotBundle::new(
-                robot_entity,
-                StateVector::new(*initial_pose),
-                // route,
-                variable_timesteps.as_slice(),
-                &config,
-                &env_config,
-                radii[i],
-                &sdf.0,
-                time_fixed.elapsed().as_secs_f64(),
-                waypoints.try_into().unwrap(),
-                // config
-                formation.planning_strategy,
-                formation.waypoint_reached_when_intersects,
-                formation.finished_when_intersects,
-                // matches!(formation.planning_strategy, PlanningStrategy::RrtStar
-                // ),
-            );
-
-            let initial_visibility = if config.visualisation.draw.robots {
-                Visibility::Visible
-            } else {
-                Visibility::Hidden
-            };
-
-            let random_color = DisplayColour::iter()
-                .choose(prng.deref_mut())
-                .expect("there is more than 0 colors");
-
-            let material = materials.add(StandardMaterial {
-                base_color: Color::from_catppuccin_colour(theme.get_display_colour(&random_color)),
-                ..Default::default()
+            spawn_params.spawn_robot(RobotSpawnSpec {
+                initial_pose: *initial_pose,
+                waypoints: waypoint_poses_for_each_robot
+                    .iter()
+                    .map(|wps| wps[i])
+                    .collect(),
+                radius: radii[i],
+                planning_strategy: formation.planning_strategy,
+                waypoint_reached_when_intersects: formation.waypoint_reached_when_intersects,
+                finished_when_intersects: formation.finished_when_intersects,
             });
+        }
+    }
+}
 
-            let mesh = mesh_assets.add(
-                Sphere::new(radii[i])
-                    .mesh()
-                    .ico(2)
-                    .expect("4 subdivisions is less than the maximum allowed of 80"),
+/// Spawn a robot for every [`SpawnRobot`] event, the same way robots of a
+/// formation are spawned
+fn spawn_requested_robots(
+    mut evr_spawn_robot: EventReader<SpawnRobot>,
+    mut spawn_params: RobotSpawnParams,
+) {
+    for event in evr_spawn_robot.read() {
+        if event.waypoints.is_empty() {
+            error!(
+                "failed to spawn robot at {}, reason: no waypoints given, skipping",
+                event.position
             );
+            continue;
+        }
+
+        let target_speed = spawn_params.config.robot.target_speed.get();
+        let positions = std::iter::once(event.position)
+            .chain(event.waypoints.iter().copied())
+            .collect::<Vec<_>>();
+        let mut poses = positions
+            .iter()
+            .zip(positions.iter().skip(1).chain(positions.last()))
+            .map(|(from, to)| pose_towards(*from, *to, target_speed));
+        let initial_pose = poses
+            .next()
+            .expect("there is at least the initial position");
+
+        spawn_params.spawn_robot(RobotSpawnSpec {
+            initial_pose,
+            waypoints: poses.collect(),
+            radius: event.radius,
+            planning_strategy: PlanningStrategy::OnlyLocal,
+            waypoint_reached_when_intersects: ReachedWhen::same_as_paper(),
+            finished_when_intersects: ReachedWhen::same_as_paper(),
+        });
+    }
+}
 
-            let pbrbundle = PbrBundle {
-                mesh,
-                material,
-                transform: Transform::from_translation(initial_translation),
-                visibility: initial_visibility,
-                ..Default::default()
-            };
-
-            entity.insert((
-                robotbundle,
-                pbrbundle,
-                prng.fork_rng(),
-                simulation_loader::Reloadable,
-                // super::tracking::PositionTracker::new(1000, Duration::from_millis(50)),
-                // super::tracking::VelocityTracker::new(1000, Duration::from_millis(50)),
-                super::tracking::PositionTracker::new(10000, Duration::from_millis(100)),
-                super::tracking::VelocityTracker::new(10000, Duration::from_millis(100)),
-                PickableBundle::default(),
-                On::<Pointer<Click>>::send_event::<RobotClickedOn>(),
-                ColorAssociation { name: random_color },
-                FollowCameraMe::new(0.0, 30.0, 0.0)
-                    .with_up_direction(Direction3d::new(initial_direction).expect(
-                        "Vector between initial position and first waypoint should be different \
-                         from 0, NaN, and infinity.",
-                    ))
-                    .with_attached(true),
-                crate::goal_area::components::Collider(Box::new(parry2d::shape::Ball::new(
-                    radii[i],
-                ))),
-            ));
-
-            evw_robot_spawned.send(RobotSpawned(robot_entity));
+/// Despawn the robot of every [`DespawnRobot`] event
+fn despawn_requested_robots(
+    mut commands: Commands,
+    mut evr_despawn_robot: EventReader<DespawnRobot>,
+    mut evw_robot_despawned: EventWriter<RobotDespawned>,
+    q_robots: Query<(), With<RobotConnections>>,
+) {
+    for DespawnRobot(robot_id) in evr_despawn_robot.read() {
+        if !q_robots.contains(*robot_id) {
+            warn!("cannot despawn {:?}, reason: it is not a robot", robot_id);
+            continue;
         }
+
+        commands.entity(*robot_id).despawn_recursive();
+        evw_robot_despawned.send(RobotDespawned(*robot_id));
     }
 }
 
@@ -688,3 +764,89 @@ fn exit_application_on_scenario_finished(
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use bevy_rand::prelude::EntropyPlugin;
+
+    use super::*;
+    use crate::planner::robot::{Mission, Radius};
+
+    fn app() -> App {
+        let mut app = App::new();
+        app.add_plugins(EntropyPlugin::<bevy_prng::WyRand>::with_seed(
+            0_u64.to_le_bytes(),
+        ))
+        .insert_resource(Config::default())
+        .insert_resource(gbp_environment::Environment::default())
+        .insert_resource(CatppuccinTheme {
+            flavour: catppuccin::Flavour::Macchiato,
+        })
+        .insert_resource(Sdf(simulation_loader::SdfImage::new(100, 100)))
+        .init_resource::<Time<Fixed>>()
+        .init_resource::<Assets<StandardMaterial>>()
+        .init_resource::<Assets<Mesh>>()
+        .add_event::<SpawnRobot>()
+        .add_event::<DespawnRobot>()
+        .add_event::<RobotSpawned>()
+        .add_event::<RobotDespawned>()
+        .add_event::<WaypointCreated>()
+        .add_systems(Update, (spawn_requested_robots, despawn_requested_robots));
+        app
+    }
+
+    fn robots(app: &mut App) -> Vec<(RobotId, f32)> {
+        app.world
+            .query_filtered::<(Entity, &Radius), With<RobotConnections>>()
+            .iter(&app.world)
+            .map(|(robot_id, radius)| (robot_id, radius.0))
+            .collect()
+    }
+
+    #[test]
+    fn spawn_robot_event_spawns_a_robot() {
+        let mut app = app();
+        app.world.send_event(SpawnRobot {
+            position:  Vec2::new(-10.0, -10.0),
+            waypoints: vec![Vec2::new(10.0, 10.0)],
+            radius:    1.5,
+        });
+        app.update();
+
+        let robots = robots(&mut app);
+        assert_eq!(robots.len(), 1);
+        let (robot_id, radius) = robots[0];
+        assert!((radius - 1.5).abs() < f32::EPSILON);
+        assert!(app.world.get::<Mission>(robot_id).is_some());
+
+        app.world.send_event(DespawnRobot(robot_id));
+        app.update();
+        assert!(robots(&mut app).is_empty());
+    }
+
+    #[test]
+    fn spawn_robot_with_zero_initial_direction() {
+        let mut app = app();
+        app.world.send_event(SpawnRobot {
+            position:  Vec2::new(5.0, 0.0),
+            waypoints: vec![Vec2::new(5.0, 10.0)],
+            radius:    1.0,
+        });
+        app.update();
+
+        assert_eq!(robots(&mut app).len(), 1);
+    }
+
+    #[test]
+    fn spawn_robot_event_without_waypoints_is_ignored() {
+        let mut app = app();
+        app.world.send_event(SpawnRobot {
+            position:  Vec2::ZERO,
+            waypoints: vec![],
+            radius:    1.0,
+        });
+        app.update();
+
+        assert!(robots(&mut app).is_empty());
+    }
+}
@@ -1,5 +1,5 @@
 use bevy::prelude::*;
-use gbp_config::Config;
+use gbp_config::{Config, DrawSetting};
 
 use crate::planner::{robot::Ball, RobotConnections};
 
@@ -25,7 +25,7 @@ mod robot_colliders {
     use super::*;
     /// **Bevy** run condition for drawing robot colliders
     pub(super) fn enabled(config: Res<Config>) -> bool {
-        config.visualisation.draw.robot_colliders
+        config.visualisation.draw.get(DrawSetting::RobotColliders)
     }
 
     pub(super) fn render(
@@ -62,7 +62,10 @@ mod environment_colliders {
 
     use super::*;
     pub(super) fn enabled(config: Res<Config>) -> bool {
-        config.visualisation.draw.environment_colliders
+        config
+            .visualisation
+            .draw
+            .get(DrawSetting::EnvironmentColliders)
     }
 
     const COLOR: Color = Color::ORANGE_RED;
@@ -1,7 +1,7 @@
 //! A **Bevy** Plugin for visualising the communication graph between robots
 
 use bevy::prelude::*;
-use gbp_config::Config;
+use gbp_config::{Config, DrawSetting};
 
 use super::super::RobotConnections;
 use crate::{
@@ -21,7 +21,10 @@ impl Plugin for CommunicationGraphVisualiserPlugin {
 /// Used to check if the communication graph should be drawn
 #[inline]
 fn enabled(config: Res<Config>) -> bool {
-    config.visualisation.draw.communication_graph
+    config
+        .visualisation
+        .draw
+        .get(DrawSetting::CommunicationGraph)
 }
 
 // /// Used to keep track of which undirected edges have already been drawn
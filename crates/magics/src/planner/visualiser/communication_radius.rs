@@ -1,5 +1,5 @@
 use bevy::prelude::*;
-use gbp_config::Config;
+use gbp_config::{Config, DrawSetting};
 
 use crate::{
     planner::{robot::RadioAntenna, RobotConnections},
@@ -18,7 +18,10 @@ impl Plugin for CommunicationRadiusVisualizerPlugin {
 }
 
 fn draw_communication_radius_enabled(config: Res<Config>) -> bool {
-    config.visualisation.draw.communication_radius
+    config
+        .visualisation
+        .draw
+        .get(DrawSetting::CommunicationRadius)
 }
 
 fn draw_communication_radius(
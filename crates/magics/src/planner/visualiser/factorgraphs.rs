@@ -279,7 +279,11 @@ fn create_factorgraph_visualizer(
                         ..Default::default()
                     }),
                     transform: Transform::from_translation(transform),
-                    visibility: if config.visualisation.draw.predicted_trajectories {
+                    visibility: if config
+                        .visualisation
+                        .draw
+                        .get(DrawSetting::PredictedTrajectories)
+                    {
                         Visibility::Visible
                     } else {
                         Visibility::Hidden
@@ -355,7 +359,10 @@ fn show_or_hide_factorgraphs(
 
 #[inline]
 fn enabled(config: Res<Config>) -> bool {
-    config.visualisation.draw.predicted_trajectories
+    config
+        .visualisation
+        .draw
+        .get(DrawSetting::PredictedTrajectories)
 }
 
 /// A **Bevy** [`Update`] system
@@ -1,7 +1,7 @@
 //! Visualize interrobot factors
 //! environment.
 use bevy::prelude::*;
-use gbp_config::Config;
+use gbp_config::{Config, DrawSetting};
 
 use crate::{factorgraph::prelude::FactorGraph, planner::robot::RadioAntenna};
 
@@ -16,8 +16,14 @@ impl Plugin for InterRobotFactorVisualizerPlugin {
 /// **Bevy** run condition for drawing obstacle factors
 fn enabled(config: Res<Config>) -> bool {
     // config.visualisation.draw.interrobot_factors
-    (config.visualisation.draw.interrobot_factors_safety_distance
-        || config.visualisation.draw.interrobot_factors)
+    (config
+        .visualisation
+        .draw
+        .get(DrawSetting::InterRobotFactorsSafetyDistance)
+        || config
+            .visualisation
+            .draw
+            .get(DrawSetting::InterRobotFactors))
         && config.gbp.factors_enabled.interrobot
 }
 
@@ -61,7 +67,11 @@ fn visualize_interrobot_factors(
 
             let dist = estimated_position.distance(external_position);
 
-            if config.visualisation.draw.interrobot_factors {
+            if config
+                .visualisation
+                .draw
+                .get(DrawSetting::InterRobotFactors)
+            {
                 if dist < safety_dist as f32 {
                     let offset = 0.15; // 0.3 / 2.0;
                     let start = estimated_position + dir * offset;
@@ -73,7 +83,11 @@ fn visualize_interrobot_factors(
                 }
             }
 
-            if config.visualisation.draw.interrobot_factors_safety_distance {
+            if config
+                .visualisation
+                .draw
+                .get(DrawSetting::InterRobotFactorsSafetyDistance)
+            {
                 let color = if antenna.active {
                     Color::ORANGE
                 } else {
@@ -2,7 +2,7 @@
 //! environment.
 
 use bevy::prelude::*;
-use gbp_config::Config;
+use gbp_config::{Config, DrawSetting};
 
 use crate::factorgraph::prelude::FactorGraph;
 
@@ -49,7 +49,7 @@ mod resources {
         fn from_world(world: &mut World) -> Self {
             if let Some(config) = world.get_resource::<gbp_config::Config>() {
                 Self {
-                    enabled: config.visualisation.draw.obstacle_factors,
+                    enabled: config.visualisation.draw.get(DrawSetting::ObstacleFactors),
                 }
             } else {
                 Self { enabled: false }
@@ -157,5 +157,6 @@ fn visualize_obstacle_factors(
 /// **Bevy** run condition for drawing obstacle factors
 #[inline]
 fn enabled(config: Res<Config>) -> bool {
-    config.visualisation.draw.obstacle_factors && config.gbp.factors_enabled.obstacle
+    config.visualisation.draw.get(DrawSetting::ObstacleFactors)
+        && config.gbp.factors_enabled.obstacle
 }
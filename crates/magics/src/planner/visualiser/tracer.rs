@@ -7,7 +7,7 @@ use ringbuf::{Rb, StaticRb};
 const MAX_TRACE_LENGTH: usize = 10000;
 const SAMPLE_DELAY: f32 = 0.5;
 
-use gbp_config::Config;
+use gbp_config::{Config, DrawSetting};
 
 use crate::{
     planner::{
@@ -121,7 +121,7 @@ fn track_robots(
 
 #[inline]
 fn enabled(config: Res<Config>) -> bool {
-    config.visualisation.draw.paths
+    config.visualisation.draw.get(DrawSetting::Paths)
 }
 
 /// **Bevy** [`Update`] system
@@ -2,7 +2,7 @@
 //! Includes the trackings paths, and the tracking factors
 
 use bevy::prelude::*;
-use gbp_config::Config;
+use gbp_config::{Config, DrawSetting};
 use itertools::Itertools;
 
 use crate::{
@@ -106,5 +106,5 @@ fn visualise_tracking_factors(
 /// **Bevy** run condition for drawing obstacle factors
 #[inline]
 fn enabled(config: Res<Config>) -> bool {
-    config.visualisation.draw.tracking && config.gbp.factors_enabled.tracking
+    config.visualisation.draw.get(DrawSetting::Tracking) && config.gbp.factors_enabled.tracking
 }
@@ -1,6 +1,6 @@
 #![warn(missing_docs)]
 use bevy::prelude::*;
-use gbp_config::Config;
+use gbp_config::{Config, DrawSetting};
 
 use super::{RobotTracker, Z_FIGHTING_OFFSET};
 use crate::{
@@ -163,7 +163,7 @@ fn init_uncertainty(
                 } else {
                     materials.uncertainty_unattenable.clone()
                 };
-                let visibility = if config.visualisation.draw.uncertainty {
+                let visibility = if config.visualisation.draw.get(DrawSetting::Uncertainty) {
                     Visibility::Visible
                 } else {
                     Visibility::Hidden
@@ -36,7 +36,7 @@ impl Plugin for WaypointVisualiserPlugin {
 }
 
 fn enabled(config: Res<Config>) -> bool {
-    config.visualisation.draw.waypoints
+    config.visualisation.draw.get(DrawSetting::Waypoints)
 }
 
 fn visualize_waypoints(
@@ -121,7 +121,7 @@ fn create_waypoint_visualizer(
                 mesh: meshes.waypoint.clone(),
                 material: materials.waypoint.clone(),
                 transform,
-                visibility: if config.visualisation.draw.waypoints {
+                visibility: if config.visualisation.draw.get(DrawSetting::Waypoints) {
                     Visibility::Visible
                 } else {
                     Visibility::Hidden
@@ -8,8 +8,8 @@ use bevy::{
     prelude::*,
     time::common_conditions::{on_real_timer, on_timer},
 };
-use bevy_notify::{ToastEvent, ToastLevel, ToastOptions};
-use gbp_config::{Config, FormationGroup};
+use bevy_notify::{ToastEvent, ToastId, ToastLevel, ToastOptions};
+use gbp_config::{Config, FormationGroup, GbpIterationScheduleOverrides};
 use gbp_environment::Environment;
 use smol_str::SmolStr;
 
@@ -30,6 +30,15 @@ pub struct SimulationLoaderPlugin {
     pub show_toasts: bool,
     pub initial_simulation: InitialSimulation,
     pub reload_after: Option<Duration>,
+    /// Seed overriding `simulation.prng-seed` in the config of every
+    /// simulation
+    pub prng_seed: Option<u64>,
+    /// Overrides of `gbp.iteration-schedule` in the config of every
+    /// simulation
+    pub gbp_iteration_schedule: GbpIterationScheduleOverrides,
+    /// Value overriding `simulation.exit-application-on-scenario-finished` in
+    /// the config of every simulation
+    pub exit_application_on_scenario_finished: Option<bool>,
 }
 
 impl Default for SimulationLoaderPlugin {
@@ -38,6 +47,9 @@ impl Default for SimulationLoaderPlugin {
             show_toasts: true,
             initial_simulation: InitialSimulation::FirstFoundInFolder,
             reload_after: None,
+            prng_seed: None,
+            gbp_iteration_schedule: GbpIterationScheduleOverrides::default(),
+            exit_application_on_scenario_finished: None,
         }
     }
 }
@@ -47,6 +59,26 @@ impl SimulationLoaderPlugin {
         self.reload_after = Some(duration);
         self
     }
+
+    /// Override the `simulation.prng-seed` of every simulation with `seed`
+    pub fn prng_seed(mut self, seed: u64) -> Self {
+        self.prng_seed = Some(seed);
+        self
+    }
+
+    /// Override `gbp.iteration-schedule` of every simulation with the fields
+    /// set in `overrides`
+    pub fn gbp_iteration_schedule(mut self, overrides: GbpIterationScheduleOverrides) -> Self {
+        self.gbp_iteration_schedule = overrides;
+        self
+    }
+
+    /// Override `simulation.exit-application-on-scenario-finished` of every
+    /// simulation with `exit`
+    pub fn exit_application_on_scenario_finished(mut self, exit: bool) -> Self {
+        self.exit_application_on_scenario_finished = Some(exit);
+        self
+    }
 }
 
 pub type SdfImage = image::ImageBuffer<image::Rgb<u8>, Vec<u8>>;
@@ -75,6 +107,9 @@ impl SimulationLoaderPlugin {
                     InitialSimulation::Name(name)
                 }),
             reload_after: None,
+            prng_seed: None,
+            gbp_iteration_schedule: GbpIterationScheduleOverrides::default(),
+            exit_application_on_scenario_finished: None,
             // reload_after: Some(Duration::from_secs(80)), // for experiments purposes to run
             // overnight
 
@@ -139,8 +174,16 @@ impl Plugin for SimulationLoaderPlugin {
                     .expect("failed to parse simulation name");
                 // println!("about to load: {name:?}");
                 let config_path = dir.path().join("config.toml");
-                let config = Config::from_file(config_path)
+                let mut config = Config::from_file(config_path)
                     .expect(format!("failed to load config for simulation: {name:?}").as_str());
+                if let Some(seed) = self.prng_seed {
+                    config.simulation.prng_seed = seed;
+                }
+                self.gbp_iteration_schedule
+                    .apply(&mut config.gbp.iteration_schedule);
+                if let Some(exit) = self.exit_application_on_scenario_finished {
+                    config.simulation.exit_application_on_scenario_finished = exit;
+                }
                 let environment_path = dir.path().join("environment.yaml");
                 let environment = Environment::from_file(environment_path).expect(
                     format!("failed to load environment for simulation: {name:?}").as_str(),
@@ -213,6 +256,7 @@ impl Plugin for SimulationLoaderPlugin {
         // v).unwrap();
 
         let config = initial_simulation.config.clone();
+        let seed: [u8; 8] = config.simulation.prng_seed.to_le_bytes();
         let formation_group = initial_simulation.formation_group.clone();
         let environment = initial_simulation.environment.clone();
         let sdf = initial_simulation.sdf.clone();
@@ -222,7 +266,7 @@ impl Plugin for SimulationLoaderPlugin {
 
         app
             .add_plugins(
-                    bevy_rand::prelude::EntropyPlugin::<bevy_prng::WyRand>::default(),
+                    bevy_rand::prelude::EntropyPlugin::<bevy_prng::WyRand>::with_seed(seed),
             )
             // .add_systems(Startup, load_initial_simulation)
             .insert_resource(config)
@@ -639,7 +683,7 @@ fn handle_requests(
             // load config
 
             // app.insert_resource(Time::<Fixed>::from_hz(hz))
-            *time_fixed = Time::<Fixed>::from_hz(config.simulation.hz);
+            *time_fixed = Time::<Fixed>::from_hz(config.simulation.hz.get());
             *config = simulation_manager.simulations[id.0].config.clone();
             // config.simulation.t0 =
             *environment = simulation_manager.simulations[id.0].environment.clone();
@@ -657,6 +701,7 @@ fn handle_requests(
             let simulation_name = &simulation_manager.names[id.0];
 
             evw_toast.send(ToastEvent {
+                id:      ToastId::new(),
                 caption: format!("simulation loaded: {}", simulation_name),
                 options: ToastOptions {
                     level: ToastLevel::Success,
@@ -680,6 +725,7 @@ fn handle_requests(
                 info!("sent reload simulation event with id: {}", index);
                 simulation_manager.simulations_loaded += 1;
                 evw_toast.send(ToastEvent {
+                    id:      ToastId::new(),
                     caption: "simulation reloaded".into(),
                     options: ToastOptions {
                         level: ToastLevel::Success,
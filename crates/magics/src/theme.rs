@@ -53,8 +53,12 @@ impl CatppuccinTheme {
 impl FromWorld for CatppuccinTheme {
     fn from_world(world: &mut World) -> Self {
         let mut q = world.query::<(&Window, &PrimaryWindow)>();
-        let (primary_window, _) = q.single(world);
-        let window_theme = primary_window.window_theme.unwrap_or(WindowTheme::Dark);
+        // there is no primary window when running headless
+        let window_theme = q
+            .get_single(world)
+            .ok()
+            .and_then(|(primary_window, _)| primary_window.window_theme)
+            .unwrap_or(WindowTheme::Dark);
 
         let flavour = match window_theme {
             WindowTheme::Light => Flavour::Latte,
@@ -481,8 +485,11 @@ impl Plugin for ThemePlugin {
 }
 
 /// **Bevy** run criteria, checking if the window theme has been set
+/// Also true if there is no primary window, as there is no theme to set then
 fn window_theme_is_initialised(windows: Query<&Window, With<PrimaryWindow>>) -> bool {
-    windows.single().window_theme.is_some()
+    windows
+        .get_single()
+        .map_or(true, |window| window.window_theme.is_some())
     // let window = windows.single();
     // window.window_theme.is_none()
 }
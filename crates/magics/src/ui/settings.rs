@@ -9,13 +9,14 @@ use bevy_infinite_grid::InfiniteGrid;
 use bevy_inspector_egui::{bevy_inspector, DefaultInspectorConfigPlugin};
 use bevy_notify::ToastEvent;
 use catppuccin::Colour;
-use gbp_config::{Config, DrawSection, DrawSetting};
+use gbp_config::{Config, DrawSection};
 use gbp_linalg::Float;
 use gbp_schedule::GbpScheduleAtIteration;
 use repeating_array::RepeatingArray;
 use smol_str::SmolStr;
 use struct_iterable::Iterable;
 use strum::IntoEnumIterator;
+use unit_interval::UnitInterval;
 
 use super::{custom, scale::ScaleUi, OccupiedScreenSpace, ToUiString, UiScaleType, UiState};
 use crate::{
@@ -293,13 +294,17 @@ fn ui_settings_panel(
                         ui.separator();
                         // ui.add_space(2.5);
 
+                        // the pattern of a custom schedule decides the number of iterations
+                        let editable = time_virtual.is_paused()
+                            && !config.gbp.iteration_schedule.schedule.is_custom();
                         custom::grid("iterations_per_timestep_grid", 2).show(ui, |ui| {
                             ui.label("Internal");
-                            let mut text = config.gbp.iteration_schedule.internal.to_string();
+                            let mut text =
+                                config.gbp.iteration_schedule.internal_iterations().to_string();
 
                             let te_output = egui::TextEdit::singleline(&mut text)
                                 .char_limit(3)
-                                .interactive(time_virtual.is_paused())
+                                .interactive(editable)
                                 .desired_width(f32::INFINITY)
                                 .show(ui);
 
@@ -317,10 +322,11 @@ fn ui_settings_panel(
                             ui.end_row();
 
                             ui.label("External");
-                            let mut text = config.gbp.iteration_schedule.external.to_string();
+                            let mut text =
+                                config.gbp.iteration_schedule.external_iterations().to_string();
                             let te_output = egui::TextEdit::singleline(&mut text)
                                 .char_limit(3)
-                                .interactive(time_virtual.is_paused())
+                                .interactive(editable)
                                 .desired_width(f32::INFINITY)
                                 .show(ui);
 
@@ -423,18 +429,21 @@ fn ui_settings_panel(
                         custom::grid("select_gbp_schedule_grid", 2).show(ui, |ui| {
                             ui.label("Schedule");
                             ui.vertical_centered_justified(|ui| {
-                                let current: &'static str = config.gbp.iteration_schedule.schedule.into();
+                                let current: &'static str = (&config.gbp.iteration_schedule.schedule).into();
                                 ui.menu_button(current, |ui| {
-                                    for schedule in gbp_config::GbpIterationScheduleKind::iter() {
+                                    // custom schedules can only be given in the config file
+                                    for schedule in gbp_config::GbpIterationScheduleKind::iter()
+                                        .filter(|schedule| !schedule.is_custom())
+                                    {
                                         ui.vertical(|ui| {
                                         // ui.vertical_centered(|ui| {
                                         // ui.vertical_centered_justified(|ui| {
-                                            let text: &'static str = schedule.into();
+                                            let text: &'static str = (&schedule).into();
                                             let button = egui::Button::new(text).wrap(false);
                                             if ui.add(button).clicked() {
                                                 let new_schedule = schedule.into();
                                                 config.gbp.iteration_schedule.schedule = new_schedule;
-                                                world.send_event::<crate::planner::robot::GbpScheduleChanged>(config.gbp.iteration_schedule.into());
+                                                world.send_event::<crate::planner::robot::GbpScheduleChanged>(config.gbp.iteration_schedule.clone().into());
                                                 ui.close_menu();
                                             }
                                         });
@@ -726,7 +735,7 @@ fn ui_settings_panel(
                         // Slider for communication failure rate (probability) in [0.0, 1.0]
                         ui.label("Failure");
                         ui.horizontal(|ui| {
-                            let mut failure_rate = config.robot.communication.failure_rate;
+                            let mut failure_rate = config.robot.communication.failure_rate.get();
                             ui.label(format!("{:.2}%", failure_rate));
                             // ui.spacing_mut().slider_width = ui.available_width()  - (custom::SLIDER_EXTRA_WIDE + custom::SPACING);
                             ui.spacing_mut().slider_width = ui.available_width();
@@ -738,7 +747,8 @@ fn ui_settings_panel(
                                     .show_value(false)
                             );
                             if slider_response.changed() {
-                                config.robot.communication.failure_rate = failure_rate;
+                                config.robot.communication.failure_rate =
+                                    UnitInterval::clamp(failure_rate);
                             }
                         });
                         ui.end_row();
@@ -758,8 +768,7 @@ fn ui_settings_panel(
                             if ui.button("None").clicked() {
                                 let events = config.visualisation.draw
                                     .iter()
-                                    .filter_map(|(name, _value)| name.parse::<DrawSetting>().ok())
-                                    .map(|setting| DrawSettingsEvent {setting, draw: false} );
+                                    .map(|(setting, _)| DrawSettingsEvent {setting, draw: false} );
                                 world.send_event_batch(events);
 
                                 config.visualisation.draw = DrawSection::all_disabled();
@@ -769,8 +778,7 @@ fn ui_settings_panel(
                             if ui.button("All").clicked() {
                                 let events = config.visualisation.draw
                                     .iter()
-                                    .filter_map(|(name, _value)| name.parse::<DrawSetting>().ok())
-                                    .map(|setting| DrawSettingsEvent {setting, draw: true} );
+                                    .map(|(setting, _)| DrawSettingsEvent {setting, draw: true} );
                                 world.send_event_batch(events);
 
                                 config.visualisation.draw = DrawSection::all_enabled();
@@ -780,13 +788,7 @@ fn ui_settings_panel(
                             if ui.button("Flip").clicked() {
                                 let events = config.visualisation.draw
                                     .iter()
-                                    .filter_map(|(name, value)|{
-                                        if let (Ok(setting), Some(value)) = (name.parse::<DrawSetting>(), value.downcast_ref::<bool>()) {
-                                            Some(DrawSettingsEvent {setting, draw: !value })
-                                        } else {
-                                            None
-                                        }
-                                    });
+                                    .map(|(setting, draw)| DrawSettingsEvent {setting, draw: !draw });
                                 world.send_event_batch(events);
 
                                 config.visualisation.draw.flip_all();
@@ -797,13 +799,7 @@ fn ui_settings_panel(
                                 let unmodified_draw_section = simulation_manager.active().map(|sim| &sim.config.visualisation.draw).unwrap();
                                 let events = unmodified_draw_section
                                     .iter()
-                                    .filter_map(|(name, value)|{
-                                        if let (Ok(setting), Some(value)) = (name.parse::<DrawSetting>(), value.downcast_ref::<bool>()) {
-                                            Some(DrawSettingsEvent {setting, draw: *value })
-                                        } else {
-                                            None
-                                        }
-                                    });
+                                    .map(|(setting, draw)| DrawSettingsEvent {setting, draw });
                                 world.send_event_batch(events);
                                 config.visualisation.draw = *unmodified_draw_section;
                             }
@@ -822,32 +818,16 @@ fn ui_settings_panel(
                             .column(egui_extras::Column::initial(max_rect.width() * 0.8))
                             .column(egui_extras::Column::remainder())
                             .body(|mut body| {
-                                for (name, _) in config.visualisation.draw.clone().iter() {
+                                for (setting, _) in config.visualisation.draw.clone().iter() {
                                     body.row(25., |mut row| {
-                                        //row.col(|col| {col.label(DrawSection::to_display_string(name));});
-
                                         row.col(|col| {custom::center_y(col, |col| {
-                                            let label = DrawSection::to_display_string(name);
-                                            col.label(label);});});
+                                            col.label(setting.to_display_string());});});
                                         row.col(|ui|  {
-                                            let setting = config
-                                                .visualisation
-                                                .draw
-                                                .get_field_mut::<bool>(name)
-                                                .expect("is bool"
-                                                );
+                                            let mut draw = config.visualisation.draw.get(setting);
                                             custom::float_right(ui, |ui| {
-                                                if custom::toggle_ui(ui, setting).clicked() {
-                                                    println!("name: {}", name);
-                                                    if let Ok(setting_kind) = name.parse::<DrawSetting>() {
-                                                        let event = DrawSettingsEvent {
-                                                            setting: setting_kind,
-                                                            draw:    *setting,
-                                                        };
-                                                        world.send_event::<DrawSettingsEvent>(event);
-                                                    } else {
-                                                        error!("Failed to parse into a `DrawSection`: {}", name);
-                                                    }
+                                                if custom::toggle_ui(ui, &mut draw).clicked() {
+                                                    config.visualisation.draw.set(setting, draw);
+                                                    world.send_event(DrawSettingsEvent { setting, draw });
                                                 }
                                             });
                                         });
@@ -1012,7 +992,7 @@ fn ui_settings_panel(
                                             clippy::cast_possible_truncation
                                         )]
                                         let step_size = config.simulation.manual_step_factor as f32
-                                            / config.simulation.hz as f32;
+                                            / config.simulation.hz.get() as f32;
                                         time_fixed.advance_by(Duration::from_secs_f32(step_size));
                                     }
                                 });
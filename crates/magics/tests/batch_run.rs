@@ -0,0 +1,78 @@
+//! Integration tests running the `magics` binary as a headless batch job
+use std::process::{Command, Output};
+
+/// Run the `magics` binary headless with `args`, and return its stdout
+/// Panics if it does not exit successfully
+fn run_headless(args: &[&str]) -> String {
+    let Output {
+        status,
+        stdout,
+        stderr,
+    } = Command::new(env!("CARGO_BIN_EXE_magics"))
+        .arg("--headless")
+        .args(args)
+        // scenarios are loaded from `./config/scenarios` in the workspace root
+        .current_dir(concat!(env!("CARGO_MANIFEST_DIR"), "/../.."))
+        .output()
+        .expect("the magics binary can be run");
+
+    assert!(
+        status.success(),
+        "magics exited with {status}, stderr:\n{}",
+        String::from_utf8_lossy(&stderr)
+    );
+    String::from_utf8_lossy(&stdout).into_owned()
+}
+
+#[test]
+fn headless_run_exits_after_max_steps() {
+    let stdout = run_headless(&["--max-steps", "10"]);
+    assert!(
+        stdout.contains("steps run:       10"),
+        "unexpected summary:\n{stdout}"
+    );
+}
+
+#[test]
+fn same_seed_spawns_robots_at_same_positions() {
+    let first_spawns = |stdout: &str| {
+        stdout
+            .lines()
+            .find(|line| line.starts_with("first robots spawned at:"))
+            .map(ToOwned::to_owned)
+    };
+
+    let args = ["--seed", "42", "--max-steps", "300"];
+    let first = first_spawns(&run_headless(&args));
+    let second = first_spawns(&run_headless(&args));
+
+    assert!(first.is_some(), "no robots were spawned within 300 steps");
+    assert_eq!(first, second);
+}
+
+#[test]
+fn metrics_are_exported_as_json() {
+    let path = std::env::temp_dir().join(format!("magics-metrics-{}.json", std::process::id()));
+    let path_arg = path.to_str().expect("temp dir is valid utf-8");
+
+    run_headless(&["--max-steps", "100", "--export-metrics", path_arg]);
+
+    let contents = std::fs::read_to_string(&path).expect("metrics file was written");
+    let _ = std::fs::remove_file(&path);
+    let metrics: serde_json::Value =
+        serde_json::from_str(&contents).expect("metrics file is valid json");
+
+    for key in [
+        "makespan",
+        "robots_finished",
+        "robot_robot_collisions",
+        "robot_environment_collisions",
+        "total_path_length",
+        "mean_path_length",
+    ] {
+        assert!(
+            metrics.get(key).is_some(),
+            "missing key `{key}` in:\n{contents}"
+        );
+    }
+}
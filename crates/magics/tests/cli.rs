@@ -0,0 +1,124 @@
+//! Integration tests of the command line flags of the `magics` binary, that
+//! either exit before the app is run or run it headless
+use std::process::{Command, Output};
+
+/// Run the `magics` binary with `args`, and return its output
+fn run(args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_magics"))
+        .args(args)
+        .current_dir(concat!(env!("CARGO_MANIFEST_DIR"), "/../.."))
+        .output()
+        .expect("the magics binary can be run")
+}
+
+#[test]
+fn dump_all_environments_writes_every_environment_type() {
+    let dir = std::env::temp_dir().join(format!("magics-environments-{}", std::process::id()));
+    let dir_arg = dir.to_str().expect("temp dir is valid utf-8");
+
+    let output = run(&["--dump-all-environments", dir_arg]);
+    assert!(
+        output.status.success(),
+        "magics exited with {}, stderr:\n{}",
+        output.status,
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    let mut files = std::fs::read_dir(&dir)
+        .expect("output directory was created")
+        .map(|entry| entry.expect("directory entry can be read").path())
+        .collect::<Vec<_>>();
+    files.sort();
+    assert_eq!(files.len(), 6, "unexpected files: {files:?}");
+
+    for file in &files {
+        assert_eq!(file.extension().and_then(|ext| ext.to_str()), Some("yaml"));
+        assert!(
+            gbp_environment::Environment::from_file(file).is_ok(),
+            "{} does not re-parse",
+            file.display()
+        );
+    }
+
+    std::fs::remove_dir_all(&dir).expect("output directory can be removed");
+}
+
+#[test]
+fn validate_config_rejects_invalid_config() {
+    let mut table =
+        toml::Table::try_from(gbp_config::Config::default()).expect("default config serializes");
+    table
+        .get_mut("gbp")
+        .and_then(toml::Value::as_table_mut)
+        .expect("config has a gbp section")
+        .insert("variables".into(), toml::Value::Integer(1));
+
+    let path = std::env::temp_dir().join(format!("magics-config-{}.toml", std::process::id()));
+    std::fs::write(&path, table.to_string()).expect("config can be written");
+    let path_arg = path.to_str().expect("temp dir is valid utf-8");
+
+    let output = run(&["--validate-config", path_arg]);
+    std::fs::remove_file(&path).expect("config can be removed");
+
+    assert!(!output.status.success(), "invalid config was accepted");
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("gbp.variables must be at least 2"),
+        "unexpected stderr:\n{stderr}"
+    );
+}
+
+#[test]
+fn validate_config_warns_about_deprecated_fields() {
+    let mut table =
+        toml::Table::try_from(gbp_config::Config::default()).expect("default config serializes");
+    let gbp = table
+        .get_mut("gbp")
+        .and_then(toml::Value::as_table_mut)
+        .expect("config has a gbp section");
+    let schedule = gbp
+        .remove("iteration-schedule")
+        .expect("config has an iteration schedule");
+    gbp.insert("iterations-per-timestep".into(), schedule);
+
+    let path = std::env::temp_dir().join(format!(
+        "magics-deprecated-config-{}.toml",
+        std::process::id()
+    ));
+    std::fs::write(&path, table.to_string()).expect("config can be written");
+    let path_arg = path.to_str().expect("temp dir is valid utf-8");
+
+    let output = run(&["--validate-config", path_arg]);
+    std::fs::remove_file(&path).expect("config can be removed");
+
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(output.status.success(), "stderr:\n{stderr}");
+    assert!(
+        stderr.contains("`gbp.iterations-per-timestep` is deprecated"),
+        "unexpected stderr:\n{stderr}"
+    );
+}
+
+#[test]
+fn headless_run_until_finished_exits_with_finished_reason() {
+    // The only formation of this scenario has no robots, so it is finished as
+    // soon as it has been spawned
+    let output = run(&[
+        "--headless",
+        "--until-finished",
+        "--initial-scenario",
+        "Obstacle Shapes Showcase",
+    ]);
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(
+        output.status.success(),
+        "magics exited with {}, stderr:\n{}",
+        output.status,
+        String::from_utf8_lossy(&output.stderr)
+    );
+    assert!(
+        stdout.contains("ending batch run, reason: all formations finished"),
+        "unexpected stdout:\n{stdout}"
+    );
+}
@@ -8,17 +8,9 @@ use serde::{Deserialize, Deserializer, Serialize};
 /// `N` elements. It is useful when you want to ensure that a vector has at
 /// least a certain number of elements. but don't want to check it every time
 /// you access the vector.
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct MinLenVec<T, const N: usize>(Vec<T>);
 
-impl<T: std::clone::Clone, const N: usize> std::clone::Clone for MinLenVec<T, N> {
-    fn clone(&self) -> Self {
-        Self(self.0.clone())
-        // self.0.clone()
-        // todo!()
-    }
-}
-
 /// Error type for `MinLenVec`.
 #[derive(Debug, PartialEq, Eq)]
 pub enum MinLenVecError {
@@ -108,6 +100,25 @@ impl<T, const N: usize> MinLenVec<T, N> {
         self.0.push(value);
     }
 
+    /// Move all elements of `other` to the end of the vector.
+    /// The minimum length `N` is kept, as the length of the vector can only
+    /// grow.
+    #[inline(always)]
+    pub fn append<const M: usize>(&mut self, other: MinLenVec<T, M>) {
+        self.0.extend(other.0);
+    }
+
+    /// Concatenate the vector with `other`, returning a new `MinLenVec` with
+    /// the elements of `self` followed by the elements of `other`.
+    /// The minimum length `N` is kept, as the combined length is always at
+    /// least `N + M`.
+    #[inline(always)]
+    #[must_use]
+    pub fn concat<const M: usize>(mut self, other: MinLenVec<T, M>) -> Self {
+        self.append(other);
+        self
+    }
+
     /// Pop an element from the vector.
     ///
     /// # Errors
@@ -144,6 +155,58 @@ impl<T, const N: usize> MinLenVec<T, N> {
     pub fn last(&self) -> &T {
         &self.0[self.0.len() - 1]
     }
+
+    /// Get the first element and the rest of the vector.
+    /// Since the vector has at least `N` elements, this will always return a
+    /// first element when `N >= 1`.
+    ///
+    /// # Panics
+    ///
+    /// If `N == 0` and the vector is empty.
+    #[inline(always)]
+    #[must_use]
+    pub fn split_first(&self) -> (&T, &[T]) {
+        self.0
+            .split_first()
+            .expect("there is always at least N >= 1 elements")
+    }
+
+    /// Get the last element and the rest of the vector.
+    /// Since the vector has at least `N` elements, this will always return a
+    /// last element when `N >= 1`.
+    ///
+    /// # Panics
+    ///
+    /// If `N == 0` and the vector is empty.
+    #[inline(always)]
+    #[must_use]
+    pub fn split_last(&self) -> (&T, &[T]) {
+        self.0
+            .split_last()
+            .expect("there is always at least N >= 1 elements")
+    }
+
+    /// Returns an iterator over all contiguous windows of length `size`.
+    /// See [`slice::windows`].
+    ///
+    /// # Panics
+    ///
+    /// If `size` is 0.
+    #[inline(always)]
+    pub fn windows(&self, size: usize) -> std::slice::Windows<'_, T> {
+        self.0.windows(size)
+    }
+
+    /// Returns an iterator over `chunk_size` elements of the vector at a time.
+    /// See [`slice::chunks`].
+    ///
+    /// # Panics
+    ///
+    /// If `chunk_size` is 0.
+    #[inline(always)]
+    pub fn chunks(&self, chunk_size: usize) -> std::slice::Chunks<'_, T> {
+        self.0.chunks(chunk_size)
+    }
 }
 
 impl<T, const N: usize> std::iter::IntoIterator for MinLenVec<T, N> {
@@ -169,6 +232,20 @@ impl<T, const N: usize> std::ops::IndexMut<usize> for MinLenVec<T, N> {
     }
 }
 
+impl<T, const N: usize> std::ops::Deref for MinLenVec<T, N> {
+    type Target = [T];
+
+    fn deref(&self) -> &Self::Target {
+        self.as_slice()
+    }
+}
+
+impl<T, const N: usize> AsRef<[T]> for MinLenVec<T, N> {
+    fn as_ref(&self) -> &[T] {
+        self.as_slice()
+    }
+}
+
 impl<T, const N: usize> TryFrom<Vec<T>> for MinLenVec<T, N> {
     type Error = MinLenVecError;
 
@@ -342,6 +419,52 @@ mod tests {
         assert_eq!(v.last(), &4);
     }
 
+    #[test]
+    fn test_split_first() {
+        let v: OneOrMore<i32> = one_or_more![1, 2, 3, 4];
+        let (first, rest) = v.split_first();
+        assert_eq!(first, &1);
+        assert_eq!(rest, &[2, 3, 4]);
+
+        let v: OneOrMore<i32> = one_or_more![1];
+        assert_eq!(v.split_first(), (&1, &[][..]));
+    }
+
+    #[test]
+    fn test_split_last() {
+        let v: OneOrMore<i32> = one_or_more![1, 2, 3, 4];
+        let (last, rest) = v.split_last();
+        assert_eq!(last, &4);
+        assert_eq!(rest, &[1, 2, 3]);
+    }
+
+    #[test]
+    fn test_windows_and_chunks() {
+        let v: OneOrMore<i32> = one_or_more![1, 2, 3, 4];
+        let windows: Vec<_> = v.windows(2).collect();
+        assert_eq!(windows, vec![&[1, 2], &[2, 3], &[3, 4]]);
+
+        let chunks: Vec<_> = v.chunks(3).collect();
+        assert_eq!(chunks, vec![&[1, 2, 3][..], &[4][..]]);
+    }
+
+    #[test]
+    fn test_concat() {
+        let front = MinLenVec::<_, 2>::new(vec![1, 2]).unwrap();
+        let back = MinLenVec::<_, 3>::new(vec![3, 4, 5]).unwrap();
+        let combined = front.concat(back);
+        assert_eq!(combined.len(), 5);
+        assert_eq!(combined.into_inner(), vec![1, 2, 3, 4, 5]);
+    }
+
+    #[test]
+    fn test_append() {
+        let mut v = MinLenVec::<_, 2>::new(vec![1, 2]).unwrap();
+        v.append(MinLenVec::<_, 3>::new(vec![3, 4, 5]).unwrap());
+        assert_eq!(v.len(), 5);
+        assert_eq!(v.as_slice(), &[1, 2, 3, 4, 5]);
+    }
+
     #[test]
     fn test_one_or_more_macro() {
         let v = one_or_more!["one"];
@@ -378,4 +501,24 @@ mod tests {
             })
         ));
     }
+
+    #[test]
+    fn test_clone_and_eq() {
+        let v: TwoOrMore<i32> = two_or_more![1, 2, 3];
+        let cloned = v.clone();
+        assert_eq!(v, cloned);
+
+        let mut different = v.clone();
+        different.push(4);
+        assert_ne!(v, different);
+    }
+
+    #[test]
+    fn test_deref_to_slice() {
+        let v: TwoOrMore<i32> = two_or_more![3, 1, 2];
+        assert!(v.contains(&1));
+        assert_eq!(v.iter().max(), Some(&3));
+        assert_eq!(v.as_ref(), &[3, 1, 2]);
+        assert_eq!(v.get(1..), Some(&[1, 2][..]));
+    }
 }
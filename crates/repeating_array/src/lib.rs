@@ -5,30 +5,104 @@
 #[derive(Debug)]
 pub struct RepeatingArray<T, const N: usize> {
     /// The array to iterate over
-    array: [T; N],
+    array:  [T; N],
     /// The current index in the array, starting at 0
-    index: usize,
+    index:  usize,
+    /// The number of times the index has wrapped around to 0
+    cycles: usize,
 }
 
-impl<T: Copy, const N: usize> RepeatingArray<T, N> {
+impl<T, const N: usize> RepeatingArray<T, N> {
     /// Create a new `RepeatingArray`
     #[inline]
     #[must_use]
     pub const fn new(array: [T; N]) -> Self {
-        Self { array, index: 0 }
+        Self {
+            array,
+            index: 0,
+            cycles: 0,
+        }
     }
 
-    /// Get the next item or the first one if we are at the end
-    pub fn next_or_first(&mut self) -> T {
-        let item = self.array[self.index];
+    /// Advance to the next index, and return the current one
+    fn advance(&mut self) -> usize {
+        let index = self.index;
         self.index = (self.index + 1) % N;
-        item
+        if self.index == 0 {
+            self.cycles += 1;
+        }
+        index
+    }
+
+    /// The index of the item returned next
+    #[inline]
+    #[must_use]
+    pub const fn current_index(&self) -> usize {
+        self.index
+    }
+
+    /// Get the item returned next, without advancing
+    #[must_use]
+    pub fn peek(&self) -> T
+    where
+        T: Clone,
+    {
+        self.array[self.index].clone()
+    }
+
+    /// The number of items in one cycle through the array, i.e. `N`
+    /// - The [`Iterator`] implementation never terminates, so this is not its
+    ///   length
+    #[inline]
+    #[must_use]
+    pub const fn period(&self) -> usize {
+        N
     }
 
-    /// Reset the index to 0
+    /// The number of full cycles through the array so far
+    #[inline]
+    #[must_use]
+    pub const fn cycles(&self) -> usize {
+        self.cycles
+    }
+
+    /// Reset the index and the number of cycles to 0
     #[inline(always)]
     pub fn reset(&mut self) {
         self.index = 0;
+        self.cycles = 0;
+    }
+
+    /// Reset the index to `index` and the number of cycles to 0
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds, i.e. `index >= N`
+    pub fn reset_to(&mut self, index: usize) {
+        assert!(index < N, "index {index} out of bounds for length {N}");
+        self.index = index;
+        self.cycles = 0;
+    }
+
+    /// Get a clone of the next item or the first one if we are at the end
+    /// - Use [`RepeatingArray::next_or_first`] for `Copy` types
+    pub fn next_cloned(&mut self) -> T
+    where
+        T: Clone,
+    {
+        let index = self.advance();
+        self.array[index].clone()
+    }
+
+    /// Iterate over exactly `n` items, continuing from the current index and
+    /// wrapping around to the start as needed
+    /// - Unlike the [`Iterator`] implementation, this iterator terminates, so
+    ///   it is safe to `.collect()`
+    pub fn iter_n(&mut self, n: usize) -> impl Iterator<Item = T> + '_
+    where
+        T: Clone,
+    {
+        (0..n).map(|_| self.next_cloned())
     }
 
     // /// Turn the RepeatingArray into an `Iterator`
@@ -37,6 +111,14 @@ impl<T: Copy, const N: usize> RepeatingArray<T, N> {
     // }
 }
 
+impl<T: Copy, const N: usize> RepeatingArray<T, N> {
+    /// Get the next item or the first one if we are at the end
+    pub fn next_or_first(&mut self) -> T {
+        let index = self.advance();
+        self.array[index]
+    }
+}
+
 // impl<T: Copy, const N: usize> std::iter::IntoIterator for RepeatingArray<T,
 // N> {     type IntoIter = std::array::IntoIter<T, N>;
 //     type Item = T;
@@ -46,17 +128,19 @@ impl<T: Copy, const N: usize> RepeatingArray<T, N> {
 //     }
 // }
 
-impl<T: Copy, const N: usize> Iterator for RepeatingArray<T, N> {
+/// Iterate over the array repeatedly
+/// The iterator never terminates, as it wraps around to the start when reaching
+/// the end, so do not `.collect()` it. Use [`RepeatingArray::iter_n`] or
+/// [`Iterator::take`] to get a bounded number of items
+impl<T: Clone, const N: usize> Iterator for RepeatingArray<T, N> {
     type Item = T;
 
     fn next(&mut self) -> Option<Self::Item> {
-        Some(self.next_or_first())
+        Some(self.next_cloned())
     }
-}
 
-impl<T: Copy, const N: usize> std::iter::ExactSizeIterator for RepeatingArray<T, N> {
-    fn len(&self) -> usize {
-        N
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (usize::MAX, None)
     }
 }
 
@@ -114,4 +198,75 @@ mod tests {
         assert_eq!(array[2], 7);
         assert_eq!(array[3], 8);
     }
+
+    #[test]
+    fn counts_cycles() {
+        let mut array = RepeatingArray::new([1, 2, 3]);
+        assert_eq!(array.cycles(), 0);
+        for _ in 0..7 {
+            array.next_or_first();
+        }
+        assert_eq!(array.cycles(), 2);
+
+        array.reset_to(2);
+        assert_eq!(array.cycles(), 0);
+        assert_eq!(array.next_or_first(), 3);
+        assert_eq!(array.cycles(), 1);
+        assert_eq!(array.next_or_first(), 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "out of bounds")]
+    fn reset_to_out_of_bounds_panics() {
+        RepeatingArray::new([1, 2, 3]).reset_to(3);
+    }
+
+    #[test]
+    fn non_copy_items_can_be_cycled() {
+        let mut colours = RepeatingArray::new(["red".to_string(), "green".to_string()]);
+        assert_eq!(colours.next_cloned(), "red");
+        assert_eq!(colours.next_cloned(), "green");
+        assert_eq!(colours.next(), Some("red".to_string()));
+        assert_eq!(colours.next(), Some("green".to_string()));
+        assert_eq!(colours.cycles(), 2);
+    }
+
+    #[test]
+    fn iter_n_yields_exactly_n_items() {
+        let mut array = RepeatingArray::new([1, 2, 3]);
+        assert_eq!(array.iter_n(5).collect::<Vec<_>>(), vec![1, 2, 3, 1, 2]);
+        assert_eq!(array.iter_n(2).collect::<Vec<_>>(), vec![3, 1]);
+        assert_eq!(array.iter_n(0).count(), 0);
+    }
+
+    #[test]
+    fn size_hint_is_unbounded() {
+        let array = RepeatingArray::new([1, 2, 3]);
+        assert_eq!(array.size_hint(), (usize::MAX, None));
+        assert_eq!(array.period(), 3);
+    }
+
+    #[test]
+    fn peek_does_not_advance() {
+        let mut array = RepeatingArray::new([1, 2, 3]);
+        for _ in 0..4 {
+            let peeked = array.peek();
+            assert_eq!(array.peek(), peeked);
+            assert_eq!(array.next_or_first(), peeked);
+        }
+    }
+
+    #[test]
+    fn current_index_wraps_around() {
+        let mut array = RepeatingArray::new([1, 2, 3]);
+        let indices = (0..5)
+            .map(|_| {
+                let index = array.current_index();
+                array.next_or_first();
+                index
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(indices, vec![0, 1, 2, 0, 1]);
+        assert_eq!(array.current_index(), 2);
+    }
 }
@@ -4,8 +4,39 @@
 use serde::{Deserialize, Deserializer, Serialize};
 
 /// A value in the closed interval [0.0, 1.0].
-#[derive(Debug, Clone, Copy, PartialEq)]
-pub struct UnitInterval(f64);
+/// Backed by an `f64` by default, use [`UnitIntervalF32`] to avoid casts when
+/// working with `f32` values.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[allow(clippy::derive_partial_eq_without_eq)] // `F` is always a float
+pub struct UnitInterval<F = f64>(F);
+
+/// A `UnitInterval` backed by an `f32`.
+pub type UnitIntervalF32 = UnitInterval<f32>;
+
+mod sealed {
+    pub trait Sealed {}
+    impl Sealed for f32 {}
+    impl Sealed for f64 {}
+}
+
+/// Floating point types a `UnitInterval` can be backed by, i.e. `f32` and
+/// `f64`.
+pub trait Float: Copy + PartialOrd + Into<f64> + sealed::Sealed {
+    /// The lower bound of the interval
+    const ZERO: Self;
+    /// The upper bound of the interval
+    const ONE: Self;
+}
+
+impl Float for f32 {
+    const ONE: Self = 1.0;
+    const ZERO: Self = 0.0;
+}
+
+impl Float for f64 {
+    const ONE: Self = 1.0;
+    const ZERO: Self = 0.0;
+}
 
 // impl sub and add for `UnitInterval`
 impl std::ops::Add<UnitInterval> for UnitInterval {
@@ -50,18 +81,18 @@ impl std::error::Error for UnitIntervalError {}
 /// A type alias for `Result<T, UnitIntervalError>`.
 pub type Result<T> = std::result::Result<T, UnitIntervalError>;
 
-impl UnitInterval {
+impl<F: Float> UnitInterval<F> {
     /// Creates a new `UnitInterval` from a value. Returns an error if the value
     /// is not in the interval [0.0, 1.0].
     ///
     /// # Errors
     ///
     /// Will return `Err` if `value` is not in the interval [0.0, 1.0]
-    pub fn new(value: f64) -> Result<Self> {
-        if (0.0..=1.0).contains(&value) {
+    pub fn new(value: F) -> Result<Self> {
+        if (F::ZERO..=F::ONE).contains(&value) {
             Ok(Self(value))
         } else {
-            Err(UnitIntervalError::OutOfBounds(value))
+            Err(UnitIntervalError::OutOfBounds(value.into()))
         }
     }
 
@@ -73,17 +104,90 @@ impl UnitInterval {
     /// You have to manually ensure the invariant that the given value is
     /// between [0.0, 1.0]
     #[must_use]
-    pub const unsafe fn new_unchecked(value: f64) -> Self {
+    pub const unsafe fn new_unchecked(value: F) -> Self {
         Self(value)
     }
 
     /// Returns the inner value of the `UnitInterval`.
     #[must_use]
-    pub const fn get(self) -> f64 {
+    pub const fn get(self) -> F {
         self.0
     }
 }
 
+impl UnitInterval {
+    /// Creates a new `UnitInterval` from a value, clamping it to the interval
+    /// [0.0, 1.0] instead of returning an error if it is out of bounds
+    /// A NaN `value` is mapped to 0.0
+    #[must_use]
+    pub fn clamp(value: f64) -> Self {
+        Self::saturate(value).unwrap_or(Self(0.0))
+    }
+
+    /// Returns `1.0 - self`, i.e. the distance to the upper bound of the
+    /// interval
+    #[must_use]
+    pub fn complement(self) -> Self {
+        Self(1.0 - self.0)
+    }
+
+    /// Adds `rhs` to `self`, clamping the result to the interval [0.0, 1.0]
+    /// If `rhs` is NaN, `self` is returned unchanged
+    #[must_use]
+    pub fn saturating_add(self, rhs: f64) -> Self {
+        Self::saturate(self.0 + rhs).unwrap_or(self)
+    }
+
+    /// Subtracts `rhs` from `self`, clamping the result to the interval
+    /// [0.0, 1.0]
+    /// If `rhs` is NaN, `self` is returned unchanged
+    #[must_use]
+    pub fn saturating_sub(self, rhs: f64) -> Self {
+        Self::saturate(self.0 - rhs).unwrap_or(self)
+    }
+
+    /// Adds `rhs` to `self`
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if the sum is not in the interval [0.0, 1.0]
+    pub fn checked_add(self, rhs: f64) -> Result<Self> {
+        Self::new(self.0 + rhs)
+    }
+
+    /// Multiplies `self` by `rhs`
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if the product is not in the interval [0.0, 1.0]
+    pub fn checked_mul(self, rhs: f64) -> Result<Self> {
+        Self::new(self.0 * rhs)
+    }
+
+    /// Linearly interpolates between `self` and `other`
+    /// `t` is clamped to [0.0, 1.0], so the result always lies between `self`
+    /// and `other`. A NaN `t` is treated as 0.0
+    #[must_use]
+    pub fn lerp(self, other: Self, t: f64) -> Self {
+        let t = if t.is_nan() { 0.0 } else { t.clamp(0.0, 1.0) };
+        // weighted form is exact at the endpoints, clamp guards against rounding
+        // errors in between
+        Self(self.0.mul_add(1.0 - t, other.0 * t).clamp(0.0, 1.0))
+    }
+
+    /// Returns the value halfway between `self` and `other`
+    #[must_use]
+    pub fn midpoint(self, other: Self) -> Self {
+        Self(self.0.mul_add(0.5, other.0 * 0.5))
+    }
+
+    /// Clamps `value` to the interval [0.0, 1.0], or returns `None` if it is
+    /// NaN
+    fn saturate(value: f64) -> Option<Self> {
+        (!value.is_nan()).then(|| Self(value.clamp(0.0, 1.0)))
+    }
+}
+
 impl TryFrom<f64> for UnitInterval {
     type Error = UnitIntervalError;
 
@@ -113,25 +217,83 @@ impl From<UnitInterval> for f32 {
     }
 }
 
-impl std::ops::Deref for UnitInterval {
-    type Target = f64;
+impl TryFrom<f32> for UnitIntervalF32 {
+    type Error = UnitIntervalError;
+
+    fn try_from(value: f32) -> Result<Self> {
+        Self::new(value)
+    }
+}
+
+impl From<UnitIntervalF32> for f32 {
+    fn from(unit_interval: UnitIntervalF32) -> Self {
+        unit_interval.0
+    }
+}
+
+impl From<UnitIntervalF32> for f64 {
+    fn from(unit_interval: UnitIntervalF32) -> Self {
+        Self::from(unit_interval.0)
+    }
+}
+
+impl From<UnitIntervalF32> for UnitInterval {
+    fn from(unit_interval: UnitIntervalF32) -> Self {
+        Self(f64::from(unit_interval.0))
+    }
+}
+
+/// Implement comparisons in both directions between a `UnitInterval` and its
+/// backing float type
+macro_rules! impl_float_comparisons {
+    ($($float:ty),*) => {$(
+        impl PartialEq<$float> for UnitInterval<$float> {
+            fn eq(&self, other: &$float) -> bool {
+                self.0 == *other
+            }
+        }
+
+        impl PartialEq<UnitInterval<$float>> for $float {
+            fn eq(&self, other: &UnitInterval<$float>) -> bool {
+                *self == other.0
+            }
+        }
+
+        impl PartialOrd<$float> for UnitInterval<$float> {
+            fn partial_cmp(&self, other: &$float) -> Option<std::cmp::Ordering> {
+                self.0.partial_cmp(other)
+            }
+        }
+
+        impl PartialOrd<UnitInterval<$float>> for $float {
+            fn partial_cmp(&self, other: &UnitInterval<$float>) -> Option<std::cmp::Ordering> {
+                self.partial_cmp(&other.0)
+            }
+        }
+    )*};
+}
+
+impl_float_comparisons!(f32, f64);
+
+impl<F> std::ops::Deref for UnitInterval<F> {
+    type Target = F;
 
     fn deref(&self) -> &Self::Target {
         &self.0
     }
 }
 
-impl<'de> Deserialize<'de> for UnitInterval {
+impl<'de, F: Float + Deserialize<'de>> Deserialize<'de> for UnitInterval<F> {
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: Deserializer<'de>,
     {
-        let value = f64::deserialize(deserializer)?;
+        let value = F::deserialize(deserializer)?;
         Self::new(value).map_err(serde::de::Error::custom)
     }
 }
 
-impl Serialize for UnitInterval {
+impl<F: Serialize> Serialize for UnitInterval<F> {
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
         S: serde::Serializer,
@@ -205,4 +367,92 @@ mod tests {
         let unit_interval: UnitInterval = 0.5.try_into().unwrap();
         assert_eq!(unit_interval, UnitInterval(0.5));
     }
+
+    #[test]
+    #[allow(clippy::unwrap_used)]
+    fn test_saturating_arithmetic() {
+        let value = UnitInterval::new(0.8).unwrap();
+        assert_eq!(value.saturating_add(0.5), UnitInterval(1.0));
+        assert_eq!(value.saturating_sub(1.5), UnitInterval(0.0));
+        assert_relative_eq!(value.saturating_sub(0.3).get(), 0.5);
+        assert_eq!(value.saturating_add(f64::NAN), value);
+    }
+
+    #[test]
+    #[allow(clippy::unwrap_used)]
+    fn test_checked_arithmetic() {
+        let value = UnitInterval::new(0.8).unwrap();
+        assert!(matches!(
+            value.checked_add(0.5),
+            Err(UnitIntervalError::OutOfBounds(_))
+        ));
+        assert_relative_eq!(value.checked_add(0.1).unwrap().get(), 0.9);
+        assert_relative_eq!(value.checked_mul(0.5).unwrap().get(), 0.4);
+        assert!(matches!(
+            value.checked_mul(2.0),
+            Err(UnitIntervalError::OutOfBounds(_))
+        ));
+        assert!(matches!(
+            value.checked_mul(-1.0),
+            Err(UnitIntervalError::OutOfBounds(_))
+        ));
+    }
+
+    #[test]
+    fn test_complement() {
+        assert_eq!(UnitInterval(0.0).complement(), UnitInterval(1.0));
+        assert_eq!(UnitInterval(1.0).complement(), UnitInterval(0.0));
+        assert_relative_eq!(UnitInterval(0.25).complement().get(), 0.75);
+    }
+
+    #[test]
+    fn test_lerp_and_midpoint() {
+        let (a, b) = (UnitInterval(0.2), UnitInterval(0.8));
+        assert_relative_eq!(a.midpoint(b).get(), 0.5);
+        assert_relative_eq!(b.midpoint(a).get(), 0.5);
+
+        assert_eq!(a.lerp(b, 0.0), a);
+        assert_eq!(a.lerp(b, 1.0), b);
+        assert_relative_eq!(a.lerp(b, 0.25).get(), 0.35);
+        assert_eq!(a.lerp(b, 2.0), b);
+        assert_eq!(a.lerp(b, -1.0), a);
+        assert_eq!(a.lerp(b, f64::NAN), a);
+    }
+
+    #[test]
+    fn test_clamp() {
+        assert_eq!(UnitInterval::clamp(-0.5), UnitInterval(0.0));
+        assert_eq!(UnitInterval::clamp(2.0), UnitInterval(1.0));
+        assert_eq!(UnitInterval::clamp(0.3), UnitInterval(0.3));
+        assert_eq!(UnitInterval::clamp(f64::NAN), UnitInterval(0.0));
+    }
+
+    #[test]
+    #[allow(clippy::unwrap_used)]
+    fn test_compare_with_f64() {
+        let value = UnitInterval::new(0.3).unwrap();
+        assert!(value < 0.5);
+        assert!(value > 0.0);
+        assert!(0.5 > value);
+        assert_eq!(value, 0.3);
+        assert!(value < UnitInterval(0.4));
+        assert_eq!(value.partial_cmp(&f64::NAN), None);
+    }
+
+    #[test]
+    #[allow(clippy::unwrap_used, clippy::float_cmp)]
+    fn test_f32_backed() {
+        let value = UnitIntervalF32::new(0.1_f32).unwrap();
+        assert_eq!(value.get(), 0.1_f32);
+        assert_eq!(f32::from(value), 0.1_f32);
+        assert_eq!(f64::from(value), f64::from(0.1_f32));
+        let widened: UnitInterval = value.into();
+        assert_eq!(widened.get(), f64::from(0.1_f32));
+        assert!(value < 0.5_f32);
+
+        assert!(matches!(
+            UnitIntervalF32::try_from(1.5_f32),
+            Err(UnitIntervalError::OutOfBounds(v)) if v == 1.5
+        ));
+    }
 }
@@ -0,0 +1,138 @@
+#![deny(missing_docs)]
+//! This module contains a newtype representing a frequency in hertz.
+
+use std::time::Duration;
+
+/// Newtype representing a frequency in hertz, i.e. occurrences per second
+///
+/// The frequency is always strictly positive and finite.
+/// (De)serialized as a plain number, and deserialization fails if the number
+/// is not a valid frequency.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, serde::Serialize, serde::Deserialize)]
+#[serde(try_from = "f64", into = "f64")]
+pub struct Hertz(f64);
+
+/// Error type for fallible functions in this module
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Error {
+    /// A frequency must be strictly positive
+    NonPositive(f64),
+    /// A frequency must be finite
+    NotFinite(f64),
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::NonPositive(hz) => {
+                write!(
+                    f,
+                    "A frequency must be strictly positive, provided value is {hz}"
+                )
+            }
+            Self::NotFinite(hz) => {
+                write!(f, "A frequency must be finite, provided value is {hz}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+/// Result type for fallible functions in this module
+pub type Result<T> = std::result::Result<T, Error>;
+
+impl Hertz {
+    /// Create a `Hertz` from a number of occurrences per second.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if `hz` is not strictly positive and finite
+    pub fn new(hz: f64) -> Result<Self> {
+        if !hz.is_finite() {
+            Err(Error::NotFinite(hz))
+        } else if hz <= 0.0 {
+            Err(Error::NonPositive(hz))
+        } else {
+            Ok(Self(hz))
+        }
+    }
+
+    /// Returns the number of occurrences per second.
+    #[inline(always)]
+    #[must_use]
+    pub const fn get(self) -> f64 {
+        self.0
+    }
+
+    /// Returns the time between two occurrences.
+    #[inline]
+    #[must_use]
+    pub fn period(self) -> Duration {
+        Duration::from_secs_f64(1.0 / self.0)
+    }
+}
+
+impl TryFrom<f64> for Hertz {
+    type Error = Error;
+
+    fn try_from(hz: f64) -> Result<Self> {
+        Self::new(hz)
+    }
+}
+
+impl From<Hertz> for f64 {
+    fn from(hz: Hertz) -> Self {
+        hz.0
+    }
+}
+
+impl std::fmt::Display for Hertz {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} Hz", self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn period_is_inverse_of_frequency() {
+        let hz = Hertz::new(50.0).expect("50 Hz is a valid frequency");
+        assert_eq!(hz.period(), Duration::from_millis(20));
+    }
+
+    #[test]
+    fn non_positive_and_non_finite_are_rejected() {
+        assert_eq!(Hertz::new(0.0), Err(Error::NonPositive(0.0)));
+        assert_eq!(Hertz::new(-60.0), Err(Error::NonPositive(-60.0)));
+        assert_eq!(
+            Hertz::new(f64::INFINITY),
+            Err(Error::NotFinite(f64::INFINITY))
+        );
+        assert!(matches!(Hertz::new(f64::NAN), Err(Error::NotFinite(_))));
+    }
+
+    #[test]
+    fn serde_rejects_zero_and_accepts_sixty() {
+        #[derive(Debug, serde::Serialize, serde::Deserialize)]
+        struct Section {
+            hz: Hertz,
+        }
+
+        let err = toml::from_str::<Section>("hz = 0.0").expect_err("0 Hz is invalid");
+        assert!(
+            err.to_string().contains("strictly positive"),
+            "unexpected error: {err}"
+        );
+
+        let section: Section = toml::from_str("hz = 60.0").expect("60 Hz is valid");
+        assert_eq!(section.hz, Hertz::new(60.0).expect("60 Hz is valid"));
+        assert_eq!(
+            toml::to_string(&section).map(|s| s.trim().to_string()).ok(),
+            Some("hz = 60.0".to_string()),
+            "serialized as a plain number"
+        );
+    }
+}
@@ -1,12 +1,18 @@
 #![deny(missing_docs)]
 //! Simple crate that contains newtypes for various physical units
 //! It contains the following modules:
+//! - `hertz`
+//! - `meter`
 //! - `sample_rate`
 
+pub mod hertz;
+pub mod meter;
 pub mod sample_rate;
+pub use hertz::Hertz;
+pub use meter::Meter;
 pub use sample_rate::SampleRate;
 
 /// Prelude module bringing entire public api of this crate into scope
 pub mod prelude {
-    pub use super::sample_rate;
+    pub use super::{hertz, meter, sample_rate};
 }
@@ -0,0 +1,108 @@
+#![deny(missing_docs)]
+//! This module contains a newtype representing a distance in meters.
+
+/// Newtype representing a distance in meters
+/// (De)serialized as a plain number, so config fields documented with
+/// "SI unit: m" can use it without changing the config format.
+#[derive(
+    Debug, Clone, Copy, Default, PartialEq, PartialOrd, serde::Serialize, serde::Deserialize,
+)]
+#[serde(transparent)]
+pub struct Meter(f64);
+
+impl Meter {
+    /// A distance of zero meters
+    pub const ZERO: Self = Self(0.0);
+
+    /// Create a `Meter` from a number of meters.
+    #[inline(always)]
+    #[must_use]
+    pub const fn from_meters(meters: f64) -> Self {
+        Self(meters)
+    }
+
+    /// Returns the distance as a number of meters.
+    #[inline(always)]
+    #[must_use]
+    pub const fn as_meters(self) -> f64 {
+        self.0
+    }
+}
+
+impl From<f64> for Meter {
+    fn from(meters: f64) -> Self {
+        Self(meters)
+    }
+}
+
+impl From<Meter> for f64 {
+    fn from(meter: Meter) -> Self {
+        meter.0
+    }
+}
+
+impl std::ops::Add for Meter {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self(self.0 + rhs.0)
+    }
+}
+
+impl std::ops::Sub for Meter {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self(self.0 - rhs.0)
+    }
+}
+
+impl std::ops::Mul<f64> for Meter {
+    type Output = Self;
+
+    fn mul(self, rhs: f64) -> Self::Output {
+        Self(self.0 * rhs)
+    }
+}
+
+impl std::fmt::Display for Meter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} m", self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn arithmetic() {
+        let a = Meter::from_meters(2.5);
+        let b = Meter::from_meters(1.0);
+
+        assert_eq!(a + b, Meter::from_meters(3.5));
+        assert_eq!(a - b, Meter::from_meters(1.5));
+        assert_eq!(a * 2.0, Meter::from_meters(5.0));
+        assert_eq!(a - a, Meter::ZERO);
+        assert!((a.as_meters() - 2.5).abs() < f64::EPSILON);
+        assert_eq!(a.to_string(), "2.5 m");
+    }
+
+    #[test]
+    fn serde_round_trip_as_plain_number() {
+        #[derive(Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+        struct Section {
+            radius: Meter,
+        }
+
+        let section = Section {
+            radius: Meter::from_meters(20.0),
+        };
+        let serialized = toml::to_string(&section).expect("section can be serialized");
+        assert_eq!(serialized.trim(), "radius = 20.0");
+
+        let deserialized: Section =
+            toml::from_str(&serialized).expect("serialized section can be deserialized");
+        assert_eq!(deserialized, section);
+    }
+}
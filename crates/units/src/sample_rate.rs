@@ -81,6 +81,76 @@ impl SampleRate {
     pub const fn as_duration(self) -> Duration {
         self.0
     }
+
+    /// Returns the time between two samples.
+    #[inline(always)]
+    #[must_use]
+    pub const fn period(&self) -> Duration {
+        self.0
+    }
+
+    /// Returns the number of samples per second (Hz).
+    #[inline]
+    #[must_use]
+    pub fn hz(&self) -> f64 {
+        1.0 / self.0.as_secs_f64()
+    }
+}
+
+/// Create a `SampleRate` from a number of samples per second (Hz).
+impl TryFrom<f64> for SampleRate {
+    type Error = Error;
+
+    fn try_from(hz: f64) -> Result<Self> {
+        if hz.is_sign_negative() {
+            Err(Error::NegativeTime(hz))
+        } else if hz == 0.0 || !hz.is_finite() {
+            Err(Error::Instantaneous)
+        } else {
+            let period = Duration::from_secs_f64(1.0 / hz);
+            if period.is_zero() {
+                Err(Error::Instantaneous)
+            } else {
+                Ok(Self(period))
+            }
+        }
+    }
+}
+
+/// Scale the number of samples per second, e.g. `rate * 2.0` samples twice as
+/// often as `rate`.
+///
+/// # Panics
+///
+/// If `factor` is not strictly positive and finite
+impl std::ops::Mul<f64> for SampleRate {
+    type Output = Self;
+
+    fn mul(self, factor: f64) -> Self::Output {
+        assert!(
+            factor.is_finite() && factor > 0.0,
+            "a SampleRate can only be scaled by a strictly positive finite factor, got {factor}"
+        );
+        Self(self.0.div_f64(factor))
+    }
+}
+
+/// Scale the number of samples per second, e.g. `rate / 2.0` samples half as
+/// often as `rate`.
+///
+/// # Panics
+///
+/// If `divisor` is not strictly positive and finite
+impl std::ops::Div<f64> for SampleRate {
+    type Output = Self;
+
+    fn div(self, divisor: f64) -> Self::Output {
+        assert!(
+            divisor.is_finite() && divisor > 0.0,
+            "a SampleRate can only be scaled by a strictly positive finite divisor, got {divisor}"
+        );
+        Self(self.0.mul_f64(divisor))
+    }
 }
 
 impl std::ops::Deref for SampleRate {
@@ -91,13 +161,42 @@ impl std::ops::Deref for SampleRate {
     }
 }
 
-// #[cfg(test)]
-// mod tests {
-//     use super::*;
-//     // use pretty_assertions::{assert_eq, assert_ne};
-//
-//     // #[test]
-//     // fn () {
-//
-//     // }
-// }
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sixty_hz_round_trips_through_period() {
+        let rate = SampleRate::try_from(60.0).expect("60 Hz is a valid sample rate");
+        let period_ms = rate.period().as_secs_f64() * 1000.0;
+        assert!(
+            (period_ms - 16.667).abs() < 1e-3,
+            "period was {period_ms} ms"
+        );
+        // the period is stored with nanosecond resolution
+        assert!((rate.hz() - 60.0).abs() < 1e-5, "rate was {} Hz", rate.hz());
+
+        let from_hz = SampleRate::from_hz(NonZeroU64::new(60).expect("60 > 0"));
+        assert!((from_hz.hz() - rate.hz()).abs() < 1e-5);
+    }
+
+    #[test]
+    fn non_positive_hz_is_rejected() {
+        assert!(matches!(
+            SampleRate::try_from(0.0),
+            Err(Error::Instantaneous)
+        ));
+        assert!(matches!(
+            SampleRate::try_from(-10.0),
+            Err(Error::NegativeTime(_))
+        ));
+    }
+
+    #[test]
+    fn scaling_changes_number_of_samples_per_second() {
+        let rate = SampleRate::try_from(10.0).expect("10 Hz is a valid sample rate");
+        assert!(((rate * 2.0).hz() - 20.0).abs() < 1e-6);
+        assert!(((rate / 2.0).hz() - 5.0).abs() < 1e-6);
+        assert_eq!((rate / 2.0).period(), Duration::from_millis(200));
+    }
+}